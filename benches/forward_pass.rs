@@ -0,0 +1,125 @@
+// Forward pass on the DQL topology [state_dim, 64, 64, action_dim]:
+// legacy nested Vec<Vec<Vec<f32>>> layout (clone + propagate, as the old `predict` did)
+// against the flat row-major layout with a reused scratch buffer,
+// then a batch of agents per sample loop against `propagate_batch`.
+//
+// cargo bench --bench forward_pass [--features parallel]
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rustml::neural_network::initializer::WeightInit;
+use rustml::neural_network::nn_struct::{BatchScratch, ForwardScratch, NeuralNetwork};
+use rustml::neural_network::propagate::{forward, propagate_batch};
+
+const STATE_DIM: usize = 16;
+const ACTION_DIM: usize = 4;
+const ITERATIONS: u32 = 20_000;
+const BATCH: usize = 256;
+
+#[derive(Clone)]
+struct LegacyNetwork {
+    num_layers: usize,
+    neurons_per_layer: Vec<usize>,
+    weights: Vec<Vec<Vec<f32>>>,
+    activations: Vec<Vec<f32>>,
+}
+
+impl LegacyNetwork {
+    // Same parameters, old layout: row 0 is the bias, column 0 is unused
+    fn from_flat(model: &NeuralNetwork) -> LegacyNetwork {
+        let mut weights = vec![Vec::new()];
+        for layer in 1..model.num_layers {
+            let previous = model.neurons_per_layer[layer - 1];
+            let current = model.neurons_per_layer[layer];
+            let mut layer_weights = vec![vec![0.0; current + 1]; previous + 1];
+            for j in 0..current {
+                layer_weights[0][j + 1] = model.biases[layer][j];
+                for i in 0..previous {
+                    layer_weights[i + 1][j + 1] = model.weights[layer][j * previous + i];
+                }
+            }
+            weights.push(layer_weights);
+        }
+        LegacyNetwork {
+            num_layers: model.num_layers,
+            neurons_per_layer: model.neurons_per_layer.clone(),
+            weights,
+            activations: model.neurons_per_layer.iter()
+                .map(|&n| (0..=n).map(|i| if i == 0 { 1.0 } else { 0.0 }).collect())
+                .collect(),
+        }
+    }
+
+    fn predict(&self, inputs: &[f32]) -> Vec<f32> {
+        let mut model = self.clone();
+        for i in 0..model.neurons_per_layer[0] {
+            model.activations[0][i + 1] = inputs[i];
+        }
+        for layer in 1..model.num_layers {
+            for j in 1..=model.neurons_per_layer[layer] {
+                let mut total = 0.0;
+                for i in 0..=model.neurons_per_layer[layer - 1] {
+                    total += model.weights[layer][i][j] * model.activations[layer - 1][i];
+                }
+                if layer < model.num_layers - 1 {
+                    total = total.max(0.0);
+                }
+                model.activations[layer][j] = total;
+            }
+        }
+        model.activations[model.num_layers - 1][1..].to_vec()
+    }
+}
+
+fn time<F: FnMut()>(iterations: u32, mut f: F) -> Duration {
+    // Warm up caches and the scratch buffer
+    for _ in 0..iterations / 10 {
+        f();
+    }
+    let start = Instant::now();
+    for _ in 0..iterations {
+        f();
+    }
+    start.elapsed() / iterations
+}
+
+fn main() {
+    let model = NeuralNetwork::new(&[STATE_DIM, 64, 64, ACTION_DIM], WeightInit::HeUniform, 0.0, 42);
+    let legacy = LegacyNetwork::from_flat(&model);
+    let state: Vec<f32> = (0..STATE_DIM).map(|x| x as f32 / STATE_DIM as f32).collect();
+
+    let mut scratch = ForwardScratch::new(&model);
+    let flat_output = forward(&model, &state, false, &mut scratch).to_vec();
+    let legacy_output = legacy.predict(&state);
+    for (a, b) in flat_output.iter().zip(&legacy_output) {
+        assert!((a - b).abs() < 1e-4, "layouts disagree: {} vs {}", a, b);
+    }
+
+    let legacy_time = time(ITERATIONS, || {
+        black_box(legacy.predict(black_box(&state)));
+    });
+    let flat_time = time(ITERATIONS, || {
+        black_box(forward(black_box(&model), black_box(&state), false, &mut scratch));
+    });
+
+    println!("topology [{}, 64, 64, {}], {} iterations", STATE_DIM, ACTION_DIM, ITERATIONS);
+    println!("legacy nested layout : {:?} / predict", legacy_time);
+    println!("flat row-major layout: {:?} / predict", flat_time);
+    println!("speedup              : {:.1}x", legacy_time.as_secs_f64() / flat_time.as_secs_f64());
+
+    let batch: Vec<f32> = (0..BATCH * STATE_DIM).map(|x| (x % 31) as f32 / 31.0).collect();
+    let mut batch_scratch = BatchScratch::new(&model, BATCH);
+    let loop_time = time(ITERATIONS / 100, || {
+        for row in batch.chunks(STATE_DIM) {
+            black_box(forward(black_box(&model), black_box(row), false, &mut scratch));
+        }
+    });
+    let batch_time = time(ITERATIONS / 100, || {
+        black_box(propagate_batch(black_box(&model), black_box(&batch), BATCH, false, &mut batch_scratch));
+    });
+
+    println!();
+    println!("{} agents, one forward per agent: {:?}", BATCH, loop_time);
+    println!("{} agents, propagate_batch      : {:?}", BATCH, batch_time);
+    println!("speedup                         : {:.1}x", loop_time.as_secs_f64() / batch_time.as_secs_f64());
+}
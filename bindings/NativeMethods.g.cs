@@ -0,0 +1,644 @@
+// <auto-generated>
+// This code is generated by csbindgen.
+// DON'T CHANGE THIS DIRECTLY.
+// </auto-generated>
+#pragma warning disable CS8500
+#pragma warning disable CS8981
+using System;
+using System.Runtime.InteropServices;
+
+
+namespace RustMl
+{
+    public static unsafe partial class NativeMethods
+    {
+        const string __DllName = "rustml";
+
+
+
+
+
+        [DllImport(__DllName, EntryPoint = "add", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status add(ulong left, ulong right, ulong* result);
+
+        [DllImport(__DllName, EntryPoint = "delete_kmeans", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status delete_kmeans(ulong model);
+
+        [DllImport(__DllName, EntryPoint = "save_kmeans", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status save_kmeans(ulong model, byte* path);
+
+        [DllImport(__DllName, EntryPoint = "load_kmeans", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status load_kmeans(byte* path, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "predict_kmeans", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status predict_kmeans(ulong model, float* inputs, int inputs_len, int* cluster);
+
+        [DllImport(__DllName, EntryPoint = "kmeans_inertia", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status kmeans_inertia(ulong model, float* inertia);
+
+        [DllImport(__DllName, EntryPoint = "kmeans_centers", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status kmeans_centers(ulong model, float* output, int output_len, int* written);
+
+        [DllImport(__DllName, EntryPoint = "kmeans_silhouette", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status kmeans_silhouette(float* inputs_ptr, int input_length, int inputs_sub, int* assignments_ptr, int assignments_len, float* score);
+
+        [DllImport(__DllName, EntryPoint = "fit_kmeans", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status fit_kmeans(float* inputs_ptr, int input_length, int inputs_sub, int k, int max_iterations, ulong seed, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "delete_pca", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status delete_pca(ulong model);
+
+        [DllImport(__DllName, EntryPoint = "save_pca", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status save_pca(ulong model, byte* path);
+
+        [DllImport(__DllName, EntryPoint = "load_pca", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status load_pca(byte* path, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "pca_transform", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status pca_transform(ulong model, float* inputs, int inputs_len, float* output, int output_len, int* written);
+
+        [DllImport(__DllName, EntryPoint = "pca_explained_variance_ratio", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status pca_explained_variance_ratio(ulong model, float* output, int output_len, int* written);
+
+        [DllImport(__DllName, EntryPoint = "fit_pca", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status fit_pca(float* inputs_ptr, int input_length, int inputs_sub, int n_components, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "init_dql", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status init_dql(int state_dim, int action_dim, float learning_rate, float gamma, float epsilon, float epsilon_min, float epsilon_decay, ulong seed, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "delete_dql", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status delete_dql(ulong model);
+
+        [DllImport(__DllName, EntryPoint = "check_size", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status check_size(ulong model, int* size);
+
+        [DllImport(__DllName, EntryPoint = "save_model", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status save_model(ulong model, ByteBuffer* @out);
+
+        [DllImport(__DllName, EntryPoint = "deserialize_model", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status deserialize_model(byte* ptr, nuint len, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "choose_action", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status choose_action(ulong model, float* inputs, int inputs_len, int output_len, int* available_actions, int available_actions_len, int* action);
+
+        [DllImport(__DllName, EntryPoint = "update_epsilon", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status update_epsilon(ulong model);
+
+        [DllImport(__DllName, EntryPoint = "set_dql_regularization", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status set_dql_regularization(ulong model, RegularizationParams regularization);
+
+        [DllImport(__DllName, EntryPoint = "learn_dql", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status learn_dql(ulong model, float* state, int state_len, int action, int reward, float* next_state, int next_state_len, int output_len, [MarshalAs(UnmanagedType.U1)] bool done);
+
+        [DllImport(__DllName, EntryPoint = "env_create", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status env_create(int kind, EnvironmentParams @params, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "env_delete", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status env_delete(ulong env);
+
+        [DllImport(__DllName, EntryPoint = "env_reset", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status env_reset(ulong env, int* state);
+
+        [DllImport(__DllName, EntryPoint = "env_step", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status env_step(ulong env, int action, int* next_state, float* reward, bool* done);
+
+        [DllImport(__DllName, EntryPoint = "env_truncated", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status env_truncated(ulong env, bool* truncated);
+
+        [DllImport(__DllName, EntryPoint = "env_available_actions", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status env_available_actions(ulong env, int* actions, int actions_len, int* written);
+
+        [DllImport(__DllName, EntryPoint = "env_encode_state", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status env_encode_state(ulong env, FloatBuffer* @out);
+
+        [DllImport(__DllName, EntryPoint = "env_render_ascii", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status env_render_ascii(ulong env, ByteBuffer* @out);
+
+        [DllImport(__DllName, EntryPoint = "free_float_buffer", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status free_float_buffer(FloatBuffer buffer);
+
+        [DllImport(__DllName, EntryPoint = "free_byte_buffer", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status free_byte_buffer(ByteBuffer buffer);
+
+        /// <summary>
+        ///  Message of the last failed call on this thread, null when it succeeded.
+        ///  The string is owned by the library and valid until the next call on this thread.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "rustml_last_error", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern byte* rustml_last_error();
+
+        [DllImport(__DllName, EntryPoint = "init_linear_model", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status init_linear_model(int kind, int input_size, int output_size, ulong seed, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "delete_linear_model", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status delete_linear_model(ulong model);
+
+        [DllImport(__DllName, EntryPoint = "save_linear_model", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status save_linear_model(ulong model, byte* path);
+
+        [DllImport(__DllName, EntryPoint = "load_linear_model", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status load_linear_model(byte* path, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "predict_linear_model", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status predict_linear_model(ulong model, float* inputs, int inputs_len, FloatBuffer* @out);
+
+        [DllImport(__DllName, EntryPoint = "train_linear_model", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status train_linear_model(ulong model, float* inputs_ptr, int input_length, int inputs_sub, float* output_ptr, int output_length, int output_sub, float learning_rate, int iteration);
+
+        [DllImport(__DllName, EntryPoint = "metrics_accuracy", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status metrics_accuracy(float* predictions, float* labels, int length, int sub, float* score);
+
+        [DllImport(__DllName, EntryPoint = "metrics_precision", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status metrics_precision(float* predictions, float* labels, int length, int sub, int average, float* score);
+
+        [DllImport(__DllName, EntryPoint = "metrics_recall", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status metrics_recall(float* predictions, float* labels, int length, int sub, int average, float* score);
+
+        [DllImport(__DllName, EntryPoint = "metrics_f1", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status metrics_f1(float* predictions, float* labels, int length, int sub, int average, float* score);
+
+        [DllImport(__DllName, EntryPoint = "metrics_confusion_matrix", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status metrics_confusion_matrix(float* predictions, float* labels, int length, int sub, int* output, int output_len, int* classes);
+
+        [DllImport(__DllName, EntryPoint = "metrics_log_loss", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status metrics_log_loss(float* probabilities, float* labels, int length, int sub, float* score);
+
+        [DllImport(__DllName, EntryPoint = "metrics_roc_auc", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status metrics_roc_auc(float* scores, float* labels, int length, float* score);
+
+        [DllImport(__DllName, EntryPoint = "metrics_mse", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status metrics_mse(float* predictions, float* labels, int length, float* score);
+
+        [DllImport(__DllName, EntryPoint = "metrics_mae", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status metrics_mae(float* predictions, float* labels, int length, float* score);
+
+        [DllImport(__DllName, EntryPoint = "metrics_r2", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status metrics_r2(float* predictions, float* labels, int length, int sub, float* score);
+
+        [DllImport(__DllName, EntryPoint = "metrics_classification_report", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status metrics_classification_report(float* predictions, float* labels, int length, int sub, ByteBuffer* @out);
+
+        [DllImport(__DllName, EntryPoint = "metrics_regression_report", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status metrics_regression_report(float* predictions, float* labels, int length, int sub, ByteBuffer* @out);
+
+        [DllImport(__DllName, EntryPoint = "init", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status init(int* arr, int len, int weight_init, float bias, ulong seed, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "set_hidden_activation", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status set_hidden_activation(ulong model, int hidden_activation);
+
+        [DllImport(__DllName, EntryPoint = "delete_pmc", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status delete_pmc(ulong model);
+
+        [DllImport(__DllName, EntryPoint = "save_pmc", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status save_pmc(ulong model, byte* path);
+
+        [DllImport(__DllName, EntryPoint = "load_pmc", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status load_pmc(byte* path, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "predict", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status predict(ulong model, float* inputs, int inputs_len, [MarshalAs(UnmanagedType.U1)] bool is_classification, FloatBuffer* @out);
+
+        [DllImport(__DllName, EntryPoint = "predict_into", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status predict_into(ulong model, float* inputs, int inputs_len, float* output, int output_len, [MarshalAs(UnmanagedType.U1)] bool is_classification);
+
+        [DllImport(__DllName, EntryPoint = "predict_batch", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status predict_batch(ulong model, float* inputs, int n_samples, int input_dim, float* output, int output_len, [MarshalAs(UnmanagedType.U1)] bool is_classification);
+
+        [DllImport(__DllName, EntryPoint = "propagate", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status propagate(ulong model, float* inputs, int inputs_len, [MarshalAs(UnmanagedType.U1)] bool is_classification);
+
+        /// <summary>
+        ///  Writes up to `history_capacity / 5` records `[iteration, train_loss, train_accuracy, val_loss, val_accuracy]`
+        ///  in `history_ptr` and how many were written in `written`.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "train_pmc_model", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status train_pmc_model(ulong model, float* inputs_ptr, int input_length, int inputs_sub, float* output_ptr, int output_length, int output_sub, float* valid_ptr, int valid_length, int valid_sub, float* label_ptr, int label_length, int label_sub, float learning_rate, int iteration, [MarshalAs(UnmanagedType.U1)] bool is_classification, RegularizationParams* regularization, int eval_every, int patience, float* history_ptr, int history_capacity, int* written);
+
+        [DllImport(__DllName, EntryPoint = "one_step_train_pmc", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status one_step_train_pmc(ulong model, float* inputs_ptr, int input_length, float* output_ptr, int output_length, float learning_rate, [MarshalAs(UnmanagedType.U1)] bool is_classification, RegularizationParams* regularization);
+
+        [DllImport(__DllName, EntryPoint = "delete_scaler", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status delete_scaler(ulong scaler);
+
+        [DllImport(__DllName, EntryPoint = "delete_one_hot_encoder", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status delete_one_hot_encoder(ulong encoder);
+
+        [DllImport(__DllName, EntryPoint = "save_scaler", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status save_scaler(ulong scaler, byte* path);
+
+        [DllImport(__DllName, EntryPoint = "load_scaler", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status load_scaler(byte* path, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "save_one_hot_encoder", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status save_one_hot_encoder(ulong encoder, byte* path);
+
+        [DllImport(__DllName, EntryPoint = "load_one_hot_encoder", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status load_one_hot_encoder(byte* path, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "fit_scaler", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status fit_scaler(int kind, float* inputs_ptr, int input_length, int inputs_sub, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "scaler_transform", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status scaler_transform(ulong scaler, float* data, int data_len);
+
+        [DllImport(__DllName, EntryPoint = "scaler_inverse_transform", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status scaler_inverse_transform(ulong scaler, float* data, int data_len);
+
+        [DllImport(__DllName, EntryPoint = "fit_one_hot_encoder", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status fit_one_hot_encoder(float* inputs_ptr, int input_length, int inputs_sub, int* columns_ptr, int columns_len, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "one_hot_encoder_output_size", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status one_hot_encoder_output_size(ulong encoder, int* size);
+
+        [DllImport(__DllName, EntryPoint = "one_hot_encoder_transform", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status one_hot_encoder_transform(ulong encoder, float* inputs, int inputs_len, float* output, int output_len, int* written);
+
+        [DllImport(__DllName, EntryPoint = "init_rbf", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status init_rbf(int input_size, int output_size, int num_centers, float gamma, int center_selection, int training, [MarshalAs(UnmanagedType.U1)] bool is_classification, ulong seed, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "delete_rbf", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status delete_rbf(ulong model);
+
+        [DllImport(__DllName, EntryPoint = "save_rbf", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status save_rbf(ulong model, byte* path);
+
+        [DllImport(__DllName, EntryPoint = "load_rbf", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status load_rbf(byte* path, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "predict_rbf", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status predict_rbf(ulong model, float* inputs, int inputs_len, FloatBuffer* @out);
+
+        [DllImport(__DllName, EntryPoint = "train_rbf", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status train_rbf(ulong model, float* inputs_ptr, int input_length, int inputs_sub, float* output_ptr, int output_length, int output_sub, float learning_rate, int iteration);
+
+        [DllImport(__DllName, EntryPoint = "init_q_learning", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status init_q_learning(int iterations, float gamma, float alpha, float epsilon, ulong seed, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "init_sarsa", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status init_sarsa(int num_states, int num_actions, float alpha, float gamma, float epsilon, int nb_episode, ulong seed, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "init_dyna_q", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status init_dyna_q(int iterations, float gamma, float alpha, float epsilon, int planning_steps, ulong seed, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "init_dyna_q_plus", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status init_dyna_q_plus(int iterations, float gamma, float alpha, float epsilon, int planning_steps, float kappa, ulong seed, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "init_monte_carlo_es", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status init_monte_carlo_es(int num_episodes, float gamma, ulong seed, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "init_policy_iteration", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status init_policy_iteration(int num_states, int num_actions, float* rewards, int rewards_len, float* probabilities, int probabilities_len, float gamma, int* terminal_states, int terminal_len, ulong seed, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "init_value_iteration", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status init_value_iteration(int num_states, int num_actions, float* rewards, int rewards_len, float* probabilities, int probabilities_len, float gamma, int* terminal_states, int terminal_len, float theta, ulong seed, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "delete_tabular", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status delete_tabular(ulong model);
+
+        [DllImport(__DllName, EntryPoint = "tabular_action", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status tabular_action(ulong model, int state, int* action);
+
+        [DllImport(__DllName, EntryPoint = "tabular_policy", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status tabular_policy(ulong model, int* actions, int actions_len, int* written);
+
+        [DllImport(__DllName, EntryPoint = "train_tabular", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status train_tabular(ulong model, EnvironmentCallbacks* env);
+
+        [DllImport(__DllName, EntryPoint = "init_svm", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status init_svm(int input_size, int output_size, KernelParams kernel, float c, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "delete_svm", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status delete_svm(ulong model);
+
+        [DllImport(__DllName, EntryPoint = "save_svm", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status save_svm(ulong model, byte* path);
+
+        [DllImport(__DllName, EntryPoint = "load_svm", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status load_svm(byte* path, ulong* @out);
+
+        [DllImport(__DllName, EntryPoint = "predict_svm", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status predict_svm(ulong model, float* inputs, int inputs_len, FloatBuffer* @out);
+
+        [DllImport(__DllName, EntryPoint = "predict_svm_into", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status predict_svm_into(ulong model, float* inputs, int inputs_len, float* output, int output_len);
+
+        [DllImport(__DllName, EntryPoint = "svm_support_vector_count", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status svm_support_vector_count(ulong model, int @class, int* count);
+
+        [DllImport(__DllName, EntryPoint = "svm_support_vectors", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status svm_support_vectors(ulong model, int @class, float* output, int output_len, int* written);
+
+        [DllImport(__DllName, EntryPoint = "svm_dual_coefficients", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status svm_dual_coefficients(ulong model, int @class, float* output, int output_len, int* written);
+
+        [DllImport(__DllName, EntryPoint = "svm_bias", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status svm_bias(ulong model, int @class, float* bias);
+
+        [DllImport(__DllName, EntryPoint = "train_svm", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        public static extern Status train_svm(ulong model, float* inputs_ptr, int input_length, int inputs_sub, float* output_ptr, int output_length, int output_sub, int iteration);
+
+
+    }
+
+    /// <summary>
+    ///  Sizes and seed of the built-in environments, a kind ignores the fields it does not use.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct EnvironmentParams
+    {
+        /// <summary>
+        ///  LineWorld cells, GridWorld rows.
+        /// </summary>
+        public int rows;
+        /// <summary>
+        ///  GridWorld columns.
+        /// </summary>
+        public int cols;
+        /// <summary>
+        ///  LineWorld and GridWorld cell of the agent until the first reset, which picks a random cell.
+        /// </summary>
+        public int start;
+        /// <summary>
+        ///  MontyHall doors.
+        /// </summary>
+        public int doors;
+        /// <summary>
+        ///  Generator of the random resets and moves, the secret environments ignore it.
+        /// </summary>
+        public ulong seed;
+        /// <summary>
+        ///  Steps after which an episode is truncated, 0 for episodes that only end in a terminal state.
+        /// </summary>
+        public int max_episode_steps;
+    }
+
+    /// <summary>
+    ///  Callbacks of a host environment, `user_data` is passed back to each of them.
+    ///  `random_state` is optional, Monte Carlo ES uses it for exploring starts and falls back on `reset`.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct EnvironmentCallbacks
+    {
+        public void* user_data;
+        public int num_states;
+        public int num_actions;
+        /// <summary>
+        ///  Starts an episode and returns its first state.
+        /// </summary>
+        public delegate* unmanaged[Cdecl]<void*, int> reset;
+        /// <summary>
+        ///  Plays `action` and writes the next state, the reward and whether the episode ended.
+        /// </summary>
+        public delegate* unmanaged[Cdecl]<void*, int, int*, float*, bool*, void> step;
+        /// <summary>
+        ///  Writes the actions allowed in the current state and returns how many were written.
+        /// </summary>
+        public delegate* unmanaged[Cdecl]<void*, int*, int, int> available_actions;
+        /// <summary>
+        ///  Returns the current state.
+        /// </summary>
+        public delegate* unmanaged[Cdecl]<void*, int> state_id;
+        /// <summary>
+        ///  Moves to a random state and returns it.
+        /// </summary>
+        public delegate* unmanaged[Cdecl]<void*, int> random_state;
+    }
+
+    /// <summary>
+    ///  f32 array owned by Rust and handed to the host. `ptr`, `len` and `cap` come from a `Vec&lt;f32&gt;`,
+    ///  the host reads `len` values then gives the buffer back to `free_float_buffer`.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct FloatBuffer
+    {
+        public float* ptr;
+        public nuint len;
+        public nuint cap;
+    }
+
+    /// <summary>
+    ///  Byte array owned by Rust, e.g. a serialized model. Released with `free_byte_buffer`.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct ByteBuffer
+    {
+        public byte* ptr;
+        public nuint len;
+        public nuint cap;
+    }
+
+    /// <summary>
+    ///  Regularization as passed to the exports, `clipping` is a `GradientClipping` code.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct RegularizationParams
+    {
+        /// <summary>
+        ///  L2 weight decay coefficient, biases are not decayed
+        /// </summary>
+        public float l2;
+        /// <summary>
+        ///  Probability to drop a hidden neuron (inverted dropout), only while training
+        /// </summary>
+        public float dropout;
+        public int clipping;
+        public float clip_value;
+    }
+
+    /// <summary>
+    ///  Kernel as passed to `init_svm`, `kind` is a `KernelKind` code.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct KernelParams
+    {
+        public int kind;
+        public int degree;
+        public float gamma;
+        public float coef0;
+    }
+
+
+    public enum EnvironmentKind : uint
+    {
+        LineWorld,
+        GridWorld,
+        RockPaperScissors,
+        MontyHall,
+        SecretEnv0,
+        SecretEnv1,
+        SecretEnv2,
+        SecretEnv3,
+    }
+
+    /// <summary>
+    ///  Returned by every export. Anything but `Ok` leaves a message in `rustml_last_error`
+    ///  and the out parameters untouched.
+    /// </summary>
+    public enum Status : uint
+    {
+        Ok = 0,
+        NullPointer = 1,
+        /// <summary>
+        ///  Negative length or size
+        /// </summary>
+        InvalidLength = 2,
+        /// <summary>
+        ///  Buffer length that does not match the model or the sub array size
+        /// </summary>
+        DimensionMismatch = 3,
+        InvalidArgument = 4,
+        /// <summary>
+        ///  File could not be read or written, or is not a valid model file
+        /// </summary>
+        Io = 5,
+        /// <summary>
+        ///  Rust code panicked, the model may be left half updated
+        /// </summary>
+        Panic = 6,
+        /// <summary>
+        ///  Model handle that was deleted, never created, or belongs to another kind of model
+        /// </summary>
+        InvalidHandle = 7,
+    }
+
+    public enum LinearModelKind : uint
+    {
+        /// <summary>
+        ///  Rosenblatt rule, labels and predictions in {-1, 1}
+        /// </summary>
+        Perceptron,
+        /// <summary>
+        ///  Least squares solved with the normal equation
+        /// </summary>
+        LinearRegression,
+        /// <summary>
+        ///  Sigmoid trained by gradient descent, labels &gt; 0 are the positive class, predictions are probabilities
+        /// </summary>
+        LogisticRegression,
+    }
+
+    public enum Average : uint
+    {
+        /// <summary>
+        ///  Unweighted mean of the per-class scores
+        /// </summary>
+        Macro,
+        /// <summary>
+        ///  Score of the pooled true / false positives of every class
+        /// </summary>
+        Micro,
+    }
+
+    /// <summary>
+    ///  Activation of the hidden layers. The output layer stays tanh for classification and linear for regression.
+    /// </summary>
+    public enum Activation : uint
+    {
+        /// <summary>
+        ///  tanh for classification, ReLU for regression
+        /// </summary>
+        Auto,
+        Tanh,
+        Relu,
+        Sigmoid,
+    }
+
+    /// <summary>
+    ///  Weight initialization scheme used by `create::init`.
+    /// </summary>
+    public enum WeightInit : uint
+    {
+        /// <summary>
+        ///  Legacy scheme: uniform in [-1, 1]
+        /// </summary>
+        Uniform,
+        /// <summary>
+        ///  Glorot: uniform in [-sqrt(6 / (fan_in + fan_out)), +sqrt(...)]
+        /// </summary>
+        XavierUniform,
+        /// <summary>
+        ///  Glorot: normal with std = sqrt(2 / (fan_in + fan_out))
+        /// </summary>
+        XavierNormal,
+        /// <summary>
+        ///  Kaiming: uniform in [-sqrt(6 / fan_in), +sqrt(6 / fan_in)]
+        /// </summary>
+        HeUniform,
+        /// <summary>
+        ///  Kaiming: normal with std = sqrt(2 / fan_in)
+        /// </summary>
+        HeNormal,
+    }
+
+    public enum GradientClipping : uint
+    {
+        None,
+        /// <summary>
+        ///  Clamp every gradient component to [-clip_value, clip_value]
+        /// </summary>
+        Value,
+        /// <summary>
+        ///  Rescale the whole gradient when its global L2 norm exceeds clip_value
+        /// </summary>
+        Norm,
+    }
+
+    public enum ScalerKind : uint
+    {
+        /// <summary>
+        ///  (x - min) / (max - min), features in [0, 1]
+        /// </summary>
+        MinMax,
+        /// <summary>
+        ///  (x - mean) / std
+        /// </summary>
+        Standard,
+        /// <summary>
+        ///  (x - median) / IQR, insensitive to outliers
+        /// </summary>
+        Robust,
+    }
+
+    public enum RbfCenters : uint
+    {
+        /// <summary>
+        ///  num_centers centers found by k-means++
+        /// </summary>
+        KMeans,
+        /// <summary>
+        ///  One center per training sample, num_centers is replaced by the dataset size
+        /// </summary>
+        FullSet,
+    }
+
+    public enum RbfTraining : uint
+    {
+        /// <summary>
+        ///  Exact least squares output weights (naive RBF)
+        /// </summary>
+        PseudoInverse,
+        /// <summary>
+        ///  Stochastic gradient descent on the mean squared error
+        /// </summary>
+        GradientDescent,
+    }
+
+    public enum KernelKind : uint
+    {
+        /// <summary>
+        ///  x . y
+        /// </summary>
+        Linear,
+        /// <summary>
+        ///  (gamma * x . y + coef0) ^ degree
+        /// </summary>
+        Polynomial,
+        /// <summary>
+        ///  exp(-gamma * ||x - y||^2)
+        /// </summary>
+        Rbf,
+    }
+
+
+}
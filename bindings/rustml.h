@@ -0,0 +1,790 @@
+#ifndef RUSTML_H
+#define RUSTML_H
+
+/* Generated by build.rs with cbindgen, do not edit */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define HISTORY_RECORD_SIZE 5
+
+#define POSITIVE_LABEL 1.0
+
+#define NEGATIVE_LABEL -1.0
+
+#define FORMAT_VERSION 2
+
+/**
+ * Returned by every export. Anything but `Ok` leaves a message in `rustml_last_error`
+ * and the out parameters untouched.
+ */
+typedef enum Status {
+  STATUS_OK = 0,
+  STATUS_NULL_POINTER = 1,
+  /**
+   * Negative length or size
+   */
+  STATUS_INVALID_LENGTH = 2,
+  /**
+   * Buffer length that does not match the model or the sub array size
+   */
+  STATUS_DIMENSION_MISMATCH = 3,
+  STATUS_INVALID_ARGUMENT = 4,
+  /**
+   * File could not be read or written, or is not a valid model file
+   */
+  STATUS_IO = 5,
+  /**
+   * Rust code panicked, the model may be left half updated
+   */
+  STATUS_PANIC = 6,
+  /**
+   * Model handle that was deleted, never created, or belongs to another kind of model
+   */
+  STATUS_INVALID_HANDLE = 7,
+} Status;
+
+/**
+ * Weight initialization scheme used by `create::init`.
+ */
+typedef enum WeightInit {
+  /**
+   * Legacy scheme: uniform in [-1, 1]
+   */
+  WEIGHT_INIT_UNIFORM,
+  /**
+   * Glorot: uniform in [-sqrt(6 / (fan_in + fan_out)), +sqrt(...)]
+   */
+  WEIGHT_INIT_XAVIER_UNIFORM,
+  /**
+   * Glorot: normal with std = sqrt(2 / (fan_in + fan_out))
+   */
+  WEIGHT_INIT_XAVIER_NORMAL,
+  /**
+   * Kaiming: uniform in [-sqrt(6 / fan_in), +sqrt(6 / fan_in)]
+   */
+  WEIGHT_INIT_HE_UNIFORM,
+  /**
+   * Kaiming: normal with std = sqrt(2 / fan_in)
+   */
+  WEIGHT_INIT_HE_NORMAL,
+} WeightInit;
+
+/**
+ * Activation of the hidden layers. The output layer stays tanh for classification and linear for regression.
+ */
+typedef enum Activation {
+  /**
+   * tanh for classification, ReLU for regression
+   */
+  ACTIVATION_AUTO,
+  ACTIVATION_TANH,
+  ACTIVATION_RELU,
+  ACTIVATION_SIGMOID,
+} Activation;
+
+typedef enum GradientClipping {
+  GRADIENT_CLIPPING_NONE,
+  /**
+   * Clamp every gradient component to [-clip_value, clip_value]
+   */
+  GRADIENT_CLIPPING_VALUE,
+  /**
+   * Rescale the whole gradient when its global L2 norm exceeds clip_value
+   */
+  GRADIENT_CLIPPING_NORM,
+} GradientClipping;
+
+typedef enum LinearModelKind {
+  /**
+   * Rosenblatt rule, labels and predictions in {-1, 1}
+   */
+  LINEAR_MODEL_KIND_PERCEPTRON,
+  /**
+   * Least squares solved with the normal equation
+   */
+  LINEAR_MODEL_KIND_LINEAR_REGRESSION,
+  /**
+   * Sigmoid trained by gradient descent, labels > 0 are the positive class, predictions are probabilities
+   */
+  LINEAR_MODEL_KIND_LOGISTIC_REGRESSION,
+} LinearModelKind;
+
+typedef enum RbfCenters {
+  /**
+   * num_centers centers found by k-means++
+   */
+  RBF_CENTERS_K_MEANS,
+  /**
+   * One center per training sample, num_centers is replaced by the dataset size
+   */
+  RBF_CENTERS_FULL_SET,
+} RbfCenters;
+
+typedef enum RbfTraining {
+  /**
+   * Exact least squares output weights (naive RBF)
+   */
+  RBF_TRAINING_PSEUDO_INVERSE,
+  /**
+   * Stochastic gradient descent on the mean squared error
+   */
+  RBF_TRAINING_GRADIENT_DESCENT,
+} RbfTraining;
+
+typedef enum KernelKind {
+  /**
+   * x . y
+   */
+  KERNEL_KIND_LINEAR,
+  /**
+   * (gamma * x . y + coef0) ^ degree
+   */
+  KERNEL_KIND_POLYNOMIAL,
+  /**
+   * exp(-gamma * ||x - y||^2)
+   */
+  KERNEL_KIND_RBF,
+} KernelKind;
+
+typedef enum ScalerKind {
+  /**
+   * (x - min) / (max - min), features in [0, 1]
+   */
+  SCALER_KIND_MIN_MAX,
+  /**
+   * (x - mean) / std
+   */
+  SCALER_KIND_STANDARD,
+  /**
+   * (x - median) / IQR, insensitive to outliers
+   */
+  SCALER_KIND_ROBUST,
+} ScalerKind;
+
+typedef enum Average {
+  /**
+   * Unweighted mean of the per-class scores
+   */
+  AVERAGE_MACRO,
+  /**
+   * Score of the pooled true / false positives of every class
+   */
+  AVERAGE_MICRO,
+} Average;
+
+typedef enum EnvironmentKind {
+  ENVIRONMENT_KIND_LINE_WORLD,
+  ENVIRONMENT_KIND_GRID_WORLD,
+  ENVIRONMENT_KIND_ROCK_PAPER_SCISSORS,
+  ENVIRONMENT_KIND_MONTY_HALL,
+  ENVIRONMENT_KIND_SECRET_ENV0,
+  ENVIRONMENT_KIND_SECRET_ENV1,
+  ENVIRONMENT_KIND_SECRET_ENV2,
+  ENVIRONMENT_KIND_SECRET_ENV3,
+} EnvironmentKind;
+
+/**
+ * Sizes and seed of the built-in environments, a kind ignores the fields it does not use.
+ */
+typedef struct EnvironmentParams {
+  /**
+   * LineWorld cells, GridWorld rows.
+   */
+  int32_t rows;
+  /**
+   * GridWorld columns.
+   */
+  int32_t cols;
+  /**
+   * LineWorld and GridWorld cell of the agent until the first reset, which picks a random cell.
+   */
+  int32_t start;
+  /**
+   * MontyHall doors.
+   */
+  int32_t doors;
+  /**
+   * Generator of the random resets and moves, the secret environments ignore it.
+   */
+  uint64_t seed;
+  /**
+   * Steps after which an episode is truncated, 0 for episodes that only end in a terminal state.
+   */
+  int32_t max_episode_steps;
+} EnvironmentParams;
+
+/**
+ * Opaque id of a model, 0 is never a valid handle.
+ */
+typedef uint64_t Handle;
+
+/**
+ * f32 array owned by Rust and handed to the host. `ptr`, `len` and `cap` come from a `Vec<f32>`,
+ * the host reads `len` values then gives the buffer back to `free_float_buffer`.
+ */
+typedef struct FloatBuffer {
+  float *ptr;
+  size_t len;
+  size_t cap;
+} FloatBuffer;
+
+/**
+ * Byte array owned by Rust, e.g. a serialized model. Released with `free_byte_buffer`.
+ */
+typedef struct ByteBuffer {
+  uint8_t *ptr;
+  size_t len;
+  size_t cap;
+} ByteBuffer;
+
+/**
+ * Regularization as passed to the exports, `clipping` is a `GradientClipping` code.
+ */
+typedef struct RegularizationParams {
+  /**
+   * L2 weight decay coefficient, biases are not decayed
+   */
+  float l2;
+  /**
+   * Probability to drop a hidden neuron (inverted dropout), only while training
+   */
+  float dropout;
+  int32_t clipping;
+  float clip_value;
+} RegularizationParams;
+
+/**
+ * Kernel as passed to `init_svm`, `kind` is a `KernelKind` code.
+ */
+typedef struct KernelParams {
+  int32_t kind;
+  int32_t degree;
+  float gamma;
+  float coef0;
+} KernelParams;
+
+/**
+ * Callbacks of a host environment, `user_data` is passed back to each of them.
+ * `random_state` is optional, Monte Carlo ES uses it for exploring starts and falls back on `reset`.
+ */
+typedef struct EnvironmentCallbacks {
+  void *user_data;
+  int32_t num_states;
+  int32_t num_actions;
+  /**
+   * Starts an episode and returns its first state.
+   */
+  int32_t (*reset)(void *user_data);
+  /**
+   * Plays `action` and writes the next state, the reward and whether the episode ended.
+   */
+  void (*step)(void *user_data, int32_t action, int32_t *next_state, float *reward, bool *done);
+  /**
+   * Writes the actions allowed in the current state and returns how many were written.
+   */
+  int32_t (*available_actions)(void *user_data, int32_t *actions, int32_t capacity);
+  /**
+   * Returns the current state.
+   */
+  int32_t (*state_id)(void *user_data);
+  /**
+   * Moves to a random state and returns it.
+   */
+  int32_t (*random_state)(void *user_data);
+} EnvironmentCallbacks;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+enum Status env_create(int32_t kind, struct EnvironmentParams params, Handle *out);
+
+enum Status env_reset(Handle env, int32_t *state);
+
+enum Status env_step(Handle env, int32_t action, int32_t *next_state, float *reward, bool *done);
+
+enum Status env_truncated(Handle env, bool *truncated);
+
+enum Status env_available_actions(Handle env,
+                                  int32_t *actions,
+                                  int32_t actions_len,
+                                  int32_t *written);
+
+enum Status env_encode_state(Handle env, struct FloatBuffer *out);
+
+enum Status env_render_ascii(Handle env, struct ByteBuffer *out);
+
+enum Status env_delete(Handle env);
+
+enum Status init(const int32_t *arr,
+                 int32_t len,
+                 int32_t weight_init,
+                 float bias,
+                 uint64_t seed,
+                 Handle *out);
+
+enum Status set_hidden_activation(Handle model, int32_t hidden_activation);
+
+enum Status delete_pmc(Handle model);
+
+enum Status propagate(Handle model,
+                      const float *inputs,
+                      int32_t inputs_len,
+                      bool is_classification);
+
+/**
+ * Writes up to `history_capacity / 5` records `[iteration, train_loss, train_accuracy, val_loss, val_accuracy]`
+ * in `history_ptr` and how many were written in `written`.
+ */
+enum Status train_pmc_model(Handle model,
+                            const float *inputs_ptr,
+                            int32_t input_length,
+                            int32_t inputs_sub,
+                            const float *output_ptr,
+                            int32_t output_length,
+                            int32_t output_sub,
+                            const float *valid_ptr,
+                            int32_t valid_length,
+                            int32_t valid_sub,
+                            const float *label_ptr,
+                            int32_t label_length,
+                            int32_t label_sub,
+                            float learning_rate,
+                            int32_t iteration,
+                            bool is_classification,
+                            const struct RegularizationParams *regularization,
+                            int32_t eval_every,
+                            int32_t patience,
+                            float *history_ptr,
+                            int32_t history_capacity,
+                            int32_t *written);
+
+enum Status one_step_train_pmc(Handle model,
+                               const float *inputs_ptr,
+                               int32_t input_length,
+                               const float *output_ptr,
+                               int32_t output_length,
+                               float learning_rate,
+                               bool is_classification,
+                               const struct RegularizationParams *regularization);
+
+enum Status predict(Handle model,
+                    const float *inputs,
+                    int32_t inputs_len,
+                    bool is_classification,
+                    struct FloatBuffer *out);
+
+enum Status predict_into(Handle model,
+                         const float *inputs,
+                         int32_t inputs_len,
+                         float *output,
+                         int32_t output_len,
+                         bool is_classification);
+
+enum Status predict_batch(Handle model,
+                          const float *inputs,
+                          int32_t n_samples,
+                          int32_t input_dim,
+                          float *output,
+                          int32_t output_len,
+                          bool is_classification);
+
+enum Status save_pmc(Handle model, const char *path);
+
+enum Status load_pmc(const char *path, Handle *out);
+
+enum Status init_linear_model(int32_t kind,
+                              int32_t input_size,
+                              int32_t output_size,
+                              uint64_t seed,
+                              Handle *out);
+
+enum Status delete_linear_model(Handle model);
+
+enum Status train_linear_model(Handle model,
+                               const float *inputs_ptr,
+                               int32_t input_length,
+                               int32_t inputs_sub,
+                               const float *output_ptr,
+                               int32_t output_length,
+                               int32_t output_sub,
+                               float learning_rate,
+                               int32_t iteration);
+
+enum Status predict_linear_model(Handle model,
+                                 const float *inputs,
+                                 int32_t inputs_len,
+                                 struct FloatBuffer *out);
+
+enum Status save_linear_model(Handle model, const char *path);
+
+enum Status load_linear_model(const char *path, Handle *out);
+
+enum Status init_rbf(int32_t input_size,
+                     int32_t output_size,
+                     int32_t num_centers,
+                     float gamma,
+                     int32_t center_selection,
+                     int32_t training,
+                     bool is_classification,
+                     uint64_t seed,
+                     Handle *out);
+
+enum Status delete_rbf(Handle model);
+
+enum Status train_rbf(Handle model,
+                      const float *inputs_ptr,
+                      int32_t input_length,
+                      int32_t inputs_sub,
+                      const float *output_ptr,
+                      int32_t output_length,
+                      int32_t output_sub,
+                      float learning_rate,
+                      int32_t iteration);
+
+enum Status predict_rbf(Handle model,
+                        const float *inputs,
+                        int32_t inputs_len,
+                        struct FloatBuffer *out);
+
+enum Status save_rbf(Handle model, const char *path);
+
+enum Status load_rbf(const char *path, Handle *out);
+
+enum Status init_svm(int32_t input_size,
+                     int32_t output_size,
+                     struct KernelParams kernel,
+                     float c,
+                     Handle *out);
+
+enum Status delete_svm(Handle model);
+
+enum Status train_svm(Handle model,
+                      const float *inputs_ptr,
+                      int32_t input_length,
+                      int32_t inputs_sub,
+                      const float *output_ptr,
+                      int32_t output_length,
+                      int32_t output_sub,
+                      int32_t iteration);
+
+enum Status predict_svm(Handle model,
+                        const float *inputs,
+                        int32_t inputs_len,
+                        struct FloatBuffer *out);
+
+enum Status predict_svm_into(Handle model,
+                             const float *inputs,
+                             int32_t inputs_len,
+                             float *output,
+                             int32_t output_len);
+
+enum Status svm_support_vector_count(Handle model, int32_t class_, int32_t *count);
+
+enum Status svm_support_vectors(Handle model,
+                                int32_t class_,
+                                float *output,
+                                int32_t output_len,
+                                int32_t *written);
+
+enum Status svm_dual_coefficients(Handle model,
+                                  int32_t class_,
+                                  float *output,
+                                  int32_t output_len,
+                                  int32_t *written);
+
+enum Status svm_bias(Handle model, int32_t class_, float *bias);
+
+enum Status save_svm(Handle model, const char *path);
+
+enum Status load_svm(const char *path, Handle *out);
+
+enum Status fit_scaler(int32_t kind,
+                       const float *inputs_ptr,
+                       int32_t input_length,
+                       int32_t inputs_sub,
+                       Handle *out);
+
+enum Status scaler_transform(Handle scaler, float *data, int32_t data_len);
+
+enum Status scaler_inverse_transform(Handle scaler, float *data, int32_t data_len);
+
+enum Status fit_one_hot_encoder(const float *inputs_ptr,
+                                int32_t input_length,
+                                int32_t inputs_sub,
+                                const int32_t *columns_ptr,
+                                int32_t columns_len,
+                                Handle *out);
+
+enum Status one_hot_encoder_output_size(Handle encoder, int32_t *size);
+
+enum Status one_hot_encoder_transform(Handle encoder,
+                                      const float *inputs,
+                                      int32_t inputs_len,
+                                      float *output,
+                                      int32_t output_len,
+                                      int32_t *written);
+
+enum Status delete_scaler(Handle scaler);
+
+enum Status delete_one_hot_encoder(Handle encoder);
+
+enum Status save_scaler(Handle scaler, const char *path);
+
+enum Status load_scaler(const char *path, Handle *out);
+
+enum Status save_one_hot_encoder(Handle encoder, const char *path);
+
+enum Status load_one_hot_encoder(const char *path, Handle *out);
+
+enum Status metrics_accuracy(const float *predictions,
+                             const float *labels,
+                             int32_t length,
+                             int32_t sub,
+                             float *score);
+
+enum Status metrics_precision(const float *predictions,
+                              const float *labels,
+                              int32_t length,
+                              int32_t sub,
+                              int32_t average,
+                              float *score);
+
+enum Status metrics_recall(const float *predictions,
+                           const float *labels,
+                           int32_t length,
+                           int32_t sub,
+                           int32_t average,
+                           float *score);
+
+enum Status metrics_f1(const float *predictions,
+                       const float *labels,
+                       int32_t length,
+                       int32_t sub,
+                       int32_t average,
+                       float *score);
+
+enum Status metrics_confusion_matrix(const float *predictions,
+                                     const float *labels,
+                                     int32_t length,
+                                     int32_t sub,
+                                     int32_t *output,
+                                     int32_t output_len,
+                                     int32_t *classes);
+
+enum Status metrics_log_loss(const float *probabilities,
+                             const float *labels,
+                             int32_t length,
+                             int32_t sub,
+                             float *score);
+
+enum Status metrics_roc_auc(const float *scores, const float *labels, int32_t length, float *score);
+
+enum Status metrics_mse(const float *predictions,
+                        const float *labels,
+                        int32_t length,
+                        float *score);
+
+enum Status metrics_mae(const float *predictions,
+                        const float *labels,
+                        int32_t length,
+                        float *score);
+
+enum Status metrics_r2(const float *predictions,
+                       const float *labels,
+                       int32_t length,
+                       int32_t sub,
+                       float *score);
+
+enum Status metrics_classification_report(const float *predictions,
+                                          const float *labels,
+                                          int32_t length,
+                                          int32_t sub,
+                                          struct ByteBuffer *out);
+
+enum Status metrics_regression_report(const float *predictions,
+                                      const float *labels,
+                                      int32_t length,
+                                      int32_t sub,
+                                      struct ByteBuffer *out);
+
+enum Status fit_kmeans(const float *inputs_ptr,
+                       int32_t input_length,
+                       int32_t inputs_sub,
+                       int32_t k,
+                       int32_t max_iterations,
+                       uint64_t seed,
+                       Handle *out);
+
+enum Status predict_kmeans(Handle model, const float *inputs, int32_t inputs_len, int32_t *cluster);
+
+enum Status kmeans_inertia(Handle model, float *inertia);
+
+enum Status kmeans_centers(Handle model, float *output, int32_t output_len, int32_t *written);
+
+enum Status kmeans_silhouette(const float *inputs_ptr,
+                              int32_t input_length,
+                              int32_t inputs_sub,
+                              const int32_t *assignments_ptr,
+                              int32_t assignments_len,
+                              float *score);
+
+enum Status delete_kmeans(Handle model);
+
+enum Status save_kmeans(Handle model, const char *path);
+
+enum Status load_kmeans(const char *path, Handle *out);
+
+enum Status fit_pca(const float *inputs_ptr,
+                    int32_t input_length,
+                    int32_t inputs_sub,
+                    int32_t n_components,
+                    Handle *out);
+
+enum Status pca_transform(Handle model,
+                          const float *inputs,
+                          int32_t inputs_len,
+                          float *output,
+                          int32_t output_len,
+                          int32_t *written);
+
+enum Status pca_explained_variance_ratio(Handle model,
+                                         float *output,
+                                         int32_t output_len,
+                                         int32_t *written);
+
+enum Status delete_pca(Handle model);
+
+enum Status save_pca(Handle model, const char *path);
+
+enum Status load_pca(const char *path, Handle *out);
+
+enum Status add(uint64_t left, uint64_t right, uint64_t *result);
+
+enum Status free_float_buffer(struct FloatBuffer buffer);
+
+enum Status free_byte_buffer(struct ByteBuffer buffer);
+
+/**
+ * Message of the last failed call on this thread, null when it succeeded.
+ * The string is owned by the library and valid until the next call on this thread.
+ */
+const char *rustml_last_error(void);
+
+enum Status init_dql(int32_t state_dim,
+                     int32_t action_dim,
+                     float learning_rate,
+                     float gamma,
+                     float epsilon,
+                     float epsilon_min,
+                     float epsilon_decay,
+                     uint64_t seed,
+                     Handle *out);
+
+enum Status delete_dql(Handle model);
+
+enum Status choose_action(Handle model,
+                          const float *inputs,
+                          int32_t inputs_len,
+                          int32_t output_len,
+                          const int32_t *available_actions,
+                          int32_t available_actions_len,
+                          int32_t *action);
+
+enum Status update_epsilon(Handle model);
+
+enum Status set_dql_regularization(Handle model, struct RegularizationParams regularization);
+
+enum Status learn_dql(Handle model,
+                      const float *state,
+                      int32_t state_len,
+                      int32_t action,
+                      int32_t reward,
+                      const float *next_state,
+                      int32_t next_state_len,
+                      int32_t output_len,
+                      bool done);
+
+enum Status check_size(Handle model, int32_t *size);
+
+enum Status save_model(Handle model, struct ByteBuffer *out);
+
+enum Status deserialize_model(const uint8_t *ptr, size_t len, Handle *out);
+
+enum Status init_q_learning(int32_t iterations,
+                            float gamma,
+                            float alpha,
+                            float epsilon,
+                            uint64_t seed,
+                            Handle *out);
+
+enum Status init_sarsa(int32_t num_states,
+                       int32_t num_actions,
+                       float alpha,
+                       float gamma,
+                       float epsilon,
+                       int32_t nb_episode,
+                       uint64_t seed,
+                       Handle *out);
+
+enum Status init_dyna_q(int32_t iterations,
+                        float gamma,
+                        float alpha,
+                        float epsilon,
+                        int32_t planning_steps,
+                        uint64_t seed,
+                        Handle *out);
+
+enum Status init_dyna_q_plus(int32_t iterations,
+                             float gamma,
+                             float alpha,
+                             float epsilon,
+                             int32_t planning_steps,
+                             float kappa,
+                             uint64_t seed,
+                             Handle *out);
+
+enum Status init_monte_carlo_es(int32_t num_episodes, float gamma, uint64_t seed, Handle *out);
+
+enum Status init_policy_iteration(int32_t num_states,
+                                  int32_t num_actions,
+                                  const float *rewards,
+                                  int32_t rewards_len,
+                                  const float *probabilities,
+                                  int32_t probabilities_len,
+                                  float gamma,
+                                  const int32_t *terminal_states,
+                                  int32_t terminal_len,
+                                  uint64_t seed,
+                                  Handle *out);
+
+enum Status init_value_iteration(int32_t num_states,
+                                 int32_t num_actions,
+                                 const float *rewards,
+                                 int32_t rewards_len,
+                                 const float *probabilities,
+                                 int32_t probabilities_len,
+                                 float gamma,
+                                 const int32_t *terminal_states,
+                                 int32_t terminal_len,
+                                 float theta,
+                                 uint64_t seed,
+                                 Handle *out);
+
+enum Status train_tabular(Handle model, const struct EnvironmentCallbacks *env);
+
+enum Status tabular_action(Handle model, int32_t state, int32_t *action);
+
+enum Status tabular_policy(Handle model, int32_t *actions, int32_t actions_len, int32_t *written);
+
+enum Status delete_tabular(Handle model);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* RUSTML_H */
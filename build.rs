@@ -0,0 +1,50 @@
+// Generates the C header and the C# bindings of the #[no_mangle] exports in OUT_DIR.
+// The committed copies in bindings/ are only rewritten with RUSTML_UPDATE_BINDINGS=1,
+// tests/bindings.rs fails when they are stale.
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn rust_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir).unwrap().map(|entry| entry.unwrap().path()).collect();
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            rust_files(&path, files);
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+fn main() {
+    let crate_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-env-changed=RUSTML_UPDATE_BINDINGS");
+
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml")).unwrap();
+    let code_enums = config.export.include.clone();
+    cbindgen::generate_with_config(&crate_dir, config)
+        .expect("unable to generate the C header")
+        .write_to_file(out_dir.join("rustml.h"));
+
+    let mut files = Vec::new();
+    rust_files(&crate_dir.join("src"), &mut files);
+    files.into_iter()
+        .fold(csbindgen::Builder::default(), |builder, file| builder.input_extern_file(file))
+        .always_included_types(code_enums)
+        .csharp_dll_name("rustml")
+        .csharp_namespace("RustMl")
+        .csharp_class_name("NativeMethods")
+        .csharp_class_accessibility("public")
+        .generate_csharp_file(out_dir.join("NativeMethods.g.cs"))
+        .expect("unable to generate the C# bindings");
+
+    if env::var_os("RUSTML_UPDATE_BINDINGS").is_some_and(|value| value == "1") {
+        for file in ["rustml.h", "NativeMethods.g.cs"] {
+            fs::copy(out_dir.join(file), crate_dir.join("bindings").join(file)).unwrap();
+        }
+    }
+}
@@ -1,6 +1,12 @@
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, FfiError, Status};
+
 #[no_mangle]
-pub extern "C" fn add(left: u64, right: u64) -> u64 {
-    left + right
+pub extern "C" fn add(left: u64, right: u64, result: *mut u64) -> Status {
+    ffi_call(|| {
+        let sum = left.checked_add(right).ok_or_else(|| FfiError::invalid("overflow"))?;
+        args::write(result, sum, "result")
+    })
 }
 
 #[cfg(test)]
@@ -9,7 +15,9 @@ mod tests {
 
     #[test]
     fn it_works() {
-        let result = add(2, 2);
+        let mut result = 0;
+        assert_eq!(add(2, 2, &mut result), Status::Ok);
         assert_eq!(result, 4);
+        assert_eq!(add(u64::MAX, 1, &mut result), Status::InvalidArgument);
     }
 }
\ No newline at end of file
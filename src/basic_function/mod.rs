@@ -1,2 +1,4 @@
 pub mod init;
+pub mod random;
+pub mod storage;
 pub mod tools;
\ No newline at end of file
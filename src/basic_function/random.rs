@@ -0,0 +1,54 @@
+use bincode::de::Decoder;
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+use bincode::{Decode, Encode};
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+/// Generator owned by an environment or an agent, two runs with the same seed draw the same values.
+/// Equality compares the generator state, so two agents are equal only if they will draw the same values.
+///
+/// The generator is not saved with a model: decoding reseeds it from entropy, so a loaded model
+/// is not reproducible and is not equal to the model it was saved from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeededRng(StdRng);
+
+impl SeededRng {
+    pub fn new(seed: u64) -> SeededRng {
+        SeededRng(StdRng::seed_from_u64(seed))
+    }
+
+    /// Not reproducible, seeded from the thread generator.
+    pub fn from_entropy() -> SeededRng {
+        SeededRng(StdRng::from_rng(&mut rand::rng()))
+    }
+}
+
+impl RngCore for SeededRng {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.0.fill_bytes(dst)
+    }
+}
+
+// Nothing in the payload, the saved formats are unchanged
+impl Encode for SeededRng {
+    fn encode<E: Encoder>(&self, _encoder: &mut E) -> Result<(), EncodeError> {
+        Ok(())
+    }
+}
+
+impl<Context> Decode<Context> for SeededRng {
+    fn decode<D: Decoder<Context = Context>>(_decoder: &mut D) -> Result<Self, DecodeError> {
+        Ok(SeededRng::from_entropy())
+    }
+}
+
+bincode::impl_borrow_decode!(SeededRng);
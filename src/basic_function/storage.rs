@@ -0,0 +1,152 @@
+use std::fs;
+use std::io;
+use bincode::config;
+use bincode::{Decode, Encode};
+
+// File layout: magic | format version (u16) | model kind (u16) | payload length (u64) | checksum (u64) | bincode payload
+pub const MAGIC: [u8; 4] = *b"RSML";
+// Version written by encode_model. 2: DeepQLearning payload without the self_ptr field
+pub const FORMAT_VERSION: u16 = 2;
+const HEADER_SIZE: usize = 24;
+
+/// Model stored in a file, checked on load so a KMeans file is never decoded as a PMC.
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModelKind {
+    NeuralNetwork = 1,
+    LinearModel = 2,
+    Rbf = 3,
+    Svm = 4,
+    KMeans = 5,
+    Pca = 6,
+    Scaler = 7,
+    OneHotEncoder = 8,
+    LabelEncoder = 9,
+    DeepQLearning = 10,
+}
+
+impl ModelKind {
+    fn from_u16(value: u16) -> Option<ModelKind> {
+        use ModelKind::*;
+        [NeuralNetwork, LinearModel, Rbf, Svm, KMeans, Pca, Scaler, OneHotEncoder, LabelEncoder, DeepQLearning]
+            .into_iter()
+            .find(|kind| *kind as u16 == value)
+    }
+
+    /// Oldest format version whose payload still decodes as this kind, kinds untouched by a
+    /// version bump keep reading the older files.
+    pub fn oldest_version(self) -> u16 {
+        match self {
+            ModelKind::DeepQLearning => 2,
+            _ => 1,
+        }
+    }
+}
+
+// FNV-1a, enough to catch truncated or corrupted files
+fn checksum(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Header followed by the bincode payload of `value`.
+pub fn encode_model<T: Encode>(value: &T, kind: ModelKind) -> io::Result<Vec<u8>> {
+    let payload = bincode::encode_to_vec(value, config::standard())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let mut bytes = Vec::with_capacity(HEADER_SIZE + payload.len());
+    bytes.extend_from_slice(&MAGIC);
+    bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&(kind as u16).to_le_bytes());
+    bytes.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(&checksum(&payload).to_le_bytes());
+    bytes.extend_from_slice(&payload);
+    Ok(bytes)
+}
+
+/// Checks the header against `kind` before decoding, every mismatch is an InvalidData error.
+pub fn decode_model<T: Decode<()>>(bytes: &[u8], kind: ModelKind) -> io::Result<T> {
+    if bytes.len() < HEADER_SIZE || bytes[0..4] != MAGIC {
+        return Err(invalid_data("not a rustml model file".to_string()));
+    }
+
+    let found = u16::from_le_bytes([bytes[6], bytes[7]]);
+    if found != kind as u16 {
+        let found = ModelKind::from_u16(found).map_or(format!("unknown kind {}", found), |k| format!("{:?}", k));
+        return Err(invalid_data(format!("expected a {:?} file, found {}", kind, found)));
+    }
+
+    let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+    if !(kind.oldest_version()..=FORMAT_VERSION).contains(&version) {
+        return Err(invalid_data(format!("unsupported {:?} format version {}, expected {} to {}",
+                                        kind, version, kind.oldest_version(), FORMAT_VERSION)));
+    }
+
+    let length = u64::from_le_bytes(bytes[8..16].try_into().unwrap()) as usize;
+    let payload = &bytes[HEADER_SIZE..];
+    if payload.len() != length {
+        return Err(invalid_data(format!("payload is {} bytes, header announces {}", payload.len(), length)));
+    }
+    if checksum(payload) != u64::from_le_bytes(bytes[16..24].try_into().unwrap()) {
+        return Err(invalid_data("checksum mismatch, the file is corrupted".to_string()));
+    }
+
+    let (decoded, _): (T, usize) = bincode::decode_from_slice(payload, config::standard())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok(decoded)
+}
+
+pub(crate) fn save_model_file<T: Encode>(value: &T, kind: ModelKind, path: &str) -> io::Result<()> {
+    fs::write(path, encode_model(value, kind)?)
+}
+
+pub(crate) fn load_model_file<T: Decode<()>>(kind: ModelKind, path: &str) -> io::Result<T> {
+    decode_model(&fs::read(path)?, kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let value = vec![1.5f32, -2.0, 3.25];
+        let bytes = encode_model(&value, ModelKind::Pca).unwrap();
+        assert_eq!(&bytes[0..4], b"RSML");
+        assert_eq!(decode_model::<Vec<f32>>(&bytes, ModelKind::Pca).unwrap(), value);
+    }
+
+    #[test]
+    fn rejects_bad_files() {
+        let bytes = encode_model(&vec![1.0f32, 2.0], ModelKind::Svm).unwrap();
+
+        // Headerless bincode, as written by older versions
+        let legacy = bincode::encode_to_vec(vec![1.0f32, 2.0], config::standard()).unwrap();
+        assert!(decode_model::<Vec<f32>>(&legacy, ModelKind::Svm).is_err());
+
+        let wrong_kind = decode_model::<Vec<f32>>(&bytes, ModelKind::KMeans).unwrap_err();
+        assert!(wrong_kind.to_string().contains("Svm"));
+
+        let mut future = bytes.clone();
+        future[4] = 99;
+        assert!(decode_model::<Vec<f32>>(&future, ModelKind::Svm).unwrap_err().to_string().contains("version 99"));
+
+        // Version 1 payloads only changed for DeepQLearning
+        let mut v1 = bytes.clone();
+        v1[4] = 1;
+        assert_eq!(decode_model::<Vec<f32>>(&v1, ModelKind::Svm).unwrap(), vec![1.0, 2.0]);
+        let mut v1 = encode_model(&vec![1.0f32, 2.0], ModelKind::DeepQLearning).unwrap();
+        v1[4] = 1;
+        assert!(decode_model::<Vec<f32>>(&v1, ModelKind::DeepQLearning).unwrap_err().to_string().contains("version 1"));
+
+        let mut corrupted = bytes.clone();
+        *corrupted.last_mut().unwrap() ^= 0xff;
+        assert!(decode_model::<Vec<f32>>(&corrupted, ModelKind::Svm).unwrap_err().to_string().contains("checksum"));
+
+        assert!(decode_model::<Vec<f32>>(&bytes[..bytes.len() - 1], ModelKind::Svm).is_err());
+        assert_eq!(decode_model::<Vec<f32>>(&[], ModelKind::Svm).unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+}
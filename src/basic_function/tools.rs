@@ -1,3 +1,4 @@
+use crate::linalg;
 use crate::neural_network::nn_struct::NeuralNetwork;
 
 pub fn recompose_2d_vec(ptr: *const f32, array_length: i32, sub_array: i32) -> Vec<Vec<f32>>{
@@ -16,23 +17,11 @@ pub fn recompose_vec<T>(ptr: *const T, array_length: i32) -> Vec<T> where T: Clo
     slice.to_vec()
 }
 
-pub fn argmax(vector: &Vec<f32>) -> i32 {
+// Index of the largest value, NaN are skipped (0 when every value is NaN)
+pub fn argmax(vector: &[f32]) -> i32 {
     assert!(!vector.is_empty(), "The vector should not be empty.");
 
-    let mut max_index = 0;
-    let mut max_value = f32::NEG_INFINITY;
-
-    for (index, &value) in vector.iter().enumerate() {
-        if value.is_nan() {
-            continue;
-        }
-        if value > max_value {
-            max_value = value;
-            max_index = index;
-        }
-    }
-
-    max_index as i32
+    linalg::vector::argmax(vector).unwrap_or(0) as i32
 }
 
 pub fn clone_neural_network_ptr(original_model : *mut NeuralNetwork) -> *mut NeuralNetwork {
@@ -51,14 +40,6 @@ pub fn free_clone_neural_network_ptr(ptr : *mut NeuralNetwork) {
 }
 
 
-pub(crate) fn free_vec(ptr: *mut [f32]) {
-    if !ptr.is_null() {
-        unsafe {
-            let _ = Box::from_raw(ptr);
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,4 +93,4 @@ mod tests {
         let index_max = argmax(&input_data);
         assert_eq!(index_max, 1);
     }
-}
\ No newline at end of file
+}
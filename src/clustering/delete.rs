@@ -0,0 +1,12 @@
+use super::kmeans::KMeans;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
+
+#[no_mangle]
+extern "C" fn delete_kmeans(model: Handle) -> Status {
+    ffi_call(|| {
+        handle::remove::<KMeans>(model)?;
+        println!("Model deleted");
+        Ok(())
+    })
+}
@@ -0,0 +1,110 @@
+use bincode::{Decode, Encode};
+use rand::distr::weighted::WeightedIndex;
+use rand::distr::Distribution;
+use rand::Rng;
+
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub struct KMeans {
+    pub k: usize,
+    pub dimension: usize,
+    // Row-major: k rows of dimension coordinates
+    pub centers: Vec<f32>,
+    /// Sum of squared distances of the training points to their center
+    pub inertia: f32,
+    /// Lloyd iterations run before convergence
+    pub iterations: usize,
+}
+
+impl KMeans {
+    /// k-means++ seeding then Lloyd iterations until no point changes cluster.
+    /// k is capped to the number of points, an empty cluster keeps its previous center.
+    pub fn fit<R: Rng>(points: &[Vec<f32>], k: usize, max_iterations: usize, rng: &mut R) -> KMeans {
+        let k = k.min(points.len());
+        let dimension = points.first().map_or(0, |point| point.len());
+        let mut centers = plus_plus_centers(points, k, rng);
+        let mut assignments = vec![usize::MAX; points.len()];
+        let mut iterations = 0;
+
+        while iterations < max_iterations {
+            let mut changed = false;
+            for (point, assignment) in points.iter().zip(assignments.iter_mut()) {
+                let nearest = nearest_center(&centers, point).0;
+                if nearest != *assignment {
+                    *assignment = nearest;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+            iterations += 1;
+
+            // Move every center to the mean of its cluster
+            let mut sums = vec![vec![0.0f32; dimension]; k];
+            let mut counts = vec![0usize; k];
+            for (point, &assignment) in points.iter().zip(&assignments) {
+                counts[assignment] += 1;
+                for (sum, &x) in sums[assignment].iter_mut().zip(point) {
+                    *sum += x;
+                }
+            }
+            for ((center, sum), &count) in centers.iter_mut().zip(sums).zip(&counts) {
+                if count > 0 {
+                    *center = sum.iter().map(|s| s / count as f32).collect();
+                }
+            }
+        }
+
+        let inertia = points.iter().map(|point| nearest_center(&centers, point).1).sum();
+        KMeans { k, dimension, centers: centers.concat(), inertia, iterations }
+    }
+
+    pub fn center_rows(&self) -> Vec<Vec<f32>> {
+        self.centers.chunks(self.dimension.max(1)).map(|center| center.to_vec()).collect()
+    }
+
+    /// Index of the closest center.
+    pub fn predict(&self, point: &[f32]) -> usize {
+        self.centers.chunks_exact(self.dimension.max(1))
+            .map(|center| squared_distance(center, point))
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map_or(0, |(index, _)| index)
+    }
+}
+
+// First center uniform, the next ones drawn proportionally to the squared distance to the closest chosen center
+fn plus_plus_centers<R: Rng>(points: &[Vec<f32>], k: usize, rng: &mut R) -> Vec<Vec<f32>> {
+    let mut centers: Vec<Vec<f32>> = Vec::with_capacity(k);
+    if k == 0 {
+        return centers;
+    }
+    centers.push(points[rng.random_range(0..points.len())].clone());
+
+    let mut distances: Vec<f32> = points.iter().map(|point| squared_distance(&centers[0], point)).collect();
+    while centers.len() < k {
+        let next = match WeightedIndex::new(&distances) {
+            Ok(weights) => weights.sample(rng),
+            // Every point sits on a center already
+            Err(_) => rng.random_range(0..points.len()),
+        };
+        centers.push(points[next].clone());
+        for (distance, point) in distances.iter_mut().zip(points) {
+            *distance = distance.min(squared_distance(&centers[centers.len() - 1], point));
+        }
+    }
+    centers
+}
+
+pub(crate) fn squared_distance(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum()
+}
+
+// (index, squared distance) of the closest center
+fn nearest_center(centers: &[Vec<f32>], point: &[f32]) -> (usize, f32) {
+    centers.iter()
+        .map(|center| squared_distance(center, point))
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap_or((0, 0.0))
+}
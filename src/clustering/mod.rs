@@ -0,0 +1,7 @@
+pub mod kmeans;
+pub mod silhouette;
+pub mod train;
+pub mod predict;
+pub mod delete;
+pub mod manager;
+mod tester;
@@ -0,0 +1,41 @@
+use super::kmeans::KMeans;
+use super::silhouette::silhouette_score;
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, FfiError, FfiResult, Status};
+use crate::ffi::handle::{self, Handle};
+
+// Cluster of one point
+#[no_mangle]
+pub(crate) extern "C" fn predict_kmeans(model: Handle, inputs: *const f32, inputs_len: i32, cluster: *mut i32) -> Status {
+    ffi_call(|| handle::with(model, |model: &KMeans| {
+        let inputs_slice = args::slice(inputs, inputs_len, "inputs")?;
+        args::expect_len(inputs_slice.len(), model.dimension, "inputs")?;
+        args::write(cluster, model.predict(inputs_slice) as i32, "cluster")
+    }))
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn kmeans_inertia(model: Handle, inertia: *mut f32) -> Status {
+    ffi_call(|| handle::with(model, |model: &KMeans| args::write(inertia, model.inertia, "inertia")))
+}
+
+// Copy the k x dimension centers, `written` is the number of floats written
+#[no_mangle]
+pub(crate) extern "C" fn kmeans_centers(model: Handle, output: *mut f32, output_len: i32, written: *mut i32) -> Status {
+    ffi_call(|| handle::with(model, |model: &KMeans| args::copy_out(&model.centers, output, output_len, written)))
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn kmeans_silhouette(inputs_ptr: *const f32, input_length: i32, inputs_sub: i32,
+                                           assignments_ptr: *const i32, assignments_len: i32, score: *mut f32) -> Status {
+    ffi_call(|| {
+        let inputs = args::rows(inputs_ptr, input_length, inputs_sub, "inputs")?;
+        let assignments = args::slice(assignments_ptr, assignments_len, "assignments")?.iter()
+            .map(|&a| usize::try_from(a).map_err(|_| FfiError::invalid(format!("negative cluster {}", a))))
+            .collect::<FfiResult<Vec<usize>>>()?;
+        if assignments.len() != inputs.len() {
+            return Err(FfiError::dimension(format!("{} assignments for {} points", assignments.len(), inputs.len())));
+        }
+        args::write(score, silhouette_score(&inputs, &assignments), "score")
+    })
+}
@@ -0,0 +1,33 @@
+use super::kmeans::squared_distance;
+
+/// Mean silhouette (b - a) / max(a, b) over every point, a the mean distance to its own cluster
+/// and b to the closest other cluster. In [-1, 1], higher is better; 0 for a point alone in its cluster.
+pub fn silhouette_score(points: &[Vec<f32>], assignments: &[usize]) -> f32 {
+    let n_clusters = assignments.iter().max().map_or(0, |&max| max + 1);
+    let mut total = 0.0;
+
+    for (i, point) in points.iter().enumerate() {
+        let mut sums = vec![0.0f32; n_clusters];
+        let mut counts = vec![0usize; n_clusters];
+        for (j, other) in points.iter().enumerate() {
+            if i != j {
+                sums[assignments[j]] += squared_distance(point, other).sqrt();
+                counts[assignments[j]] += 1;
+            }
+        }
+
+        let own = assignments[i];
+        if counts[own] == 0 {
+            continue;
+        }
+        let a = sums[own] / counts[own] as f32;
+        let b = (0..n_clusters)
+            .filter(|&c| c != own && counts[c] > 0)
+            .map(|c| sums[c] / counts[c] as f32)
+            .fold(f32::INFINITY, f32::min);
+        if b.is_finite() {
+            total += (b - a) / a.max(b);
+        }
+    }
+    total / points.len() as f32
+}
@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use crate::clustering::kmeans::KMeans;
+    use crate::clustering::manager::{load_kmeans, save_kmeans};
+    use crate::clustering::predict::{kmeans_centers, kmeans_inertia, kmeans_silhouette, predict_kmeans};
+    use crate::clustering::silhouette::silhouette_score;
+    use crate::clustering::train::fit_kmeans;
+    use crate::ffi::error::Status;
+    use crate::ffi::handle;
+    use crate::ffi::test_support::{created, output, taken};
+
+    fn blobs() -> Vec<Vec<f32>> {
+        vec![
+            vec![0.0, 0.0], vec![0.2, 0.0], vec![0.0, 0.2],
+            vec![5.0, 5.0], vec![5.2, 5.0], vec![5.0, 5.2],
+        ]
+    }
+
+    #[test]
+    fn kmeans_finds_blobs() {
+        let model = KMeans::fit(&blobs(), 2, 100, &mut StdRng::seed_from_u64(0));
+        let mut centers = model.center_rows();
+        centers.sort_by(|a, b| a[0].total_cmp(&b[0]));
+
+        let expected = [[0.2 / 3.0, 0.2 / 3.0], [5.0 + 0.2 / 3.0, 5.0 + 0.2 / 3.0]];
+        for (center, expected) in centers.iter().zip(expected) {
+            assert!((center[0] - expected[0]).abs() < 1e-5);
+            assert!((center[1] - expected[1]).abs() < 1e-5);
+        }
+        assert_ne!(model.predict(&[0.1, 0.1]), model.predict(&[4.9, 5.1]));
+    }
+
+    #[test]
+    fn kmeans_inertia_by_hand() {
+        // Centers 1 and 11, every point at distance 1
+        let points = vec![vec![0.0], vec![2.0], vec![10.0], vec![12.0]];
+        let model = KMeans::fit(&points, 2, 100, &mut StdRng::seed_from_u64(3));
+        assert!((model.inertia - 4.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn kmeans_caps_k_and_is_seeded() {
+        let points = vec![vec![1.0], vec![2.0]];
+        assert_eq!(KMeans::fit(&points, 5, 10, &mut StdRng::seed_from_u64(0)).k, 2);
+        assert_eq!(KMeans::fit(&blobs(), 3, 100, &mut StdRng::seed_from_u64(7)),
+                   KMeans::fit(&blobs(), 3, 100, &mut StdRng::seed_from_u64(7)));
+    }
+
+    #[test]
+    fn silhouette_by_hand() {
+        let points = vec![vec![0.0], vec![1.0], vec![10.0], vec![11.0]];
+        let expected = (9.5 / 10.5 + 8.5 / 9.5) / 2.0;
+        assert!((silhouette_score(&points, &[0, 0, 1, 1]) - expected).abs() < 1e-5);
+        // Bad clustering scores lower
+        assert!(silhouette_score(&points, &[0, 1, 0, 1]) < 0.0);
+    }
+
+    #[test]
+    fn kmeans_ffi() {
+        let flat: Vec<f32> = blobs().concat();
+        let model = created(|out| fit_kmeans(flat.as_ptr(), flat.len() as i32, 2, 2, 100, 1, out));
+        assert!(output(|inertia| kmeans_inertia(model, inertia)) < 0.2);
+
+        let mut centers = [0.0; 4];
+        assert_eq!(output(|written| kmeans_centers(model, centers.as_mut_ptr(), 4, written)), 4);
+        let assignments: Vec<i32> = flat.chunks(2).map(|point| output(|cluster| predict_kmeans(model, point.as_ptr(), 2, cluster))).collect();
+        assert_eq!(assignments[0], assignments[2]);
+        assert_ne!(assignments[0], assignments[3]);
+        assert!(output(|score| kmeans_silhouette(flat.as_ptr(), flat.len() as i32, 2, assignments.as_ptr(), 6, score)) > 0.9);
+
+        // More clusters than points and wrong point sizes are reported
+        let mut model_ptr = 0;
+        assert_eq!(fit_kmeans(flat.as_ptr(), flat.len() as i32, 2, 7, 100, 1, &mut model_ptr), Status::InvalidArgument);
+        assert_eq!(model_ptr, 0);
+        let mut cluster = 0;
+        assert_eq!(predict_kmeans(model, flat.as_ptr(), 3, &mut cluster), Status::DimensionMismatch);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let model = KMeans::fit(&blobs(), 2, 100, &mut StdRng::seed_from_u64(0));
+        let path = std::env::temp_dir().join("rustml_kmeans_test.bin");
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+
+        let saved = handle::insert(model);
+        assert_eq!(save_kmeans(saved, c_path.as_ptr()), Status::Ok);
+        let loaded = created(|out| load_kmeans(c_path.as_ptr(), out));
+        assert_eq!(taken::<KMeans>(loaded), taken::<KMeans>(saved));
+        std::fs::remove_file(path).unwrap();
+    }
+}
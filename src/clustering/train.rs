@@ -0,0 +1,21 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use super::kmeans::KMeans;
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, FfiError, Status};
+use crate::ffi::handle::{self, Handle};
+
+#[no_mangle]
+pub(crate) extern "C" fn fit_kmeans(inputs_ptr: *const f32, input_length: i32, inputs_sub: i32,
+                                    k: i32, max_iterations: i32, seed: u64, out: *mut Handle) -> Status {
+    ffi_call(|| {
+        // Recompose Vec<Vec<f32>>
+        let inputs = args::rows(inputs_ptr, input_length, inputs_sub, "inputs")?;
+        let k = args::positive(k, "k")?;
+        if k > inputs.len() {
+            return Err(FfiError::invalid(format!("{} clusters for {} points", k, inputs.len())));
+        }
+        let mut rng = StdRng::seed_from_u64(seed);
+        handle::write(out, KMeans::fit(&inputs, k, args::length(max_iterations, "max_iterations")?, &mut rng))
+    })
+}
@@ -0,0 +1,167 @@
+use std::fs;
+use std::io;
+use super::dataset_struct::Dataset;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LabelColumn {
+    Last,
+    Index(usize),
+    /// Needs a header row
+    Name(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsvOptions {
+    pub delimiter: char,
+    /// None detects the header from the first two rows
+    pub has_header: Option<bool>,
+    pub label_column: LabelColumn,
+    /// One-hot encode numeric labels as classes, text labels are always one-hot encoded
+    pub one_hot_labels: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: ',',
+            has_header: None,
+            label_column: LabelColumn::Last,
+            one_hot_labels: false,
+        }
+    }
+}
+
+impl Dataset {
+    pub fn from_csv(path: &str, options: &CsvOptions) -> io::Result<Dataset> {
+        let text = fs::read_to_string(path)?;
+        Dataset::parse_csv(&text, options)
+    }
+
+    /// Numeric columns are kept as is, text columns are one-hot encoded (0 / 1) in place.
+    pub fn parse_csv(text: &str, options: &CsvOptions) -> io::Result<Dataset> {
+        let mut records: Vec<Vec<String>> = text.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| split_record(line, options.delimiter))
+            .collect();
+        if records.is_empty() {
+            return Err(invalid_data("empty csv"));
+        }
+        let width = records[0].len();
+        if let Some(row) = records.iter().position(|record| record.len() != width) {
+            return Err(invalid_data(&format!("row {} has {} fields, expected {}", row + 1, records[row].len(), width)));
+        }
+
+        let header = match options.has_header.unwrap_or_else(|| detect_header(&records)) {
+            true => records.remove(0),
+            false => (0..width).map(|i| format!("column_{}", i)).collect(),
+        };
+
+        let label_index = match &options.label_column {
+            LabelColumn::Last => width - 1,
+            LabelColumn::Index(index) if *index < width => *index,
+            LabelColumn::Index(index) => return Err(invalid_data(&format!("label column {} out of {}", index, width))),
+            LabelColumn::Name(name) => header.iter().position(|column| column == name)
+                .ok_or_else(|| invalid_data(&format!("no column named {}", name)))?,
+        };
+
+        let n_samples = records.len();
+        let mut columns: Vec<Vec<f32>> = Vec::new();
+        let mut feature_names = Vec::new();
+        for (index, name) in header.iter().enumerate().filter(|&(index, _)| index != label_index) {
+            let values: Vec<&str> = records.iter().map(|record| record[index].as_str()).collect();
+            match parse_numbers(&values) {
+                Some(numbers) => {
+                    columns.push(numbers);
+                    feature_names.push(name.clone());
+                }
+                None => {
+                    let categories = categories(&values);
+                    for category in &categories {
+                        columns.push(values.iter().map(|&v| if v == category { 1.0 } else { 0.0 }).collect());
+                        feature_names.push(format!("{}={}", name, category));
+                    }
+                }
+            }
+        }
+
+        let label_values: Vec<&str> = records.iter().map(|record| record[label_index].as_str()).collect();
+        let numeric_labels = parse_numbers(&label_values);
+        let (labels, label_size, class_names) = match numeric_labels {
+            Some(numbers) if !options.one_hot_labels => (numbers, 1, Vec::new()),
+            _ => {
+                let classes = categories(&label_values);
+                let labels = label_values.iter()
+                    .flat_map(|value| Dataset::one_hot(classes.iter().position(|class| class == value).unwrap(), classes.len()))
+                    .collect();
+                let count = classes.len();
+                (labels, count, classes)
+            }
+        };
+
+        let feature_size = columns.len();
+        let features = (0..n_samples)
+            .flat_map(|row| columns.iter().map(move |column| column[row]))
+            .collect();
+
+        Ok(Dataset { features, labels, n_samples, feature_size, label_size, feature_names, class_names })
+    }
+}
+
+// Fields split on the delimiter, double quotes protect delimiters and "" is an escaped quote
+fn split_record(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            c if c == delimiter && !quoted => fields.push(std::mem::take(&mut field).trim().to_string()),
+            c => field.push(c),
+        }
+    }
+    fields.push(field.trim().to_string());
+    fields
+}
+
+// A header has a text field above a number, or only text fields none of which come back below
+fn detect_header(records: &[Vec<String>]) -> bool {
+    let is_number = |value: &str| value.parse::<f32>().is_ok();
+    let first = &records[0];
+    match records.get(1) {
+        Some(second) => {
+            first.iter().zip(second).any(|(a, b)| !is_number(a) && is_number(b))
+                || (first.iter().all(|a| !is_number(a))
+                    && first.iter().enumerate().all(|(i, a)| records[1..].iter().all(|record| &record[i] != a)))
+        }
+        None => first.iter().all(|a| !is_number(a)),
+    }
+}
+
+fn parse_numbers(values: &[&str]) -> Option<Vec<f32>> {
+    values.iter().map(|value| value.parse::<f32>().ok()).collect()
+}
+
+// Distinct values, numeric order when they are all numbers
+fn categories(values: &[&str]) -> Vec<String> {
+    let mut categories: Vec<String> = Vec::new();
+    for value in values {
+        if !categories.iter().any(|category| category == value) {
+            categories.push(value.to_string());
+        }
+    }
+    match parse_numbers(&categories.iter().map(|c| c.as_str()).collect::<Vec<&str>>()) {
+        Some(_) => categories.sort_by(|a, b| a.parse::<f32>().unwrap().total_cmp(&b.parse::<f32>().unwrap())),
+        None => categories.sort(),
+    }
+    categories
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
@@ -0,0 +1,45 @@
+/// Samples ready for `train_pmc_model`: `features` and `labels` are flat row-major buffers,
+/// passed with `feature_size` and `label_size` as the sub array lengths.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Dataset {
+    pub features: Vec<f32>,
+    pub labels: Vec<f32>,
+    pub n_samples: usize,
+    pub feature_size: usize,
+    pub label_size: usize,
+    /// One name per feature, one-hot columns are named `column=value`
+    pub feature_names: Vec<String>,
+    /// Class of each one-hot label column, empty for numeric labels
+    pub class_names: Vec<String>,
+}
+
+// One-hot labels use the tanh range of the classification PMC
+pub const POSITIVE_LABEL: f32 = 1.0;
+pub const NEGATIVE_LABEL: f32 = -1.0;
+
+impl Dataset {
+    pub fn feature_rows(&self) -> Vec<Vec<f32>> {
+        self.features.chunks(self.feature_size.max(1)).map(|row| row.to_vec()).collect()
+    }
+
+    pub fn label_rows(&self) -> Vec<Vec<f32>> {
+        self.labels.chunks(self.label_size.max(1)).map(|row| row.to_vec()).collect()
+    }
+
+    /// Samples at `indices`, in that order, e.g. one part of a `preprocessing::split::Split`.
+    pub fn subset(&self, indices: &[usize]) -> Dataset {
+        Dataset {
+            features: indices.iter().flat_map(|&i| self.features[i * self.feature_size..(i + 1) * self.feature_size].iter().copied()).collect(),
+            labels: indices.iter().flat_map(|&i| self.labels[i * self.label_size..(i + 1) * self.label_size].iter().copied()).collect(),
+            n_samples: indices.len(),
+            feature_size: self.feature_size,
+            label_size: self.label_size,
+            feature_names: self.feature_names.clone(),
+            class_names: self.class_names.clone(),
+        }
+    }
+
+    pub(crate) fn one_hot(class: usize, count: usize) -> impl Iterator<Item = f32> {
+        (0..count).map(move |c| if c == class { POSITIVE_LABEL } else { NEGATIVE_LABEL })
+    }
+}
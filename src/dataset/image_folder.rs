@@ -0,0 +1,59 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use image::imageops::FilterType;
+use super::dataset_struct::Dataset;
+
+const IMAGE_EXTENSIONS: [&str; 3] = ["png", "jpg", "jpeg"];
+
+impl Dataset {
+    /// One sub folder per class (sorted by name), every image resized to width x height,
+    /// converted to grayscale and flattened with pixels in [0, 1]. Labels are one-hot.
+    pub fn from_image_folder(path: &str, width: u32, height: u32) -> io::Result<Dataset> {
+        let mut class_dirs: Vec<_> = fs::read_dir(path)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_dir())
+            .collect();
+        class_dirs.sort();
+
+        let class_names: Vec<String> = class_dirs.iter()
+            .map(|dir| dir.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        let mut features = Vec::new();
+        let mut labels = Vec::new();
+        let mut n_samples = 0;
+
+        for (class, dir) in class_dirs.iter().enumerate() {
+            let mut files: Vec<_> = fs::read_dir(dir)?
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| is_image(path))
+                .collect();
+            files.sort();
+
+            for file in files {
+                let image = image::open(&file)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", file.display(), e)))?;
+                let pixels = image.resize_exact(width, height, FilterType::Triangle).to_luma8();
+                features.extend(pixels.as_raw().iter().map(|&p| p as f32 / 255.0));
+                labels.extend(Dataset::one_hot(class, class_names.len()));
+                n_samples += 1;
+            }
+        }
+
+        Ok(Dataset {
+            features,
+            labels,
+            n_samples,
+            feature_size: (width * height) as usize,
+            label_size: class_names.len(),
+            feature_names: Vec::new(),
+            class_names,
+        })
+    }
+}
+
+fn is_image(path: &Path) -> bool {
+    path.extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+        .is_some_and(|extension| IMAGE_EXTENSIONS.contains(&extension.as_str()))
+}
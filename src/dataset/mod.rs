@@ -0,0 +1,4 @@
+pub mod dataset_struct;
+pub mod csv;
+pub mod image_folder;
+mod tester;
@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use image::{GrayImage, Luma, Rgb, RgbImage};
+    use crate::dataset::csv::{CsvOptions, LabelColumn};
+    use crate::dataset::dataset_struct::Dataset;
+    use crate::neural_network::create::init;
+    use crate::neural_network::nn_struct::NeuralNetwork;
+    use crate::neural_network::validation::evaluate;
+    use crate::neural_network::initializer::WeightInit;
+    use crate::neural_network::train::train_pmc_model;
+    use crate::ffi::test_support::{created, output, taken};
+
+    #[test]
+    fn csv_with_header_and_categories() {
+        let text = "size,color,label\n1.5,red,yes\n2,blue,no\n\n3,red,\"no\"\n";
+        let dataset = Dataset::parse_csv(text, &CsvOptions::default()).unwrap();
+
+        assert_eq!(dataset.n_samples, 3);
+        assert_eq!(dataset.feature_names, vec!["size", "color=blue", "color=red"]);
+        assert_eq!(dataset.features, vec![1.5, 0.0, 1.0, 2.0, 1.0, 0.0, 3.0, 0.0, 1.0]);
+        assert_eq!(dataset.class_names, vec!["no", "yes"]);
+        assert_eq!(dataset.labels, vec![-1.0, 1.0, 1.0, -1.0, 1.0, -1.0]);
+    }
+
+    #[test]
+    fn csv_without_header_and_label_column() {
+        let text = "5;1;0.5\n6;0;0.25\n";
+        let options = CsvOptions { delimiter: ';', label_column: LabelColumn::Index(0), ..Default::default() };
+        let dataset = Dataset::parse_csv(text, &options).unwrap();
+
+        assert_eq!(dataset.feature_names, vec!["column_1", "column_2"]);
+        assert_eq!(dataset.features, vec![1.0, 0.5, 0.0, 0.25]);
+        assert_eq!(dataset.labels, vec![5.0, 6.0]);
+        assert_eq!(dataset.label_size, 1);
+
+        let options = CsvOptions { delimiter: ';', label_column: LabelColumn::Index(0), one_hot_labels: true, ..Default::default() };
+        let dataset = Dataset::parse_csv(text, &options).unwrap();
+        assert_eq!(dataset.class_names, vec!["5", "6"]);
+        assert_eq!(dataset.label_rows(), vec![vec![1.0, -1.0], vec![-1.0, 1.0]]);
+    }
+
+    #[test]
+    fn csv_label_by_name_and_quotes() {
+        let text = "target,\"name, full\"\n1,\"a, b\"\n0,c\n";
+        let options = CsvOptions { label_column: LabelColumn::Name("target".to_string()), ..Default::default() };
+        let dataset = Dataset::parse_csv(text, &options).unwrap();
+        assert_eq!(dataset.feature_names, vec!["name, full=a, b", "name, full=c"]);
+        assert_eq!(dataset.labels, vec![1.0, 0.0]);
+    }
+
+    #[test]
+    fn csv_errors() {
+        assert!(Dataset::parse_csv("", &CsvOptions::default()).is_err());
+        assert!(Dataset::parse_csv("1,2\n3\n", &CsvOptions::default()).is_err());
+        let options = CsvOptions { label_column: LabelColumn::Name("missing".to_string()), ..Default::default() };
+        assert!(Dataset::parse_csv("a,b\n1,2\n", &options).is_err());
+    }
+
+    #[test]
+    fn csv_feeds_train_pmc_model() {
+        // XOR, labels as text
+        let path = std::env::temp_dir().join("rustml_dataset_xor.csv");
+        fs::write(&path, "x,y,class\n0,0,off\n0,1,on\n1,0,on\n1,1,off\n").unwrap();
+        let dataset = Dataset::from_csv(path.to_str().unwrap(), &CsvOptions::default()).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let npl = [dataset.feature_size as i32, 4, dataset.label_size as i32];
+        let model = created(|out| init(npl.as_ptr(), npl.len() as i32, WeightInit::XavierUniform as i32, 0.0, 7, out));
+        let written = output(|written| train_pmc_model(model,
+                        dataset.features.as_ptr(), dataset.features.len() as i32, dataset.feature_size as i32,
+                        dataset.labels.as_ptr(), dataset.labels.len() as i32, dataset.label_size as i32,
+                        std::ptr::null(), 0, dataset.feature_size as i32,
+                        std::ptr::null(), 0, dataset.label_size as i32,
+                        0.1, 20000, true, None, 0, 0, std::ptr::null_mut(), 0, written));
+        assert_eq!(written, 0);
+
+        let (_, accuracy) = evaluate(&taken::<NeuralNetwork>(model), &dataset.feature_rows(), &dataset.label_rows(), true);
+        assert_eq!(accuracy, 1.0);
+    }
+
+    #[test]
+    fn image_folder_classes() {
+        let root = std::env::temp_dir().join("rustml_dataset_images");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("dark")).unwrap();
+        fs::create_dir_all(root.join("light")).unwrap();
+        GrayImage::from_pixel(8, 8, Luma([0])).save(root.join("dark/a.png")).unwrap();
+        GrayImage::from_pixel(4, 6, Luma([51])).save(root.join("dark/b.png")).unwrap();
+        RgbImage::from_pixel(8, 8, Rgb([255, 255, 255])).save(root.join("light/c.png")).unwrap();
+        fs::write(root.join("light/notes.txt"), "not an image").unwrap();
+
+        let dataset = Dataset::from_image_folder(root.to_str().unwrap(), 2, 2).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(dataset.class_names, vec!["dark", "light"]);
+        assert_eq!(dataset.n_samples, 3);
+        assert_eq!(dataset.feature_size, 4);
+        assert_eq!(dataset.features, vec![0.0, 0.0, 0.0, 0.0, 0.2, 0.2, 0.2, 0.2, 1.0, 1.0, 1.0, 1.0]);
+        assert_eq!(dataset.label_rows(), vec![vec![1.0, -1.0], vec![1.0, -1.0], vec![-1.0, 1.0]]);
+    }
+}
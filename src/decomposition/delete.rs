@@ -0,0 +1,12 @@
+use super::pca::Pca;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
+
+#[no_mangle]
+extern "C" fn delete_pca(model: Handle) -> Status {
+    ffi_call(|| {
+        handle::remove::<Pca>(model)?;
+        println!("Model deleted");
+        Ok(())
+    })
+}
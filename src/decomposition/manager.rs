@@ -0,0 +1,28 @@
+use std::ffi::c_char;
+use std::io;
+use super::pca::Pca;
+use crate::basic_function::storage::{load_model_file, save_model_file, ModelKind};
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
+
+impl Pca {
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        save_model_file(self, ModelKind::Pca, path)
+    }
+
+    pub fn load(path: &str) -> io::Result<Pca> {
+        load_model_file(ModelKind::Pca, path)
+    }
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn save_pca(model: Handle, path: *const c_char) -> Status {
+    ffi_call(|| handle::with(model, |model: &Pca| Ok(model.save(&args::path(path)?)?)))
+}
+
+// Status::Io with the reason when the file cannot be read
+#[no_mangle]
+pub(crate) extern "C" fn load_pca(path: *const c_char, out: *mut Handle) -> Status {
+    ffi_call(|| handle::write(out, Pca::load(&args::path(path)?)?))
+}
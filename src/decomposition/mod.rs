@@ -0,0 +1,6 @@
+pub mod pca;
+pub mod train;
+pub mod predict;
+pub mod delete;
+pub mod manager;
+mod tester;
@@ -0,0 +1,104 @@
+use bincode::{Decode, Encode};
+use crate::linalg::matrix::Matrix;
+use crate::linalg::vector::Vector;
+
+const POWER_ITERATIONS: usize = 1000;
+const POWER_TOLERANCE: f64 = 1e-12;
+
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub struct Pca {
+    pub input_size: usize,
+    pub n_components: usize,
+    pub mean: Vec<f32>,
+    // Row-major: n_components unit rows of input_size, by decreasing variance
+    pub components: Vec<f32>,
+    /// Variance of the data along each component
+    pub explained_variance: Vec<f32>,
+    /// Share of the total variance carried by each component
+    pub explained_variance_ratio: Vec<f32>,
+}
+
+impl Pca {
+    /// Leading eigenvectors of the covariance by power iteration with deflation.
+    /// Each component is signed so its largest coordinate is positive.
+    pub fn fit(rows: &[Vec<f32>], n_components: usize) -> Pca {
+        let n_samples = rows.len();
+        let input_size = rows.first().map_or(0, |row| row.len());
+        let n_components = n_components.min(input_size);
+
+        let mean: Vec<f32> = (0..input_size)
+            .map(|i| rows.iter().map(|row| row[i]).sum::<f32>() / n_samples as f32)
+            .collect();
+        let centered = Matrix::from_rows(&rows.iter()
+            .map(|row| row.iter().zip(&mean).map(|(&x, &m)| (x - m) as f64).collect())
+            .collect::<Vec<Vec<f64>>>());
+        let mut covariance = centered.transpose().matmul(&centered).scale(1.0 / (n_samples.max(2) - 1) as f64);
+        let total_variance: f64 = (0..input_size).map(|i| covariance[(i, i)]).sum();
+
+        let mut components = Vec::with_capacity(n_components * input_size);
+        let mut explained_variance = Vec::with_capacity(n_components);
+        for component in 0..n_components {
+            let (eigenvalue, eigenvector) = power_iteration(&covariance, component);
+            // Remove the found direction: C -= lambda v v^T
+            for i in 0..input_size {
+                for j in 0..input_size {
+                    covariance[(i, j)] -= eigenvalue * eigenvector[i] * eigenvector[j];
+                }
+            }
+            components.extend(eigenvector.data.iter().map(|&x| x as f32));
+            explained_variance.push(eigenvalue as f32);
+        }
+
+        let explained_variance_ratio = explained_variance.iter()
+            .map(|&variance| if total_variance > 0.0 { variance / total_variance as f32 } else { 0.0 })
+            .collect();
+        Pca { input_size, n_components, mean, components, explained_variance, explained_variance_ratio }
+    }
+
+    /// Coordinates of one row in the component basis.
+    pub fn transform(&self, row: &[f32]) -> Vec<f32> {
+        self.components.chunks_exact(self.input_size)
+            .map(|component| component.iter().zip(row).zip(&self.mean).map(|((c, x), m)| c * (x - m)).sum())
+            .collect()
+    }
+
+    /// Back to the input space, exact when every component is kept.
+    pub fn inverse_transform(&self, coordinates: &[f32]) -> Vec<f32> {
+        let mut row = self.mean.clone();
+        for (component, &coordinate) in self.components.chunks_exact(self.input_size).zip(coordinates) {
+            for (x, &c) in row.iter_mut().zip(component) {
+                *x += coordinate * c;
+            }
+        }
+        row
+    }
+}
+
+// (eigenvalue, unit eigenvector) of the dominant eigenpair, the start vector depends on the component
+// so that it is not orthogonal to the answer by construction
+fn power_iteration(matrix: &Matrix<f64>, component: usize) -> (f64, Vector<f64>) {
+    let size = matrix.rows;
+    let mut vector = Vector::from((0..size).map(|i| 1.0 + ((i + component) % size) as f64 / size as f64).collect::<Vec<f64>>());
+    vector = vector.scale(1.0 / vector.norm());
+
+    for _ in 0..POWER_ITERATIONS {
+        let next = matrix.matvec(&vector);
+        let norm = next.norm();
+        if norm < POWER_TOLERANCE {
+            // Nothing left to explain
+            return (0.0, vector);
+        }
+        let next = next.scale(1.0 / norm);
+        let change = (&next - &vector).norm().min((&next + &vector).norm());
+        vector = next;
+        if change < POWER_TOLERANCE {
+            break;
+        }
+    }
+
+    let largest = vector.data.iter().copied().fold(0.0f64, |best, x| if x.abs() > best.abs() { x } else { best });
+    if largest < 0.0 {
+        vector = vector.scale(-1.0);
+    }
+    (vector.dot(&matrix.matvec(&vector)), vector)
+}
@@ -0,0 +1,30 @@
+use super::pca::Pca;
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, FfiError, Status};
+use crate::ffi::handle::{self, Handle};
+
+// Project rows of input_size into rows of n_components, `written` is the number of floats written
+#[no_mangle]
+pub(crate) extern "C" fn pca_transform(model: Handle, inputs: *const f32, inputs_len: i32, output: *mut f32, output_len: i32, written: *mut i32) -> Status {
+    ffi_call(|| handle::with(model, |model: &Pca| {
+        let inputs_slice = args::slice(inputs, inputs_len, "inputs")?;
+        let output_slice = args::slice_mut(output, output_len, "output")?;
+        if inputs_slice.len() % model.input_size != 0 {
+            return Err(FfiError::dimension(format!("inputs length {} is not a multiple of {} features", inputs_slice.len(), model.input_size)));
+        }
+        args::expect_len(output_slice.len(), inputs_slice.len() / model.input_size * model.n_components, "output")?;
+
+        let mut count = 0;
+        for (row, out) in inputs_slice.chunks_exact(model.input_size).zip(output_slice.chunks_exact_mut(model.n_components)) {
+            out.copy_from_slice(&model.transform(row));
+            count += out.len();
+        }
+        args::write(written, count as i32, "written")
+    }))
+}
+
+// Copy the explained variance ratio of each component, `written` is the number of floats written
+#[no_mangle]
+pub(crate) extern "C" fn pca_explained_variance_ratio(model: Handle, output: *mut f32, output_len: i32, written: *mut i32) -> Status {
+    ffi_call(|| handle::with(model, |model: &Pca| args::copy_out(&model.explained_variance_ratio, output, output_len, written)))
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use crate::decomposition::manager::{load_pca, save_pca};
+    use crate::decomposition::pca::Pca;
+    use crate::decomposition::predict::{pca_explained_variance_ratio, pca_transform};
+    use crate::decomposition::train::fit_pca;
+    use crate::ffi::error::Status;
+    use crate::ffi::handle;
+    use crate::ffi::test_support::{created, output, taken};
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn pca_on_a_line() {
+        // Every point on y = 2x, one direction carries all the variance
+        let rows = vec![vec![0.0, 0.0], vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]];
+        let pca = Pca::fit(&rows, 2);
+
+        assert_eq!(pca.mean, vec![1.5, 3.0]);
+        let norm = 5f32.sqrt();
+        assert!(close(pca.components[0], 1.0 / norm) && close(pca.components[1], 2.0 / norm));
+        assert!(close(pca.explained_variance[0], 25.0 / 3.0));
+        assert!(close(pca.explained_variance_ratio[0], 1.0) && close(pca.explained_variance_ratio[1], 0.0));
+        assert!(close(pca.transform(&[3.0, 6.0])[0], 1.5 * norm));
+    }
+
+    #[test]
+    fn pca_axis_aligned() {
+        // Variances 8/3 on x and 2/3 on y
+        let rows = vec![vec![2.0, 0.0], vec![-2.0, 0.0], vec![0.0, 1.0], vec![0.0, -1.0]];
+        let pca = Pca::fit(&rows, 2);
+
+        assert!(close(pca.components[0].abs(), 1.0) && close(pca.components[3].abs(), 1.0));
+        assert!(close(pca.explained_variance_ratio[0], 0.8));
+        assert!(close(pca.explained_variance_ratio[1], 0.2));
+
+        // Keeping every component is lossless
+        let back = pca.inverse_transform(&pca.transform(&[0.5, -0.7]));
+        assert!(close(back[0], 0.5) && close(back[1], -0.7));
+    }
+
+    #[test]
+    fn pca_ffi() {
+        let flat = [0.0f32, 0.0, 1.0, 2.0, 2.0, 4.0, 3.0, 6.0];
+        let pca = created(|out| fit_pca(flat.as_ptr(), flat.len() as i32, 2, 1, out));
+        assert_eq!(handle::with(pca, |pca: &Pca| Ok(pca.n_components)), Ok(1));
+
+        let mut projected = [0.0; 4];
+        assert_eq!(output(|written| pca_transform(pca, flat.as_ptr(), flat.len() as i32, projected.as_mut_ptr(), 4, written)), 4);
+        assert!(close(projected[0], -1.5 * 5f32.sqrt()));
+        let mut written = 0;
+        assert_eq!(pca_transform(pca, flat.as_ptr(), flat.len() as i32, projected.as_mut_ptr(), 3, &mut written), Status::DimensionMismatch);
+
+        let mut ratio = [0.0; 1];
+        assert_eq!(output(|written| pca_explained_variance_ratio(pca, ratio.as_mut_ptr(), 1, written)), 1);
+        assert!(close(ratio[0], 1.0));
+    }
+
+    #[test]
+    fn save_and_load() {
+        let pca = Pca::fit(&[vec![1.0, 2.0], vec![3.0, 1.0], vec![0.0, 0.5]], 2);
+        let path = std::env::temp_dir().join("rustml_pca_test.bin");
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+
+        let saved = handle::insert(pca);
+        assert_eq!(save_pca(saved, c_path.as_ptr()), Status::Ok);
+        let loaded = created(|out| load_pca(c_path.as_ptr(), out));
+        assert_eq!(taken::<Pca>(loaded), taken::<Pca>(saved));
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,18 @@
+use super::pca::Pca;
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, FfiError, Status};
+use crate::ffi::handle::{self, Handle};
+
+#[no_mangle]
+pub(crate) extern "C" fn fit_pca(inputs_ptr: *const f32, input_length: i32, inputs_sub: i32, n_components: i32, out: *mut Handle) -> Status {
+    ffi_call(|| {
+        // Recompose Vec<Vec<f32>>
+        let inputs = args::rows(inputs_ptr, input_length, inputs_sub, "inputs")?;
+        let n_components = args::positive(n_components, "n_components")?;
+        if inputs.is_empty() || n_components > inputs[0].len() {
+            return Err(FfiError::invalid(format!("{} components for {} rows of {} features",
+                                                 n_components, inputs.len(), inputs.first().map_or(0, |row| row.len()))));
+        }
+        handle::write(out, Pca::fit(&inputs, n_components))
+    })
+}
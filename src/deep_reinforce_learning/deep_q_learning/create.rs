@@ -1,5 +1,11 @@
 use super::dql_struct::DeepQLearning;
-use crate::neural_network::create::init;
+use crate::basic_function::random::SeededRng;
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
+use crate::neural_network::initializer::WeightInit;
+use crate::neural_network::nn_struct::NeuralNetwork;
+use crate::neural_network::regularization::Regularization;
 
 #[no_mangle]
 pub(crate) extern "C" fn init_dql(
@@ -9,24 +15,25 @@ pub(crate) extern "C" fn init_dql(
     gamma: f32,
     epsilon: f32,
     epsilon_min: f32,
-    epsilon_decay: f32
-) -> Box<DeepQLearning> {
-    // Init neural network
-    let arr = [state_dim, 64, 64, action_dim];
-    let ptr = arr.as_ptr();
-    let len = arr.len() as i32;
+    epsilon_decay: f32,
+    seed: u64,
+    out: *mut Handle
+) -> Status {
+    ffi_call(|| {
+        // Init neural network
+        let topology = [args::positive(state_dim, "state_dim")?, 64, 64, args::positive(action_dim, "action_dim")?];
 
-    let model = Box::new(DeepQLearning {
-        self_ptr: 0,
-        state_size: state_dim,
-        action_size: action_dim,
-        learning_rate,
-        gamma,
-        epsilon,
-        epsilon_min,
-        epsilon_decay,
-        neural_network: init(ptr, len)
-    });
-
-    model
-}
\ No newline at end of file
+        handle::write(out, DeepQLearning {
+            state_size: state_dim,
+            action_size: action_dim,
+            learning_rate,
+            gamma,
+            epsilon,
+            epsilon_min,
+            epsilon_decay,
+            regularization: Regularization::default(),
+            neural_network: Box::new(NeuralNetwork::new(&topology, WeightInit::Uniform, 0.0, seed)),
+            rng: SeededRng::new(seed),
+        })
+    })
+}
@@ -1,9 +1,12 @@
 use super::dql_struct::DeepQLearning;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
 
 #[no_mangle]
-pub (crate) extern "C" fn delete_dql(model: &mut DeepQLearning) {
-    unsafe {
-        let _ = Box::from_raw(model);
-    }
-    println!("Model deleted")
-}
\ No newline at end of file
+pub (crate) extern "C" fn delete_dql(model: Handle) -> Status {
+    ffi_call(|| {
+        handle::remove::<DeepQLearning>(model)?;
+        println!("Model deleted");
+        Ok(())
+    })
+}
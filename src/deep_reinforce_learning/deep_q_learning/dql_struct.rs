@@ -1,10 +1,10 @@
 use bincode::{Decode, Encode};
+use crate::basic_function::random::SeededRng;
 use crate::neural_network::nn_struct::NeuralNetwork;
+use crate::neural_network::regularization::Regularization;
 
-#[repr(C)]
 #[derive(Encode, Decode, PartialEq, Debug)]
 pub struct DeepQLearning {
-    pub self_ptr: usize,
     pub state_size: i32,
     pub action_size: i32,
     pub learning_rate: f32,
@@ -12,5 +12,8 @@ pub struct DeepQLearning {
     pub epsilon: f32,
     pub epsilon_min: f32,
     pub epsilon_decay: f32,
+    pub regularization: Regularization,
     pub neural_network: Box<NeuralNetwork>,
+    // Exploration draws, not saved with the model
+    pub rng: SeededRng,
 }
\ No newline at end of file
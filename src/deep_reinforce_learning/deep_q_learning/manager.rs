@@ -1,57 +1,46 @@
+use std::io;
 use super::dql_struct::DeepQLearning;
-use bincode::{config};
-
-#[repr(C)]
-pub struct BindModel {
-    pub self_ptr: *mut u8,
-    pub size: i32,
-}
-impl BindModel {
-    fn new(vec: Vec<u8>) -> Self {
-        let ptr = vec.as_ptr() as *mut u8;
-        let len = vec.len();
-
-        BindModel { self_ptr: ptr, size: len as i32 }
-    }
-}
+use crate::basic_function::storage::{decode_model, encode_model, ModelKind};
+use crate::ffi::args;
+use crate::ffi::buffer::ByteBuffer;
+use crate::ffi::error::{ffi_call, FfiError, Status};
+use crate::ffi::handle::{self, Handle};
 
 #[no_mangle]
-pub (crate) extern "C" fn check_size(model: &mut DeepQLearning) -> i32 {
-    let size = std::mem::size_of_val::<DeepQLearning>(model);
-    size as i32
+pub (crate) extern "C" fn check_size(model: Handle, size: *mut i32) -> Status {
+    ffi_call(|| handle::with(model, |model: &DeepQLearning| {
+        args::write(size, std::mem::size_of_val::<DeepQLearning>(model) as i32, "size")
+    }))
 }
 
 #[no_mangle]
-pub (crate) extern "C" fn save_model(model: &mut DeepQLearning) -> Box<BindModel> {
-    // Serialize model to bin code
-    let bind = serialize_model_to_bin(model);
+pub (crate) extern "C" fn save_model(model: Handle, out: *mut ByteBuffer) -> Status {
+    ffi_call(|| {
+        // Serialize model to bin code
+        let bind = handle::with(model, |model: &DeepQLearning| Ok(serialize_model_to_bin(model)?))?;
 
-    // Hash bin code to another bin code
-    //let swbin = alternate_concatenate_bin(&bined);
+        // Hash bin code to another bin code
+        //let swbin = alternate_concatenate_bin(&bined);
 
-    // Send data to unity to save it !
-   Box::new(BindModel::new(bind))
+        // Send data to unity to save it, released with free_byte_buffer !
+        args::write(out, ByteBuffer::from_vec(bind), "out")
+    })
 }
 
-fn serialize_model_to_bin(model: &mut DeepQLearning) -> Vec<u8> {
-    let config = config::standard();
-    let encoded: Vec<u8> = bincode::encode_to_vec(&*model, config).unwrap();
-    encoded
+fn serialize_model_to_bin(model: &DeepQLearning) -> io::Result<Vec<u8>> {
+    encode_model(model, ModelKind::DeepQLearning)
 }
 
+// Status::Io when the bytes are not a DeepQLearning saved by this format version
 #[no_mangle]
-pub (crate) extern "C" fn deserialize_model(ptr: *const u8, len: usize) -> Box<DeepQLearning> {
-    let slice = unsafe { std::slice::from_raw_parts(ptr, len) };
-    let config = config::standard();
-    let (decoded, _): (DeepQLearning, usize) = bincode::decode_from_slice(slice, config).unwrap();
-    Box::new(decoded)
-}
-
-#[no_mangle]
-pub extern "C" fn free_byte_array(ptr: &mut BindModel) {
-    unsafe {
-        let _ = Box::from_raw(ptr);
-    }
+pub (crate) extern "C" fn deserialize_model(ptr: *const u8, len: usize, out: *mut Handle) -> Status {
+    ffi_call(|| {
+        if ptr.is_null() && len > 0 {
+            return Err(FfiError::null("ptr"));
+        }
+        let slice = if len == 0 { &[] } else { unsafe { std::slice::from_raw_parts(ptr, len) } };
+        handle::write(out, decode_model::<DeepQLearning>(slice, ModelKind::DeepQLearning)?)
+    })
 }
 
 /*
@@ -83,4 +72,4 @@ fn alternate_concatenate_bin(password: &[u8]) -> Vec<u8> {
 
     result
 }
-*/
\ No newline at end of file
+*/
@@ -3,5 +3,5 @@ pub mod dql_struct;
 pub mod delete;
 pub mod predict;
 pub mod train;
-mod manager;
+pub mod manager;
 mod tester;
\ No newline at end of file
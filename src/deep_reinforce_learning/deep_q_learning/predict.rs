@@ -1,49 +1,51 @@
 use super::dql_struct::DeepQLearning;
-use crate::neural_network::predict::predict;
-use crate::basic_function::tools::{argmax, free_vec, recompose_vec};
-use rand::{rng, Rng};
+use crate::neural_network::predict::with_scratch;
+use crate::neural_network::propagate::forward;
+use crate::basic_function::tools::argmax;
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, FfiError, Status};
+use crate::ffi::handle::{self, Handle};
+use rand::Rng;
 
 #[no_mangle]
 pub (crate) extern "C" fn choose_action(
-    model: &mut DeepQLearning,
+    model: Handle,
     inputs: *const f32,
     inputs_len: i32,
     output_len: i32,
     available_actions: *const i32,
     available_actions_len: i32,
-) -> i32 {
-    // Rebuild vec
-    let available_actions_vec = recompose_vec::<i32>(available_actions, available_actions_len);
-
-    let mut rng = rng();
-
-    if rng.random::<f32>() <= model.epsilon {
-        let index = rng.random_range(0..available_actions_vec.len());
-        let action = available_actions_vec[index];
-        action
-    } else {
-        unsafe {
-            let mut_ref = &mut *model.neural_network;
-            let q_value_ptr = predict(
-                mut_ref,
-                inputs,
-                inputs_len,
-                false
-            );
-
-            let q_value_vec = std::slice::from_raw_parts(q_value_ptr as *mut f32, output_len as usize).to_vec();
+    action: *mut i32,
+) -> Status {
+    ffi_call(|| handle::with_mut(model, |model: &mut DeepQLearning| {
+        // Rebuild vec
+        let available_actions_vec = args::slice(available_actions, available_actions_len, "available_actions")?;
+        let inputs_slice = args::slice(inputs, inputs_len, "inputs")?;
+        let output_len = args::length(output_len, "output_len")?;
+        args::expect_len(inputs_slice.len(), model.neural_network.input_size(), "inputs")?;
+        if available_actions_vec.is_empty() {
+            return Err(FfiError::invalid("no available action"));
+        }
+        if output_len > model.neural_network.output_size() || available_actions_vec.len() > output_len {
+            return Err(FfiError::dimension(format!("{} available actions and output_len {} for {} actions",
+                                                   available_actions_vec.len(), output_len, model.neural_network.output_size())));
+        }
 
-            let mut valid_q_values = vec![f32::NEG_INFINITY; output_len as usize];
+        let chosen = if model.rng.random::<f32>() <= model.epsilon {
+            let index = model.rng.random_range(0..available_actions_vec.len());
+            available_actions_vec[index]
+        } else {
+            with_scratch(|scratch| {
+                let q_value_vec = forward(&model.neural_network, inputs_slice, false, scratch);
 
-            for action_index in 0..available_actions_vec.len() {
-                valid_q_values[action_index] = q_value_vec[action_index]
-            }
+                let mut valid_q_values = vec![f32::NEG_INFINITY; output_len];
 
-            let res = argmax(&valid_q_values);
+                let valid_len = available_actions_vec.len();
+                valid_q_values[..valid_len].copy_from_slice(&q_value_vec[..valid_len]);
 
-            free_vec(q_value_ptr);
-            
-            res
-        }
-    }
+                argmax(&valid_q_values)
+            })
+        };
+        args::write(action, chosen, "action")
+    }))
 }
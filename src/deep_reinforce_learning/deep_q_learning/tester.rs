@@ -1,76 +1,88 @@
 #[cfg(test)]
 mod tests {
+    use crate::basic_function::storage::{encode_model, ModelKind};
+    use crate::ffi::buffer::free_byte_buffer;
+    use crate::ffi::error::Status;
+    use crate::ffi::handle::{self, Handle};
+    use crate::ffi::test_support::{created, output, taken};
     use crate::deep_reinforce_learning::deep_q_learning::create::init_dql;
     use crate::deep_reinforce_learning::deep_q_learning::dql_struct::DeepQLearning;
     use crate::deep_reinforce_learning::deep_q_learning::manager::{check_size, deserialize_model, save_model};
     use crate::deep_reinforce_learning::deep_q_learning::predict::choose_action;
     use crate::deep_reinforce_learning::deep_q_learning::train::{learn_dql, update_epsilon};
 
-    fn setup_model() -> Box<DeepQLearning> {
+    fn setup_model() -> Handle {
         // Init Data
-        let model = init_dql(
+        created(|out| init_dql(
             10,
             10,
             0.001,
             0.95,
             1.0,
             0.01,
-            0.995
-        );
-        model
+            0.995,
+            42,
+            out
+        ))
     }
 
     #[test]
     fn is_init() {
-        let model = setup_model();
+        let model = taken::<DeepQLearning>(setup_model());
         assert_eq!(model.state_size, 10);
         assert_eq!(model.action_size, 10);
-        assert_eq!(model.self_ptr, 0);
     }
 
     #[test]
     fn is_ca() {
-        let mut model = setup_model();
+        let model = setup_model();
         let slice= [1.0f32, 2.0f32, 3.0f32, 4.0f32, 5.0f32, 6.0f32, 7.0f32, 8.0f32, 9.0f32, 10.0f32];
         let a = [0, 1, 3];
-        let val = choose_action(&mut model, slice.as_ptr(), slice.len() as i32, 10, a.as_ptr(), a.len() as i32);
+        let val = output(|action| choose_action(model, slice.as_ptr(), slice.len() as i32, 10, a.as_ptr(), a.len() as i32, action));
         assert!(a.contains(&val), "The array does not contain the value");
+
+        // No available action, state of the wrong size
+        let mut action = -1;
+        assert_eq!(choose_action(model, slice.as_ptr(), slice.len() as i32, 10, a.as_ptr(), 0, &mut action), Status::InvalidArgument);
+        assert_eq!(choose_action(model, slice.as_ptr(), 9, 10, a.as_ptr(), a.len() as i32, &mut action), Status::DimensionMismatch);
+        assert_eq!(action, -1);
     }
 
     #[test]
     fn is_update_epsi() {
-        let mut model = setup_model();
-        let old_epsi = model.epsilon.clone();
-        update_epsilon(&mut *model);
-        let curr_epsi = model.epsilon.clone();
+        let model = setup_model();
+        let epsilon = |model| handle::with(model, |model: &DeepQLearning| Ok(model.epsilon)).unwrap();
+        let old_epsi = epsilon(model);
+        assert_eq!(update_epsilon(model), Status::Ok);
+        let curr_epsi = epsilon(model);
         assert_ne!(old_epsi, curr_epsi);
     }
 
     #[test]
     fn is_train() {
-        let mut model = setup_model();
+        let model = setup_model();
 
-        let initial_nn = (*model.neural_network).clone();
+        let initial_nn = handle::with(model, |model: &DeepQLearning| Ok((*model.neural_network).clone())).unwrap();
 
         let state= [1.0f32, 2.0f32, 3.0f32, 4.0f32, 5.0f32, 6.0f32, 7.0f32, 8.0f32, 9.0f32, 10.0f32];
         let next_state = [2.0f32, 3.0f32, 4.0f32, 5.0f32, 6.0f32, 7.0f32, 8.0f32, 9.0f32, 10.0f32, 11.0f32];
 
-        learn_dql(&mut model, state.as_ptr(), state.len() as i32, 0, 1, next_state.as_ptr(), next_state.len() as i32, 10, false);
+        assert_eq!(learn_dql(model, state.as_ptr(), state.len() as i32, 0, 1, next_state.as_ptr(), next_state.len() as i32, 10, false), Status::Ok);
+        assert_eq!(learn_dql(model, state.as_ptr(), state.len() as i32, 10, 1, next_state.as_ptr(), next_state.len() as i32, 10, false), Status::InvalidArgument);
 
-        let nn = model.neural_network.clone();
-        let cur = (*nn).clone();
-        assert_ne!(cur.weights[1][0], initial_nn.weights[1][0]);
+        let cur = taken::<DeepQLearning>(model).neural_network;
+        assert_ne!(cur.weights[1], initial_nn.weights[1]);
 
     }
 
     #[test]
     fn is_prediction() {
-        let mut model = setup_model();
+        let model = setup_model();
 
         let state= [1.0f32, 2.0f32, 3.0f32, 4.0f32, 5.0f32, 6.0f32, 7.0f32, 8.0f32, 9.0f32, 10.0f32];
         let available_action = [0, 1, 2, 3];
 
-        let action = choose_action(&mut *model, state.as_ptr(), state.len() as i32, 10, available_action.as_ptr(), available_action.len() as i32);
+        let action = output(|action| choose_action(model, state.as_ptr(), state.len() as i32, 10, available_action.as_ptr(), available_action.len() as i32, action));
 
         println!("{:?}", action);
         assert!(available_action.contains(&action));
@@ -78,30 +90,51 @@ mod tests {
 
     #[test]
     fn is_check_size() {
-        let mut model = setup_model();
-        let size = check_size(&mut model);
+        let model = setup_model();
+        let size = output(|size| check_size(model, size));
         println!("{:?} ", size);
         assert_ne!(size, 0);
     }
 
-    /*#[test]
+    #[test]
+    fn deserialize_checks_header() {
+        let model = taken::<DeepQLearning>(setup_model());
+        let bytes = encode_model(&model, ModelKind::DeepQLearning).unwrap();
+        let mut decoded = taken::<DeepQLearning>(created(|out| deserialize_model(bytes.as_ptr(), bytes.len(), out)));
+
+        // The exploration generator is not saved, a loaded agent draws from a fresh seed
+        assert_ne!(decoded, model);
+        assert_ne!(decoded.rng, model.rng);
+        decoded.rng = model.rng.clone();
+        assert_eq!(decoded, model);
+
+        // Reported instead of panicking in bincode
+        let garbage = [0u8; 8];
+        let mut decoded = 0;
+        assert_eq!(deserialize_model(garbage.as_ptr(), garbage.len(), &mut decoded), Status::Io);
+        assert_eq!(decoded, 0);
+    }
+
+    #[test]
     fn is_bin() {
-        let mut model = setup_model();
-        let (ptr, len) = save_model(&mut model);
-        let slice = unsafe {Vec::from_raw_parts(ptr, len, len)};
-        println!("{:?}", slice);
+        let model = setup_model();
+        let buffer = output(|out| save_model(model, out));
+        let slice = unsafe { buffer.as_slice() };
 
-        let res = slice.len();
-        assert_ne!(res, 0);
+        assert_eq!(&slice[0..4], b"RSML");
+        assert!(buffer.cap >= buffer.len);
+        assert_eq!(free_byte_buffer(buffer), Status::Ok);
     }
 
     #[test]
     fn is_deserialize() {
-        let mut model = setup_model();
-        let (ptr, len) = save_model(&mut model);
-        let _ = unsafe {Vec::from_raw_parts(ptr, len, len)};
+        let model = setup_model();
+        let buffer = output(|out| save_model(model, out));
 
-        let new_model = Box::new(deserialize_model(ptr, len));
+        let new_model = created(|out| deserialize_model(buffer.ptr, buffer.len, out));
+        assert_eq!(free_byte_buffer(buffer), Status::Ok);
+        let (mut new_model, model) = (taken::<DeepQLearning>(new_model), taken::<DeepQLearning>(model));
+        new_model.rng = model.rng.clone();
         assert_eq!(new_model, model);
-    }*/
-}
\ No newline at end of file
+    }
+}
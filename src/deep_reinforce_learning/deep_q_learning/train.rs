@@ -1,61 +1,72 @@
 use super::dql_struct::DeepQLearning;
-use crate::basic_function::tools::{recompose_vec, argmax, free_vec};
-use crate::neural_network::{ predict::predict, train::one_step_train_pmc2 };
+use crate::basic_function::tools::argmax;
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, FfiError, Status};
+use crate::ffi::handle::{self, Handle};
+use crate::neural_network::{ predict::with_scratch, propagate::forward, regularization::{Regularization, RegularizationParams}, train::one_step_train_pmc2 };
 
 #[no_mangle]
-pub (crate) extern "C" fn update_epsilon(model: &mut DeepQLearning) {
-    model.epsilon = model.epsilon_min.max(model.epsilon * model.epsilon_decay);
+pub (crate) extern "C" fn update_epsilon(model: Handle) -> Status {
+    ffi_call(|| handle::with_mut(model, |model: &mut DeepQLearning| {
+        model.epsilon = model.epsilon_min.max(model.epsilon * model.epsilon_decay);
+        Ok(())
+    }))
 }
 
 #[no_mangle]
-pub (crate) extern "C" fn learn_dql(model: &mut DeepQLearning, state: *const f32, state_len: i32, action: i32, reward: i32, next_state: *const f32, next_state_len: i32, output_len: i32, done: bool) {
-    // Recompose vec
-    let next_state_vec = recompose_vec::<f32>(next_state, next_state_len);
+pub (crate) extern "C" fn set_dql_regularization(model: Handle, regularization: RegularizationParams) -> Status {
+    ffi_call(|| handle::with_mut(model, |model: &mut DeepQLearning| {
+        model.regularization = Regularization::try_from(&regularization)?;
+        Ok(())
+    }))
+}
+
+#[no_mangle]
+pub (crate) extern "C" fn learn_dql(model: Handle, state: *const f32, state_len: i32, action: i32, reward: i32, next_state: *const f32, next_state_len: i32, output_len: i32, done: bool) -> Status {
+    ffi_call(|| handle::with_mut(model, |model: &mut DeepQLearning| {
+        // Recompose vec
+        let state_slice = args::slice(state, state_len, "state")?;
+        let next_state_slice = args::slice(next_state, next_state_len, "next_state")?;
+        let output_len = args::length(output_len, "output_len")?;
+        args::expect_len(state_slice.len(), model.neural_network.input_size(), "state")?;
+        if !done {
+            args::expect_len(next_state_slice.len(), model.neural_network.input_size(), "next_state")?;
+        }
+        args::expect_len(output_len, model.neural_network.output_size(), "output_len")?;
+        if action < 0 || action as usize >= output_len {
+            return Err(FfiError::invalid(format!("action {} is out of [0, {})", action, output_len)));
+        }
+
+        let mut target = reward as f32;
 
-    // Vec to useful vars
-    let next_state_ptr = next_state_vec.as_ptr();
+        let nn_ref = &mut *model.neural_network;
 
-    let mut target = reward as f32;
+        let mut current_q_value_vec = with_scratch(|scratch| {
+            // Calc target value based on next q values
+            if !done {
+                let next_q_values = forward(nn_ref, next_state_slice, false, scratch);
+                let argmax_next_q_value = argmax(&next_q_values[..output_len]);
 
-    let nn_ref = &mut *model.neural_network;
+                target += model.gamma * next_q_values[argmax_next_q_value as usize];
+            }
 
-    // Calc target value based on next q values
-    if !done {
-        let next_q_values_ptr = predict(
+            // Calc current q values
+            forward(nn_ref, state_slice, false, scratch)[..output_len].to_vec()
+        });
+
+        current_q_value_vec[action as usize] = target;
+
+        // Train with current q Values, target in q_values[0]
+        one_step_train_pmc2(
             nn_ref,
-            next_state_ptr,
-            next_state_len,
-            false
+            state_slice.as_ptr(),
+            state_slice.len() as i32,
+            current_q_value_vec.as_mut_ptr(),
+            output_len as i32,
+            model.learning_rate,
+            false,
+            &model.regularization
         );
-        let next_q_values_vec = unsafe { std::slice::from_raw_parts(next_q_values_ptr as *mut f32, output_len as usize).to_vec() };
-        let argmax_next_q_value = argmax(&next_q_values_vec);
-
-        target += model.gamma * next_q_values_vec[argmax_next_q_value as usize];
-
-        free_vec(next_q_values_ptr);
-    }
-
-    // Calc current q values
-    let current_q_value_ptr = predict(
-        nn_ref,
-        state,
-        state_len,
-        false
-    );
-    let mut current_q_value_vec = unsafe { std::slice::from_raw_parts(current_q_value_ptr as *mut f32, output_len as usize).to_vec() };
-
-    current_q_value_vec[action as usize] = target;
-
-    // Train with current q Values, target in q_values[0]
-    one_step_train_pmc2(
-        nn_ref,
-        state,
-        state_len,
-        current_q_value_vec.as_mut_ptr(),
-        output_len,
-        model.learning_rate,
-        false
-    );
-
-    free_vec(current_q_value_ptr);
-}
\ No newline at end of file
+        Ok(())
+    }))
+}
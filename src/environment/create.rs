@@ -0,0 +1,105 @@
+use super::environment::Environment;
+use super::grid_world::GridWorld;
+use super::line_world::LineWorld;
+use super::monty_hall::MontyHall;
+use super::secret_env0dp::SecretEnv0Dp;
+use super::secret_env1dp::SecretEnv1Dp;
+use super::secret_env2dp::SecretEnv2Dp;
+use super::secret_env3dp::SecretEnv3Dp;
+use super::two_round_rock_paper_scissors::RPSGame;
+use super::wrappers::TimeLimit;
+use crate::ffi::args::{self, enum_code};
+use crate::ffi::error::{ffi_call, FfiError, FfiResult, Status};
+use crate::ffi::handle::{self, Handle};
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum EnvironmentKind {
+    LineWorld,
+    GridWorld,
+    RockPaperScissors,
+    MontyHall,
+    // Loaded from the secret_envs library in src/libs
+    SecretEnv0,
+    SecretEnv1,
+    SecretEnv2,
+    SecretEnv3,
+}
+
+enum_code!(EnvironmentKind { LineWorld, GridWorld, RockPaperScissors, MontyHall, SecretEnv0, SecretEnv1, SecretEnv2, SecretEnv3 });
+
+/// Sizes and seed of the built-in environments, a kind ignores the fields it does not use.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug)]
+pub struct EnvironmentParams {
+    /// LineWorld cells, GridWorld rows.
+    pub rows: i32,
+    /// GridWorld columns.
+    pub cols: i32,
+    /// LineWorld and GridWorld cell of the agent until the first reset, which picks a random cell.
+    pub start: i32,
+    /// MontyHall doors.
+    pub doors: i32,
+    /// Generator of the random resets and moves, the secret environments ignore it.
+    pub seed: u64,
+    /// Steps after which an episode is truncated, 0 for episodes that only end in a terminal state.
+    pub max_episode_steps: i32,
+}
+
+/// Environment behind an env_* handle.
+pub struct BuiltinEnvironment(pub Box<dyn Environment>);
+
+// The secret environments hold a pointer into their library. The exports only use the
+// environment through handle::with_mut, so one host thread at a time calls into it.
+unsafe impl Send for BuiltinEnvironment {}
+unsafe impl Sync for BuiltinEnvironment {}
+
+impl BuiltinEnvironment {
+    pub fn new(kind: EnvironmentKind, params: EnvironmentParams) -> FfiResult<BuiltinEnvironment> {
+        let env: Box<dyn Environment> = match kind {
+            EnvironmentKind::LineWorld => {
+                let len = at_least(params.rows, 2, "rows")?;
+                LineWorld::new(len, false, start(params.start, len)?, params.seed)
+            }
+            EnvironmentKind::GridWorld => {
+                let rows = args::positive(params.rows, "rows")?;
+                let cols = args::positive(params.cols, "cols")?;
+                if rows * cols < 2 {
+                    return Err(FfiError::invalid(format!("a {}x{} grid has no room for both terminal cells", rows, cols)));
+                }
+                GridWorld::new(rows as i64, cols as i64, start(params.start, rows * cols)?, params.seed)
+            }
+            EnvironmentKind::RockPaperScissors => RPSGame::new(params.seed),
+            // The host opens a losing door that is neither chosen nor winning, so 3 at least
+            EnvironmentKind::MontyHall => MontyHall::new(at_least(params.doors, 3, "doors")?, params.seed),
+            EnvironmentKind::SecretEnv0 => unsafe { SecretEnv0Dp::new() },
+            EnvironmentKind::SecretEnv1 => unsafe { SecretEnv1Dp::new() },
+            EnvironmentKind::SecretEnv2 => unsafe { SecretEnv2Dp::new() },
+            EnvironmentKind::SecretEnv3 => unsafe { SecretEnv3Dp::new() },
+        };
+        match at_least(params.max_episode_steps, 0, "max_episode_steps")? {
+            0 => Ok(BuiltinEnvironment(env)),
+            max_episode_steps => Ok(BuiltinEnvironment(Box::new(TimeLimit::new(env, max_episode_steps)))),
+        }
+    }
+}
+
+fn at_least(value: i32, min: i32, name: &str) -> FfiResult<usize> {
+    if value < min {
+        return Err(FfiError::invalid(format!("{} should be at least {} ({})", name, min, value)));
+    }
+    Ok(value as usize)
+}
+
+fn start(start: i32, cells: usize) -> FfiResult<usize> {
+    let start = args::length(start, "start")?;
+    if start >= cells {
+        return Err(FfiError::invalid(format!("start {} is out of [0, {})", start, cells)));
+    }
+    Ok(start)
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn env_create(kind: i32, params: EnvironmentParams, out: *mut Handle) -> Status {
+    ffi_call(|| handle::write(out, BuiltinEnvironment::new(EnvironmentKind::try_from(kind)?, params)?))
+}
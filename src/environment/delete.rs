@@ -0,0 +1,12 @@
+use super::create::BuiltinEnvironment;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
+
+#[no_mangle]
+extern "C" fn env_delete(env: Handle) -> Status {
+    ffi_call(|| {
+        handle::remove::<BuiltinEnvironment>(env)?;
+        println!("Environment deleted");
+        Ok(())
+    })
+}
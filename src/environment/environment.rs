@@ -19,4 +19,24 @@ pub trait Environment {
     fn is_forbidden(&self, state_or_action: usize) -> bool;
     fn transition_probability(&self, state: usize, action: usize, next_state: usize, reward: usize) -> f32;
     fn random_state(&mut self) ;
-}
\ No newline at end of file
+
+    /// Reseeds the generator of the environment, deterministic environments keep this default.
+    fn seed(&mut self, _seed: u64) {}
+
+    /// The last step ended the episode on a time limit rather than in a terminal state, `step`
+    /// reports done for both. Only the wrappers that cut episodes override this default.
+    fn truncated(&self) -> bool {
+        false
+    }
+
+    /// One-hot encoding of the current state over `all_states`, the input of a Q network.
+    fn encode_state(&self) -> Vec<f32> {
+        let state = self.state_id();
+        self.all_states().iter().map(|&s| if s == state { 1.0 } else { 0.0 }).collect()
+    }
+
+    /// Text picture of the current state, for hosts that do not read stdout.
+    fn render_ascii(&self) -> String {
+        format!("state {}", self.state_id())
+    }
+}
@@ -1,4 +1,5 @@
 use rand::Rng;
+use crate::basic_function::random::SeededRng;
 use crate::environment::environment::{State, Action, Reward, Environment};
 
 pub struct GridWorld {
@@ -10,10 +11,11 @@ pub struct GridWorld {
     pub all_actions: Vec<Action>,
     pub probabilities: Vec<Vec<Vec<f32>>>,
     pub rewards: Vec<Vec<Vec<Reward>>>,
+    pub rng: SeededRng,
 }
 
 impl GridWorld {
-    pub fn new(lines: i64, cols: i64, pos: State) -> Box<GridWorld> {
+    pub fn new(lines: i64, cols: i64, pos: State, seed: u64) -> Box<GridWorld> {
         let mut positions = Vec::new();
         for i in 0..lines {
             for j in 0..cols {
@@ -29,6 +31,7 @@ impl GridWorld {
             all_actions: vec![0, 1, 2, 3, 4],
             probabilities: vec![vec![vec![0.0; (lines * cols) as usize]; 5]; (lines * cols) as usize],
             rewards: vec![vec![vec![0.0; (lines * cols) as usize]; 5]; (lines * cols) as usize],
+            rng: SeededRng::new(seed),
         });
         env.generate_probabilities();
         env.generate_rewards();
@@ -129,10 +132,14 @@ impl Environment for GridWorld {
     }
 
     fn reset(&mut self) -> State {
-        self.agent_position = rand::thread_rng().gen_range(1..self.all_position.len());
+        self.agent_position = self.rng.random_range(1..self.all_position.len());
         self.agent_position as State
     }
 
+    fn seed(&mut self, seed: u64) {
+        self.rng = SeededRng::new(seed);
+    }
+
     fn step(&mut self, action: Action) -> (State, Reward, bool) {
         if self.is_game_over() {
             return (self.agent_position as State, self.score(), true);
@@ -191,13 +198,15 @@ impl Environment for GridWorld {
     }
 
     fn display(&self) {
+        println!("{}", self.render_ascii());
+    }
+
+    fn render_ascii(&self) -> String {
         let grid = GridWorld::get_grid(self.all_position.clone(), self.col);
-        for line in &grid {
-            for &val in line {
-                print!("{}", if val == self.agent_position { 'X' } else { '_' });
-            }
-            println!();
-        }
+        grid.iter()
+            .map(|line| line.iter().map(|&val| if val == self.agent_position { 'X' } else { '_' }).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
     }
 
     fn state_id(&self) -> State {
@@ -232,7 +241,7 @@ mod tests {
     use super::*;
 
     fn setup_grid_world() -> Box<GridWorld>{
-        let env = GridWorld::new(3, 5, 1);
+        let env = GridWorld::new(3, 5, 1, 0);
         env
     }
 
@@ -0,0 +1,262 @@
+// Gymnasium style environments: `step` tells a true end (terminated) from a time limit (truncated),
+// and the spaces describe what an action and an observation look like.
+use std::collections::HashMap;
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::{Rng, SeedableRng};
+use rand_distr::StandardNormal;
+use crate::environment::environment::{Action, Environment, Reward, State};
+
+/// Extra values returned by `reset` and `step`, like the Gymnasium info dict.
+pub type Info = HashMap<String, f32>;
+
+/// `(observation, reward, terminated, truncated, info)`
+pub type Step<O> = (O, Reward, bool, bool, Info);
+
+/// Integers in [0, n).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Discrete {
+    pub n: usize,
+}
+
+/// Vectors with each value in [low[i], high[i]], the bounds may be infinite.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoxSpace {
+    pub low: Vec<f32>,
+    pub high: Vec<f32>,
+}
+
+/// Vectors with value i in [0, nvec[i]).
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultiDiscrete {
+    pub nvec: Vec<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Space {
+    Discrete(Discrete),
+    Box(BoxSpace),
+    MultiDiscrete(MultiDiscrete),
+}
+
+impl Discrete {
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> usize {
+        rng.random_range(0..self.n)
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        value < self.n
+    }
+}
+
+impl BoxSpace {
+    pub fn new(low: Vec<f32>, high: Vec<f32>) -> BoxSpace {
+        assert_eq!(low.len(), high.len(), "low and high should have the same shape");
+        assert!(low.iter().zip(&high).all(|(low, high)| low <= high), "low should not be above high");
+        BoxSpace { low, high }
+    }
+
+    pub fn shape(&self) -> usize {
+        self.low.len()
+    }
+
+    /// Uniform between finite bounds, a normal sample kept in the bounds otherwise.
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> Vec<f32> {
+        self.low.iter().zip(&self.high).map(|(&low, &high)| {
+            if low.is_finite() && high.is_finite() {
+                if low == high { low } else { rng.random_range(low..=high) }
+            } else {
+                let value: f32 = rng.sample(StandardNormal);
+                value.clamp(low, high)
+            }
+        }).collect()
+    }
+
+    pub fn contains(&self, value: &[f32]) -> bool {
+        value.len() == self.shape() && value.iter().zip(self.low.iter().zip(&self.high)).all(|(v, (low, high))| low <= v && v <= high)
+    }
+}
+
+impl MultiDiscrete {
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> Vec<usize> {
+        self.nvec.iter().map(|&n| rng.random_range(0..n)).collect()
+    }
+
+    pub fn contains(&self, value: &[usize]) -> bool {
+        value.len() == self.nvec.len() && value.iter().zip(&self.nvec).all(|(v, n)| v < n)
+    }
+}
+
+pub trait GymEnvironment {
+    type Observation;
+    type Action;
+
+    /// Starts an episode, `seed` reseeds the environment generator.
+    fn reset(&mut self, seed: Option<u64>) -> (Self::Observation, Info);
+    fn step(&mut self, action: Self::Action) -> Step<Self::Observation>;
+    fn action_space(&self) -> Space;
+    fn observation_space(&self) -> Space;
+}
+
+/// Any `Environment` seen through `GymEnvironment`, observations and actions are the state and action ids.
+/// A `done` step is a truncation when the environment reports `truncated`, a termination otherwise:
+/// wrap the environment in a TimeLimit to truncate the episodes.
+pub struct GymAdapter {
+    env: Box<dyn Environment>,
+    elapsed_steps: usize,
+    needs_reset: bool,
+    rng: StdRng,
+}
+
+impl GymAdapter {
+    pub fn new(env: Box<dyn Environment>) -> GymAdapter {
+        GymAdapter {
+            env,
+            elapsed_steps: 0,
+            needs_reset: true,
+            rng: StdRng::from_rng(&mut rand::rng()),
+        }
+    }
+
+    pub fn env(&self) -> &dyn Environment {
+        self.env.as_ref()
+    }
+
+    pub fn env_mut(&mut self) -> &mut dyn Environment {
+        self.env.as_mut()
+    }
+
+    pub fn elapsed_steps(&self) -> usize {
+        self.elapsed_steps
+    }
+
+    /// Random action among the available ones, drawn from the generator seeded by `reset`.
+    pub fn sample_action(&mut self) -> Action {
+        *self.env.available_actions().choose(&mut self.rng).expect("no available action")
+    }
+
+    fn info(&self) -> Info {
+        HashMap::from([("score".to_string(), self.env.score())])
+    }
+}
+
+impl GymEnvironment for GymAdapter {
+    type Observation = State;
+    type Action = Action;
+
+    fn reset(&mut self, seed: Option<u64>) -> (State, Info) {
+        if let Some(seed) = seed {
+            self.rng = StdRng::seed_from_u64(seed);
+            self.env.seed(seed);
+        }
+        let state = self.env.reset();
+        self.elapsed_steps = 0;
+        self.needs_reset = false;
+        (state, self.info())
+    }
+
+    fn step(&mut self, action: Action) -> Step<State> {
+        assert!(!self.needs_reset, "the episode is over, call reset before step");
+        let (state, reward, done) = self.env.step(action);
+        self.elapsed_steps += 1;
+
+        let truncated = done && self.env.truncated();
+        let terminated = done && !truncated;
+        self.needs_reset = done;
+        (state, reward, terminated, truncated, self.info())
+    }
+
+    fn action_space(&self) -> Space {
+        Space::Discrete(Discrete { n: self.env.all_action().len() })
+    }
+
+    fn observation_space(&self) -> Space {
+        Space::Discrete(Discrete { n: self.env.all_states().len() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environment::line_world::LineWorld;
+    use crate::environment::wrappers::TimeLimit;
+
+    // LineWorld of 5 cells started on `state`, 0 stays, 1 goes left and 2 right
+    fn line_world(state: State, max_episode_steps: usize) -> GymAdapter {
+        let mut env = GymAdapter::new(Box::new(TimeLimit::new(LineWorld::new(5, false, 2, 0), max_episode_steps)));
+        env.reset(Some(0));
+        env.env_mut().set_state(state);
+        env
+    }
+
+    #[test]
+    fn test_spaces() {
+        let env = line_world(2, 10);
+        assert_eq!(env.action_space(), Space::Discrete(Discrete { n: 3 }));
+        assert_eq!(env.observation_space(), Space::Discrete(Discrete { n: 5 }));
+    }
+
+    #[test]
+    fn test_termination() {
+        let mut env = line_world(3, 10);
+        let (state, reward, terminated, truncated, info) = env.step(2);
+        assert_eq!((state, reward, terminated, truncated), (4, 1.0, true, false));
+        assert_eq!(info["score"], 1.0);
+    }
+
+    #[test]
+    fn test_truncation() {
+        let mut env = line_world(2, 3);
+        assert!(!env.step(0).3);
+        assert!(!env.step(0).3);
+        let (state, _, terminated, truncated, _) = env.step(0);
+        assert_eq!((state, terminated, truncated), (2, false, true));
+        assert_eq!(env.elapsed_steps(), 3);
+
+        env.reset(None);
+        assert_eq!(env.elapsed_steps(), 0);
+    }
+
+    #[test]
+    fn test_termination_wins_over_truncation() {
+        let mut env = line_world(3, 1);
+        let (_, _, terminated, truncated, _) = env.step(2);
+        assert!(terminated && !truncated);
+    }
+
+    #[test]
+    #[should_panic(expected = "call reset before step")]
+    fn test_step_needs_reset() {
+        let mut env = line_world(2, 1);
+        env.step(0);
+        env.step(0);
+    }
+
+    #[test]
+    fn test_seeded_sampling() {
+        let mut first = line_world(2, 10);
+        let mut second = line_world(2, 10);
+        let first_actions: Vec<Action> = (0..20).map(|_| first.sample_action()).collect();
+        let second_actions: Vec<Action> = (0..20).map(|_| second.sample_action()).collect();
+        assert_eq!(first_actions, second_actions);
+        assert!(first_actions.iter().all(|action| [0, 1, 2].contains(action)));
+    }
+
+    #[test]
+    fn test_box_and_multi_discrete() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let space = BoxSpace::new(vec![-1.0, 0.0, f32::NEG_INFINITY], vec![1.0, 0.0, 0.5]);
+        for _ in 0..50 {
+            assert!(space.contains(&space.sample(&mut rng)));
+        }
+        assert!(!space.contains(&[0.0, 0.1, 0.0]));
+        assert!(!space.contains(&[0.0, 0.0]));
+
+        let space = MultiDiscrete { nvec: vec![2, 5] };
+        for _ in 0..50 {
+            assert!(space.contains(&space.sample(&mut rng)));
+        }
+        assert!(!space.contains(&[1, 5]));
+        assert!(Discrete { n: 3 }.contains(2) && !Discrete { n: 3 }.contains(3));
+    }
+}
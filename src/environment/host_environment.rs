@@ -0,0 +1,149 @@
+// Environment implemented by the host (a Unity game) through C callbacks, so the tabular
+// learners can train on it. States and actions are ids in [0, num_states) and [0, num_actions).
+use std::ffi::c_void;
+use crate::environment::environment::{Action, Environment, Reward, State};
+use crate::ffi::args;
+use crate::ffi::error::{FfiError, FfiResult};
+
+/// Callbacks of a host environment, `user_data` is passed back to each of them.
+/// `random_state` is optional, Monte Carlo ES uses it for exploring starts and falls back on `reset`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct EnvironmentCallbacks {
+    pub user_data: *mut c_void,
+    pub num_states: i32,
+    pub num_actions: i32,
+    /// Starts an episode and returns its first state.
+    pub reset: Option<extern "C" fn(user_data: *mut c_void) -> i32>,
+    /// Plays `action` and writes the next state, the reward and whether the episode ended.
+    pub step: Option<extern "C" fn(user_data: *mut c_void, action: i32, next_state: *mut i32, reward: *mut f32, done: *mut bool)>,
+    /// Writes the actions allowed in the current state and returns how many were written.
+    pub available_actions: Option<extern "C" fn(user_data: *mut c_void, actions: *mut i32, capacity: i32) -> i32>,
+    /// Returns the current state.
+    pub state_id: Option<extern "C" fn(user_data: *mut c_void) -> i32>,
+    /// Moves to a random state and returns it.
+    pub random_state: Option<extern "C" fn(user_data: *mut c_void) -> i32>,
+}
+
+// reset, state_id and random_state all return a state
+type StateCallback = extern "C" fn(*mut c_void) -> i32;
+
+pub struct HostEnvironment {
+    user_data: *mut c_void,
+    num_states: usize,
+    num_actions: usize,
+    reset: StateCallback,
+    step: extern "C" fn(*mut c_void, i32, *mut i32, *mut f32, *mut bool),
+    available_actions: extern "C" fn(*mut c_void, *mut i32, i32) -> i32,
+    state_id: StateCallback,
+    random_state: Option<StateCallback>,
+    score: Reward,
+    done: bool,
+}
+
+impl HostEnvironment {
+    pub fn new(callbacks: Option<&EnvironmentCallbacks>) -> FfiResult<HostEnvironment> {
+        let callbacks = callbacks.ok_or_else(|| FfiError::null("env"))?;
+        Ok(HostEnvironment {
+            user_data: callbacks.user_data,
+            num_states: args::positive(callbacks.num_states, "num_states")?,
+            num_actions: args::positive(callbacks.num_actions, "num_actions")?,
+            reset: callbacks.reset.ok_or_else(|| FfiError::null("reset"))?,
+            step: callbacks.step.ok_or_else(|| FfiError::null("step"))?,
+            available_actions: callbacks.available_actions.ok_or_else(|| FfiError::null("available_actions"))?,
+            state_id: callbacks.state_id.ok_or_else(|| FfiError::null("state_id"))?,
+            random_state: callbacks.random_state,
+            score: 0.0,
+            done: false,
+        })
+    }
+
+    pub fn num_states(&self) -> usize {
+        self.num_states
+    }
+
+    pub fn num_actions(&self) -> usize {
+        self.num_actions
+    }
+
+    // The Environment trait cannot fail, a broken host is reported as a panic by ffi_call
+    fn checked(&self, id: i32, count: usize, name: &str) -> usize {
+        if id < 0 || id as usize >= count {
+            panic!("host environment returned {} {}, expected [0, {})", name, id, count);
+        }
+        id as usize
+    }
+
+    fn start(&mut self, state: i32) -> State {
+        self.score = 0.0;
+        self.done = false;
+        self.checked(state, self.num_states, "state")
+    }
+}
+
+impl Environment for HostEnvironment {
+    fn reset(&mut self) -> State {
+        let state = (self.reset)(self.user_data);
+        self.start(state)
+    }
+
+    fn step(&mut self, action: Action) -> (State, Reward, bool) {
+        let (mut next_state, mut reward, mut done) = (0, 0.0, false);
+        (self.step)(self.user_data, action as i32, &mut next_state, &mut reward, &mut done);
+        self.score += reward;
+        self.done = done;
+        (self.checked(next_state, self.num_states, "state"), reward, done)
+    }
+
+    fn available_actions(&self) -> Vec<Action> {
+        let mut actions = vec![0; self.num_actions];
+        let count = (self.available_actions)(self.user_data, actions.as_mut_ptr(), actions.len() as i32);
+        let count = self.checked(count, self.num_actions + 1, "action count");
+        actions[..count].iter().map(|&action| self.checked(action, self.num_actions, "action")).collect()
+    }
+
+    fn all_states(&self) -> Vec<State> {
+        (0..self.num_states).collect()
+    }
+
+    // Only the host knows its terminal states, episodes end on the done flag of step
+    fn terminal_states(&self) -> Vec<State> {
+        Vec::new()
+    }
+
+    fn set_state(&mut self, _state: State) {
+        panic!("host environments cannot be set to a state");
+    }
+
+    fn display(&self) {}
+
+    fn state_id(&self) -> State {
+        self.checked((self.state_id)(self.user_data), self.num_states, "state")
+    }
+
+    fn score(&self) -> Reward {
+        self.score
+    }
+
+    fn is_game_over(&self) -> bool {
+        self.done
+    }
+
+    fn all_action(&self) -> Vec<Action> {
+        (0..self.num_actions).collect()
+    }
+
+    fn is_forbidden(&self, _state_or_action: usize) -> bool {
+        false
+    }
+
+    // The transition model is unknown, dynamic programming takes it as arrays instead
+    fn transition_probability(&self, _state: usize, _action: usize, _next_state: usize, _reward: usize) -> f32 {
+        0.0
+    }
+
+    fn random_state(&mut self) {
+        let state = (self.random_state.unwrap_or(self.reset))(self.user_data);
+        self.start(state);
+    }
+}
@@ -1,4 +1,5 @@
 use rand::Rng;
+use crate::basic_function::random::SeededRng;
 use crate::environment::environment::{State, Action, Reward, Environment};
 
 
@@ -9,22 +10,24 @@ pub struct LineWorld {
     pub all_actions: Vec<Action>,
     pub rewards: Vec<Vec<Vec<Reward>>>,
     pub probabilities: Vec<Vec<Vec<f32>>>,
+    pub rng: SeededRng,
 }
 
 impl LineWorld {
-    pub fn new(len: usize, is_rand: bool, pos: usize) -> Box<LineWorld> {
+    pub fn new(len: usize, is_rand: bool, pos: usize, seed: u64) -> Box<LineWorld> {
+        let mut rng = SeededRng::new(seed);
         let mut env = Box::new(LineWorld {
             agent_position: if !is_rand {
                 pos
             } else {
-                let mut rng = rand::thread_rng();
-                rng.gen_range(1..len) as State
+                rng.random_range(1..len) as State
             },
             all_position: (0..len).collect(),
             terminal_position: vec![0, len - 1],
             all_actions: vec![0, 1, 2],
             rewards: vec![vec![vec![0.0; len as usize]; 3]; len as usize],
             probabilities: vec![vec![vec![0.0; len as usize]; 3]; len as usize],
+            rng,
         });
         env.generate_rewards();
         env.generate_probabilities();
@@ -40,8 +43,7 @@ impl LineWorld {
         self.agent_position = if !is_rand {
             pos
         } else {
-            let mut rng = rand::thread_rng();
-            rng.gen_range(1..self.all_position[self.all_position.len() - 1])
+            self.rng.random_range(1..self.all_position[self.all_position.len() - 1])
         };
         self.agent_position as State
     }
@@ -115,11 +117,14 @@ impl Environment for LineWorld {
     }
 
     fn reset(&mut self) -> State {
-        let mut rng = rand::thread_rng();
-        self.agent_position = rng.gen_range(0..self.all_position.len());
+        self.agent_position = self.rng.random_range(0..self.all_position.len());
         self.agent_position as State
     }
 
+    fn seed(&mut self, seed: u64) {
+        self.rng = SeededRng::new(seed);
+    }
+
     fn step(&mut self, action: Action) -> (State, Reward, bool) {
         match action {
             1 if self.agent_position > 0 => self.agent_position -= 1,
@@ -152,8 +157,11 @@ impl Environment for LineWorld {
     }
 
     fn display(&self) {
-        let game: String = self.all_position.iter().map(|&pos| if pos == self.agent_position { 'X' } else { '_' }).collect();
-        println!("{}", game);
+        println!("{}", self.render_ascii());
+    }
+
+    fn render_ascii(&self) -> String {
+        self.all_position.iter().map(|&pos| if pos == self.agent_position { 'X' } else { '_' }).collect()
     }
 
     fn state_id(&self) -> State {
@@ -192,7 +200,7 @@ mod tests {
     use super::*;
 
     fn setup_line_world() -> Box<LineWorld>{
-        let env = LineWorld::new(4, false, 1);
+        let env = LineWorld::new(4, false, 1, 0);
         env
     }
 
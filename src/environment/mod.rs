@@ -4,6 +4,9 @@ pub mod two_round_rock_paper_scissors;
 pub mod monty_hall;
 pub mod tools;
 pub mod environment;
+pub mod host_environment;
+pub mod gym;
+pub mod wrappers;
 pub mod playable_monte_hall;
 //pub mod secret_env0_dp;
 pub mod secret_wrapper;
@@ -12,3 +15,8 @@ pub mod secret_env1dp;
 pub mod secret_env2dp;
 pub mod secret_env3dp;
 pub mod monteHall;
+pub mod create;
+pub mod play;
+pub mod delete;
+
+mod tester;
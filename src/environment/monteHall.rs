@@ -1,5 +1,6 @@
 use rand::Rng;
 use std::fmt;
+use crate::basic_function::random::SeededRng;
 use crate::environment::environment::Environment;
 
 type State = usize;
@@ -12,12 +13,13 @@ pub struct MonteHall {
     pub opened_door: Option<usize>,
     pub nb_portes: usize,
     pub state: State,
+    pub rng: SeededRng,
 }
 
 impl MonteHall {
-    pub fn new(nb_portes: usize) -> Box<MonteHall> {
-        let mut rng = rand::thread_rng();
-        let winning_door = rng.gen_range(0..nb_portes);
+    pub fn new(nb_portes: usize, seed: u64) -> Box<MonteHall> {
+        let mut rng = SeededRng::new(seed);
+        let winning_door = rng.random_range(0..nb_portes);
         println!("La porte gagnante est la porte {}", winning_door);
 
         Box::new(MonteHall {
@@ -26,6 +28,7 @@ impl MonteHall {
             opened_door: None,
             nb_portes,
             state: 1, // Start state
+            rng,
         })
     }
 
@@ -40,11 +43,10 @@ impl MonteHall {
 
     fn open_door(&mut self) {
         if self.opened_door.is_none() {
-            let mut rng = rand::thread_rng();
             let unopened_doors: Vec<usize> = (0..self.nb_portes)
                 .filter(|&x| x != self.winning_door && x != self.chosen_door.unwrap())
                 .collect();
-            let opened_door = unopened_doors[rng.gen_range(0..unopened_doors.len())];
+            let opened_door = unopened_doors[self.rng.random_range(0..unopened_doors.len())];
             self.opened_door = Some(opened_door);
             self.state = match (self.chosen_door.unwrap(), opened_door) {
                 (0, 1) => 4,
@@ -94,14 +96,17 @@ impl MonteHall {
 impl Environment for MonteHall {
 
     fn reset(&mut self) -> State {
-        let mut rng = rand::thread_rng();
-        self.winning_door = rng.gen_range(0..self.nb_portes);
+        self.winning_door = self.rng.random_range(0..self.nb_portes);
         self.chosen_door = None;
         self.opened_door = None;
         self.state = 1; // Start state
         self.state
     }
 
+    fn seed(&mut self, seed: u64) {
+        self.rng = SeededRng::new(seed);
+    }
+
     fn step(&mut self, action: Action) -> (State, Reward, bool) {
         match self.state {
             1 | 2 | 3 => {
@@ -1,6 +1,8 @@
 use rand::Rng;
 use std::fmt;
 
+use crate::basic_function::random::SeededRng;
+
 use crate::environment::environment::{State, Action, Reward, Environment};
 
 
@@ -12,12 +14,13 @@ pub struct MontyHall {
     pub rewards: Vec<f32>,
     pub probabilities: Vec<f32>,
     pub all_actions : Vec<Action>,
+    pub rng: SeededRng,
 }
 
 impl MontyHall {
-    pub fn new(nb_portes: usize) -> Box<MontyHall> {
-        let mut rng = rand::thread_rng();
-        let winning_door = rng.gen_range(0..nb_portes);
+    pub fn new(nb_portes: usize, seed: u64) -> Box<MontyHall> {
+        let mut rng = SeededRng::new(seed);
+        let winning_door = rng.random_range(0..nb_portes);
         println!("La porte gagnante est la porte {}", winning_door);
         let all_actions : Vec<Action> = vec![0; nb_portes];
 
@@ -29,6 +32,7 @@ impl MontyHall {
             rewards: Vec::new(),
             probabilities: Vec::new(),
             all_actions,
+            rng,
         };
 
         monty_hall.init_rewards();
@@ -65,11 +69,10 @@ impl MontyHall {
             }
             Some(_) => {
                 if self.opened_door.is_none() {
-                    let mut rng = rand::thread_rng();
                     let unopened_doors: Vec<usize> = (0..self.nb_portes)
                         .filter(|&x| x != self.winning_door && x != self.chosen_door.unwrap())
                         .collect();
-                    let opened_door = unopened_doors[rng.gen_range(0..unopened_doors.len())];
+                    let opened_door = unopened_doors[self.rng.random_range(0..unopened_doors.len())];
                     self.opened_door = Some(opened_door);
                 } else {
                     if self.opened_door.unwrap() == action {
@@ -104,8 +107,7 @@ impl MontyHall {
 
 impl Environment for MontyHall {
          fn reset(&mut self) -> State{
-            let mut rng = rand::thread_rng();
-            self.winning_door = rng.gen_range(0..self.nb_portes);
+            self.winning_door = self.rng.random_range(0..self.nb_portes);
             self.chosen_door = None;
             self.opened_door = None;
             self.init_rewards();
@@ -113,6 +115,10 @@ impl Environment for MontyHall {
              self.state_id()
         }
 
+    fn seed(&mut self, seed: u64) {
+        self.rng = SeededRng::new(seed);
+    }
+
 
 
 
@@ -165,8 +171,9 @@ impl Environment for MontyHall {
             0.0
         }
 
+    // The last state is the start, no door chosen yet
     fn all_states(&self) -> Vec<State> {
-        (0..self.nb_portes as State).collect()
+        (0..=self.nb_portes as State).collect()
     }
 
 
@@ -182,6 +189,10 @@ impl Environment for MontyHall {
         println!("{}", self);
     }
 
+    fn render_ascii(&self) -> String {
+        self.to_string()
+    }
+
     fn state_id(&self) -> State {
         self.chosen_door.unwrap_or(self.nb_portes) as State
     }
@@ -231,14 +242,20 @@ mod tests {
 
     #[test]
     fn test_new_monty_hall() {
-        let monty_hall = MontyHall::new(3);
+        let monty_hall = MontyHall::new(3, 0);
         assert_eq!(monty_hall.nb_portes, 3);
         assert!(monty_hall.winning_door < 3);
     }
 
+    #[test]
+    fn test_seeded_winning_door() {
+        assert_eq!(MontyHall::new(10, 4).winning_door, MontyHall::new(10, 4).winning_door);
+        assert!((0..20).any(|seed| MontyHall::new(10, seed).winning_door != MontyHall::new(10, 0).winning_door));
+    }
+
     #[test]
     fn test_reset() {
-        let mut monty_hall = MontyHall::new(3);
+        let mut monty_hall = MontyHall::new(3, 0);
         monty_hall.reset();
         assert!(monty_hall.chosen_door.is_none());
         assert!(monty_hall.opened_door.is_none());
@@ -246,7 +263,7 @@ mod tests {
 
     #[test]
     fn test_valid_action() {
-        let mut monty_hall = MontyHall::new(3);
+        let mut monty_hall = MontyHall::new(3, 0);
         monty_hall.chosen_door = None;
         assert!(monty_hall.valid_action(0));
         assert!(monty_hall.valid_action(1));
@@ -261,7 +278,7 @@ mod tests {
 
     #[test]
     fn test_next_state() {
-        let mut monty_hall = MontyHall::new(3);
+        let mut monty_hall = MontyHall::new(3, 0);
         let (success, updated) = monty_hall.next_state(1);
         assert!(success);
         assert!(!updated);
@@ -273,7 +290,7 @@ mod tests {
 
     #[test]
     fn test_reward() {
-        let mut monty_hall = MontyHall::new(3);
+        let mut monty_hall = MontyHall::new(3, 0);
         monty_hall.winning_door = 0;
         monty_hall.chosen_door = Some(1);
         monty_hall.opened_door = Some(2);
@@ -285,7 +302,7 @@ mod tests {
 
     #[test]
     /*fn test_step() {
-        let mut monty_hall = MontyHall::new(3);
+        let mut monty_hall = MontyHall::new(3, 0);
         let winning_door = monty_hall.winning_door;
         monty_hall.chosen_door = None;
         let (reward, done) = monty_hall.step(1);
@@ -300,7 +317,7 @@ mod tests {
 
     #[test]
     fn test_available_actions() {
-        let mut monty_hall = MontyHall::new(3);
+        let mut monty_hall = MontyHall::new(3, 0);
         assert_eq!(monty_hall.available_actions(), vec![0, 1, 2]);
 
         monty_hall.opened_door = Some(1);
@@ -309,7 +326,7 @@ mod tests {
 
     #[test]
     fn test_is_game_over() {
-        let mut monty_hall = MontyHall::new(3);
+        let mut monty_hall = MontyHall::new(3, 0);
         assert!(!monty_hall.is_game_over());
 
         monty_hall.chosen_door = Some(1);
@@ -319,7 +336,7 @@ mod tests {
 
     #[test]
     fn test_score() {
-        let mut monty_hall = MontyHall::new(3);
+        let mut monty_hall = MontyHall::new(3, 0);
         monty_hall.winning_door = 0;
         monty_hall.chosen_door = Some(1);
         monty_hall.opened_door = Some(2);
@@ -332,7 +349,7 @@ mod tests {
     #[test]
     fn test_init_rewards() {
         let nb_portes = 3;
-        let mut monty_hall = MontyHall::new(nb_portes);
+        let mut monty_hall = MontyHall::new(nb_portes, 0);
 
         monty_hall.init_rewards();
 
@@ -349,7 +366,7 @@ mod tests {
     #[test]
     fn test_init_probabilities() {
         let nb_portes = 3;
-        let mut monty_hall = MontyHall::new(nb_portes);
+        let mut monty_hall = MontyHall::new(nb_portes, 0);
 
         monty_hall.init_probabilities();
 
@@ -362,7 +379,7 @@ mod tests {
     #[test]
     fn test_new_initializes_correctly() {
         let nb_portes = 3;
-        let monty_hall = MontyHall::new(nb_portes);
+        let monty_hall = MontyHall::new(nb_portes, 0);
 
         assert_eq!(monty_hall.rewards.len(), nb_portes);
         for i in 0..nb_portes {
@@ -382,7 +399,7 @@ mod tests {
     #[test]
     fn test_reset_initializes_correctly() {
         let nb_portes = 3;
-        let mut monty_hall = MontyHall::new(nb_portes);
+        let mut monty_hall = MontyHall::new(nb_portes, 0);
         monty_hall.reset();
 
         assert_eq!(monty_hall.rewards.len(), nb_portes);
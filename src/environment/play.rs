@@ -0,0 +1,76 @@
+use super::create::BuiltinEnvironment;
+use super::environment::Environment;
+use crate::ffi::args;
+use crate::ffi::buffer::{ByteBuffer, FloatBuffer};
+use crate::ffi::error::{ffi_call, FfiError, FfiResult, Status};
+use crate::ffi::handle::{self, Handle};
+
+// Every call takes the environment exclusively, see BuiltinEnvironment
+fn with_env<R>(env: Handle, body: impl FnOnce(&mut dyn Environment) -> FfiResult<R>) -> FfiResult<R> {
+    handle::with_mut(env, |env: &mut BuiltinEnvironment| body(env.0.as_mut()))
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn env_reset(env: Handle, state: *mut i32) -> Status {
+    ffi_call(|| with_env(env, |env| {
+        let first = env.reset();
+        args::write(state, first as i32, "state")
+    }))
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn env_step(env: Handle, action: i32, next_state: *mut i32, reward: *mut f32, done: *mut bool) -> Status {
+    ffi_call(|| with_env(env, |env| {
+        // Checked first so a bad call leaves the environment as it was
+        if next_state.is_null() || reward.is_null() || done.is_null() {
+            return Err(FfiError::null(if next_state.is_null() { "next_state" } else if reward.is_null() { "reward" } else { "done" }));
+        }
+        if env.is_game_over() {
+            return Err(FfiError::invalid("the episode is over, call env_reset"));
+        }
+        if action < 0 || !env.available_actions().contains(&(action as usize)) {
+            return Err(FfiError::invalid(format!("action {} is not available in state {}", action, env.state_id())));
+        }
+
+        let (state, step_reward, step_done) = env.step(action as usize);
+        args::write(next_state, state as i32, "next_state")?;
+        args::write(reward, step_reward, "reward")?;
+        args::write(done, step_done, "done")
+    }))
+}
+
+// `truncated` tells whether the last done step came from max_episode_steps rather than a terminal state
+#[no_mangle]
+pub(crate) extern "C" fn env_truncated(env: Handle, truncated: *mut bool) -> Status {
+    ffi_call(|| with_env(env, |env| args::write(truncated, env.truncated(), "truncated")))
+}
+
+// `written` receives the number of actions, the array should hold all of them
+#[no_mangle]
+pub(crate) extern "C" fn env_available_actions(env: Handle, actions: *mut i32, actions_len: i32, written: *mut i32) -> Status {
+    ffi_call(|| with_env(env, |env| {
+        let actions_slice = args::slice_mut(actions, actions_len, "actions")?;
+        let available = env.available_actions();
+        if available.len() > actions_slice.len() {
+            return Err(FfiError::dimension(format!("actions holds {} values, {} actions are available", actions_slice.len(), available.len())));
+        }
+        for (slot, &action) in actions_slice.iter_mut().zip(&available) {
+            *slot = action as i32;
+        }
+        args::write(written, available.len() as i32, "written")
+    }))
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn env_encode_state(env: Handle, out: *mut FloatBuffer) -> Status {
+    ffi_call(|| with_env(env, |env| {
+        // Released by the caller with free_float_buffer
+        args::write(out, FloatBuffer::from_vec(env.encode_state()), "out")
+    }))
+}
+
+// UTF-8 text without a trailing nul, released by the caller with free_byte_buffer
+#[no_mangle]
+pub(crate) extern "C" fn env_render_ascii(env: Handle, out: *mut ByteBuffer) -> Status {
+    ffi_call(|| with_env(env, |env| args::write(out, ByteBuffer::from_vec(env.render_ascii().into_bytes()), "out")))
+}
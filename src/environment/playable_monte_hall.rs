@@ -1,5 +1,6 @@
 extern crate rand;
 use rand::Rng;
+use crate::basic_function::random::SeededRng;
 use crate::environment::environment::{State, Action, Reward, Environment};
 use std::fmt;
 
@@ -11,12 +12,13 @@ pub struct playable_MontyHall {
     pub rewards: Vec<f32>,
     pub probabilities: Vec<f32>,
     pub all_actions : Vec<Action>,
+    pub rng: SeededRng,
 }
 
 impl playable_MontyHall {
-    pub fn new(nb_portes: usize) -> Box<playable_MontyHall> {
-        let mut rng = rand::thread_rng();
-        let winning_door = rng.gen_range(0..nb_portes);
+    pub fn new(nb_portes: usize, seed: u64) -> Box<playable_MontyHall> {
+        let mut rng = SeededRng::new(seed);
+        let winning_door = rng.random_range(0..nb_portes);
         println!("La porte gagnante est la porte {}", winning_door);
         let all_actions : Vec<Action> = vec![0; nb_portes];
 
@@ -28,6 +30,7 @@ impl playable_MontyHall {
             rewards: Vec::new(),
             probabilities: Vec::new(),
             all_actions,
+            rng,
         };
 
         monty_hall.init_rewards();
@@ -63,11 +66,10 @@ impl playable_MontyHall {
             }
             Some(_) => {
                 if self.opened_door.is_none() {
-                    let mut rng = rand::thread_rng();
                     let unopened_doors: Vec<usize> = (0..self.nb_portes)
                         .filter(|&x| x != self.winning_door && x != self.chosen_door.unwrap())
                         .collect();
-                    let opened_door = unopened_doors[rng.gen_range(0..unopened_doors.len())];
+                    let opened_door = unopened_doors[self.rng.random_range(0..unopened_doors.len())];
                     self.opened_door = Some(opened_door);
                 } else {
                     self.chosen_door = Some(action);
@@ -95,8 +97,7 @@ impl playable_MontyHall {
 
 impl Environment for playable_MontyHall {
     fn reset(&mut self) -> State {
-        let mut rng = rand::thread_rng();
-        self.winning_door = rng.gen_range(0..self.nb_portes);
+        self.winning_door = self.rng.random_range(0..self.nb_portes);
         self.chosen_door = None;
         self.opened_door = None;
         self.init_rewards();
@@ -104,6 +105,10 @@ impl Environment for playable_MontyHall {
         self.state_id()
     }
 
+    fn seed(&mut self, seed: u64) {
+        self.rng = SeededRng::new(seed);
+    }
+
     fn step(&mut self, action: Action) -> (State, Reward, bool) {
         let action = action as usize;
         let (success, updated) = self.next_state(action);
@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+    use crate::environment::create::{env_create, BuiltinEnvironment, EnvironmentKind, EnvironmentParams};
+    use crate::environment::play::{env_available_actions, env_encode_state, env_render_ascii, env_reset, env_step, env_truncated};
+    use crate::ffi::buffer::{free_byte_buffer, free_float_buffer, ByteBuffer, FloatBuffer};
+    use crate::ffi::error::{rustml_last_error, Status};
+    use crate::ffi::handle::Handle;
+    use crate::ffi::test_support::{created, output, taken};
+
+    fn last_error() -> String {
+        unsafe { CStr::from_ptr(rustml_last_error()) }.to_string_lossy().into_owned()
+    }
+
+    fn line_world(rows: i32, start: i32) -> Handle {
+        let params = EnvironmentParams { rows, start, ..Default::default() };
+        created(|out| env_create(EnvironmentKind::LineWorld as i32, params, out))
+    }
+
+    fn render(env: Handle) -> String {
+        let buffer: ByteBuffer = output(|out| env_render_ascii(env, out));
+        let text = String::from_utf8(unsafe { buffer.as_slice() }.to_vec()).unwrap();
+        assert_eq!(free_byte_buffer(buffer), Status::Ok);
+        text
+    }
+
+    fn encoded(env: Handle) -> Vec<f32> {
+        let buffer: FloatBuffer = output(|out| env_encode_state(env, out));
+        let values = unsafe { buffer.as_slice() }.to_vec();
+        assert_eq!(free_float_buffer(buffer), Status::Ok);
+        values
+    }
+
+    fn available(env: Handle) -> Vec<i32> {
+        let mut actions = [-1; 8];
+        let written = output(|written| env_available_actions(env, actions.as_mut_ptr(), 8, written));
+        actions[..written as usize].to_vec()
+    }
+
+    fn step(env: Handle, action: i32) -> (i32, f32, bool) {
+        let (mut state, mut reward, mut done) = (-1, 0.0, false);
+        assert_eq!(env_step(env, action, &mut state, &mut reward, &mut done), Status::Ok);
+        (state, reward, done)
+    }
+
+    #[test]
+    fn line_world_episode() {
+        let env = line_world(5, 2);
+        assert_eq!(render(env), "__X__");
+        assert_eq!(encoded(env), vec![0.0, 0.0, 1.0, 0.0, 0.0]);
+        assert_eq!(available(env), vec![0, 1, 2]);
+
+        assert_eq!(step(env, 2), (3, 0.0, false));
+        assert_eq!(step(env, 2), (4, 1.0, true));
+        assert_eq!(render(env), "____X");
+
+        // A finished episode is refused until the next reset
+        assert_eq!(env_step(env, 2, &mut 0, &mut 0.0, &mut false), Status::InvalidArgument);
+        assert_eq!(last_error(), "the episode is over, call env_reset");
+        let state = output(|state| env_reset(env, state));
+        assert!(state < 5);
+        assert_eq!(encoded(env)[state as usize], 1.0);
+        taken::<BuiltinEnvironment>(env);
+    }
+
+    #[test]
+    fn invalid_steps_leave_the_environment_unchanged() {
+        let env = line_world(5, 2);
+        assert_eq!(env_step(env, 7, &mut 0, &mut 0.0, &mut false), Status::InvalidArgument);
+        assert_eq!(last_error(), "action 7 is not available in state 2");
+        assert_eq!(env_step(env, -1, &mut 0, &mut 0.0, &mut false), Status::InvalidArgument);
+        assert_eq!(env_step(env, 2, &mut 0, std::ptr::null_mut(), &mut false), Status::NullPointer);
+        assert_eq!(last_error(), "reward is null");
+        assert_eq!(render(env), "__X__");
+
+        let mut actions = [0; 2];
+        assert_eq!(env_available_actions(env, actions.as_mut_ptr(), 2, &mut 0), Status::DimensionMismatch);
+        assert_eq!(last_error(), "actions holds 2 values, 3 actions are available");
+        taken::<BuiltinEnvironment>(env);
+    }
+
+    #[test]
+    fn same_seed_resets_the_same_way() {
+        let resets = |seed| {
+            let params = EnvironmentParams { rows: 4, cols: 4, seed, ..Default::default() };
+            let grid = created(|out| env_create(EnvironmentKind::GridWorld as i32, params, out));
+            let states: Vec<i32> = (0..20).map(|_| output(|state| env_reset(grid, state))).collect();
+            taken::<BuiltinEnvironment>(grid);
+            states
+        };
+        assert_eq!(resets(3), resets(3));
+        assert_ne!(resets(3), resets(4));
+    }
+
+    #[test]
+    fn grid_world_and_games() {
+        let params = EnvironmentParams { rows: 3, cols: 4, start: 5, ..Default::default() };
+        let grid = created(|out| env_create(EnvironmentKind::GridWorld as i32, params, out));
+        assert_eq!(encoded(grid)[5], 1.0);
+        assert_eq!(render(grid).lines().count(), 3);
+        assert_eq!(encoded(grid).len(), 12);
+        taken::<BuiltinEnvironment>(grid);
+
+        let params = EnvironmentParams { doors: 3, ..Default::default() };
+        let monty_hall = created(|out| env_create(EnvironmentKind::MontyHall as i32, params, out));
+        // No door chosen yet
+        assert_eq!(output(|state| env_reset(monty_hall, state)), 3);
+        assert_eq!(encoded(monty_hall), vec![0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(step(monty_hall, 1), (1, 0.0, false));
+        let (_, _, done) = step(monty_hall, available(monty_hall)[0]);
+        assert!(done);
+        taken::<BuiltinEnvironment>(monty_hall);
+
+        let rps = created(|out| env_create(EnvironmentKind::RockPaperScissors as i32, EnvironmentParams::default(), out));
+        assert_eq!(output(|state| env_reset(rps, state)), 0);
+        assert_eq!(available(rps), vec![0, 1, 2]);
+        assert!(render(rps).starts_with("Round: 0"));
+        taken::<BuiltinEnvironment>(rps);
+    }
+
+    #[test]
+    fn max_episode_steps_truncates() {
+        let params = EnvironmentParams { rows: 5, start: 2, max_episode_steps: 2, ..Default::default() };
+        let env = created(|out| env_create(EnvironmentKind::LineWorld as i32, params, out));
+        assert_eq!(step(env, 0), (2, 0.0, false));
+        assert!(!output(|truncated| env_truncated(env, truncated)));
+        assert_eq!(step(env, 0), (2, 0.0, true));
+        assert!(output(|truncated| env_truncated(env, truncated)));
+        taken::<BuiltinEnvironment>(env);
+
+        // A terminal state is not a truncation, even on the last step
+        let params = EnvironmentParams { rows: 5, start: 3, max_episode_steps: 1, ..Default::default() };
+        let env = created(|out| env_create(EnvironmentKind::LineWorld as i32, params, out));
+        assert_eq!(step(env, 2), (4, 1.0, true));
+        assert!(!output(|truncated| env_truncated(env, truncated)));
+        taken::<BuiltinEnvironment>(env);
+    }
+
+    #[test]
+    fn secret_environment() {
+        let env = created(|out| env_create(EnvironmentKind::SecretEnv0 as i32, EnvironmentParams::default(), out));
+        let state = output(|state| env_reset(env, state));
+        let encoding = encoded(env);
+        assert_eq!(encoding[state as usize], 1.0);
+        assert_eq!(encoding.iter().sum::<f32>(), 1.0);
+
+        let action = available(env)[0];
+        let (next_state, _, _) = step(env, action);
+        assert!((next_state as usize) < encoding.len());
+        taken::<BuiltinEnvironment>(env);
+    }
+
+    #[test]
+    fn invalid_params_are_rejected() {
+        let mut env = 0;
+        let cases = [
+            (EnvironmentKind::LineWorld, EnvironmentParams { rows: 1, ..Default::default() }, "rows should be at least 2 (1)"),
+            (EnvironmentKind::LineWorld, EnvironmentParams { rows: 5, start: 5, ..Default::default() }, "start 5 is out of [0, 5)"),
+            (EnvironmentKind::GridWorld, EnvironmentParams { rows: 1, cols: 1, ..Default::default() }, "a 1x1 grid has no room for both terminal cells"),
+            (EnvironmentKind::MontyHall, EnvironmentParams { doors: 2, ..Default::default() }, "doors should be at least 3 (2)"),
+            (EnvironmentKind::LineWorld, EnvironmentParams { rows: 5, max_episode_steps: -1, ..Default::default() }, "max_episode_steps should be at least 0 (-1)"),
+        ];
+        for (kind, params, message) in cases {
+            assert_eq!(env_create(kind as i32, params, &mut env), Status::InvalidArgument);
+            assert_eq!(last_error(), message);
+        }
+
+        // Codes outside EnvironmentKind never reach the match
+        for kind in [8, -1] {
+            assert_eq!(env_create(kind, EnvironmentParams { rows: 5, ..Default::default() }, &mut env), Status::InvalidArgument);
+            assert_eq!(last_error(), format!("{} is not a valid EnvironmentKind code", kind));
+        }
+        assert_eq!(env, 0);
+    }
+}
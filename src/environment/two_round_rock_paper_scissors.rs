@@ -1,4 +1,5 @@
 use rand::Rng;
+use crate::basic_function::random::SeededRng;
 use crate::environment::environment::{State, Action as ActionType, Reward, Environment};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -30,10 +31,11 @@ pub struct RPSGame {
     pub all_position: Vec<State>,
     pub all_actions: Vec<ActionType>,
     pub terminal_position: Vec<State>,
+    pub rng: SeededRng,
 }
 
 impl RPSGame {
-    pub fn new() -> Box<RPSGame> {
+    pub fn new(seed: u64) -> Box<RPSGame> {
         let all_position = (0..=2).collect();
         let all_actions = vec![0, 1, 2];
         let terminal_position = vec![2];
@@ -52,6 +54,7 @@ impl RPSGame {
             all_position,
             all_actions,
             terminal_position,
+            rng: SeededRng::new(seed),
         });
 
         env.generate_rewards();
@@ -91,10 +94,10 @@ impl RPSGame {
         }
     }
 
-    pub fn choose_adversary_action(&self) -> ActionGame {
+    pub fn choose_adversary_action(&mut self) -> ActionGame {
         if self.round == 0 {
             let actions = [ActionGame::Rock, ActionGame::Paper, ActionGame::Scissors];
-            let random_index = rand::thread_rng().gen_range(0..3);
+            let random_index = self.rng.random_range(0..3);
             actions[random_index]
         } else {
             self.first_agent_action.unwrap()
@@ -149,6 +152,10 @@ impl Environment for RPSGame {
         0 // Return initial state ID
     }
 
+    fn seed(&mut self, seed: u64) {
+        self.rng = SeededRng::new(seed);
+    }
+
     // L'agent joue deux fois via la nouvelle méthode play_two_rounds
     fn step(&mut self, action: ActionType) -> (State, Reward, bool) {
         let agent_action = match action {
@@ -203,6 +210,12 @@ impl Environment for RPSGame {
         println!("Adversary score: {}", self.adversary_score);
     }
 
+    fn render_ascii(&self) -> String {
+        let played = |action: Option<ActionGame>| action.map_or("-".to_string(), |action| format!("{:?}", action));
+        format!("Round: {}, Agent: {} ({}), Adversary: {} ({})", self.round,
+                played(self.agent_action), self.agent_score, played(self.adversary_action), self.adversary_score)
+    }
+
     fn state_id(&self) -> State {
         self.round as State
     }
@@ -0,0 +1,518 @@
+// Wrappers around any `Environment`, each one is an `Environment` too so they compose:
+// `TimeLimit::new(Box::new(RewardScale::new(LineWorld::new(5, false, 2, 0), 0.1)), 50)`.
+// `random_state` starts an episode like `reset`, Monte Carlo ES explores from it.
+use crate::environment::environment::{Action, Environment, Reward, State};
+
+// The methods a wrapper leaves to the inner environment, all but reset, step, is_game_over and random_state.
+// `except_truncated` also leaves out truncated, for the wrappers that cut episodes.
+macro_rules! delegate_environment {
+    () => {
+        delegate_environment!(except_truncated);
+
+        fn truncated(&self) -> bool {
+            self.env.truncated()
+        }
+    };
+    (except_truncated) => {
+        fn available_actions(&self) -> Vec<Action> {
+            self.env.available_actions()
+        }
+
+        fn all_states(&self) -> Vec<State> {
+            self.env.all_states()
+        }
+
+        fn terminal_states(&self) -> Vec<State> {
+            self.env.terminal_states()
+        }
+
+        fn set_state(&mut self, state: State) {
+            self.env.set_state(state)
+        }
+
+        fn display(&self) {
+            self.env.display()
+        }
+
+        fn state_id(&self) -> State {
+            self.env.state_id()
+        }
+
+        fn score(&self) -> Reward {
+            self.env.score()
+        }
+
+        fn all_action(&self) -> Vec<Action> {
+            self.env.all_action()
+        }
+
+        fn is_forbidden(&self, state_or_action: usize) -> bool {
+            self.env.is_forbidden(state_or_action)
+        }
+
+        fn transition_probability(&self, state: usize, action: usize, next_state: usize, reward: usize) -> f32 {
+            self.env.transition_probability(state, action, next_state, reward)
+        }
+
+        fn seed(&mut self, seed: u64) {
+            self.env.seed(seed)
+        }
+
+        fn encode_state(&self) -> Vec<f32> {
+            self.env.encode_state()
+        }
+
+        fn render_ascii(&self) -> String {
+            self.env.render_ascii()
+        }
+    };
+}
+
+/// Ends the episodes after `max_episode_steps` steps: `step` then returns done and `truncated`
+/// tells the cut apart from a terminal state of the inner environment.
+pub struct TimeLimit {
+    env: Box<dyn Environment>,
+    max_episode_steps: usize,
+    elapsed_steps: usize,
+}
+
+impl TimeLimit {
+    pub fn new(env: Box<dyn Environment>, max_episode_steps: usize) -> TimeLimit {
+        TimeLimit { env, max_episode_steps, elapsed_steps: 0 }
+    }
+
+    pub fn elapsed_steps(&self) -> usize {
+        self.elapsed_steps
+    }
+}
+
+impl Environment for TimeLimit {
+    fn reset(&mut self) -> State {
+        self.elapsed_steps = 0;
+        self.env.reset()
+    }
+
+    fn step(&mut self, action: Action) -> (State, Reward, bool) {
+        let (state, reward, done) = self.env.step(action);
+        self.elapsed_steps += 1;
+        (state, reward, done || self.elapsed_steps >= self.max_episode_steps)
+    }
+
+    fn is_game_over(&self) -> bool {
+        self.env.is_game_over() || self.elapsed_steps >= self.max_episode_steps
+    }
+
+    fn random_state(&mut self) {
+        self.elapsed_steps = 0;
+        self.env.random_state()
+    }
+
+    // Cut by this limit or by an inner one, as long as the inner environment did not end the episode itself
+    fn truncated(&self) -> bool {
+        self.env.truncated() || (self.elapsed_steps >= self.max_episode_steps && !self.env.is_game_over())
+    }
+
+    delegate_environment!(except_truncated);
+}
+
+/// Multiplies the rewards by `scale`.
+pub struct RewardScale {
+    env: Box<dyn Environment>,
+    scale: Reward,
+}
+
+impl RewardScale {
+    pub fn new(env: Box<dyn Environment>, scale: Reward) -> RewardScale {
+        RewardScale { env, scale }
+    }
+}
+
+impl Environment for RewardScale {
+    fn reset(&mut self) -> State {
+        self.env.reset()
+    }
+
+    fn step(&mut self, action: Action) -> (State, Reward, bool) {
+        let (state, reward, done) = self.env.step(action);
+        (state, reward * self.scale, done)
+    }
+
+    fn is_game_over(&self) -> bool {
+        self.env.is_game_over()
+    }
+
+    fn random_state(&mut self) {
+        self.env.random_state()
+    }
+
+    delegate_environment!();
+}
+
+/// Keeps the rewards in [min, max].
+pub struct RewardClip {
+    env: Box<dyn Environment>,
+    min: Reward,
+    max: Reward,
+}
+
+impl RewardClip {
+    pub fn new(env: Box<dyn Environment>, min: Reward, max: Reward) -> RewardClip {
+        assert!(min <= max, "min should not be above max");
+        RewardClip { env, min, max }
+    }
+}
+
+impl Environment for RewardClip {
+    fn reset(&mut self) -> State {
+        self.env.reset()
+    }
+
+    fn step(&mut self, action: Action) -> (State, Reward, bool) {
+        let (state, reward, done) = self.env.step(action);
+        (state, reward.clamp(self.min, self.max), done)
+    }
+
+    fn is_game_over(&self) -> bool {
+        self.env.is_game_over()
+    }
+
+    fn random_state(&mut self) {
+        self.env.random_state()
+    }
+
+    delegate_environment!();
+}
+
+/// Adds `gamma * potential(next_state) - potential(state)` to the rewards, with a potential of 0
+/// in a terminal state, a truncated episode keeps the potential of its last state. Shaping from a
+/// potential keeps the optimal policies of the environment.
+pub struct RewardShaping {
+    env: Box<dyn Environment>,
+    potential: Box<dyn Fn(State) -> Reward>,
+    gamma: f32,
+}
+
+impl RewardShaping {
+    pub fn new(env: Box<dyn Environment>, potential: impl Fn(State) -> Reward + 'static, gamma: f32) -> RewardShaping {
+        RewardShaping { env, potential: Box::new(potential), gamma }
+    }
+}
+
+impl Environment for RewardShaping {
+    fn reset(&mut self) -> State {
+        self.env.reset()
+    }
+
+    fn step(&mut self, action: Action) -> (State, Reward, bool) {
+        let potential = (self.potential)(self.env.state_id());
+        let (state, reward, done) = self.env.step(action);
+        let next_potential = if done && !self.env.truncated() { 0.0 } else { (self.potential)(state) };
+        (state, reward + self.gamma * next_potential - potential, done)
+    }
+
+    fn is_game_over(&self) -> bool {
+        self.env.is_game_over()
+    }
+
+    fn random_state(&mut self) {
+        self.env.random_state()
+    }
+
+    delegate_environment!();
+}
+
+/// Plays each action `repeat` times and sums the rewards. The repeats stop early when the
+/// episode ends or the action is no longer available.
+pub struct ActionRepeat {
+    env: Box<dyn Environment>,
+    repeat: usize,
+}
+
+impl ActionRepeat {
+    pub fn new(env: Box<dyn Environment>, repeat: usize) -> ActionRepeat {
+        assert!(repeat > 0, "repeat should be at least 1");
+        ActionRepeat { env, repeat }
+    }
+}
+
+impl Environment for ActionRepeat {
+    fn reset(&mut self) -> State {
+        self.env.reset()
+    }
+
+    fn step(&mut self, action: Action) -> (State, Reward, bool) {
+        let (mut state, mut total, mut done) = self.env.step(action);
+        for _ in 1..self.repeat {
+            if done || !self.env.available_actions().contains(&action) {
+                break;
+            }
+            let reward;
+            (state, reward, done) = self.env.step(action);
+            total += reward;
+        }
+        (state, total, done)
+    }
+
+    fn is_game_over(&self) -> bool {
+        self.env.is_game_over()
+    }
+
+    fn random_state(&mut self) {
+        self.env.random_state()
+    }
+
+    delegate_environment!();
+}
+
+/// Return and length of the episodes, the current one and every finished one.
+pub struct EpisodeStatistics {
+    env: Box<dyn Environment>,
+    episode_return: Reward,
+    episode_length: usize,
+    returns: Vec<Reward>,
+    lengths: Vec<usize>,
+}
+
+impl EpisodeStatistics {
+    pub fn new(env: Box<dyn Environment>) -> EpisodeStatistics {
+        EpisodeStatistics { env, episode_return: 0.0, episode_length: 0, returns: Vec::new(), lengths: Vec::new() }
+    }
+
+    pub fn episode_return(&self) -> Reward {
+        self.episode_return
+    }
+
+    pub fn episode_length(&self) -> usize {
+        self.episode_length
+    }
+
+    /// Returns of the finished episodes, oldest first.
+    pub fn returns(&self) -> &[Reward] {
+        &self.returns
+    }
+
+    pub fn lengths(&self) -> &[usize] {
+        &self.lengths
+    }
+
+    fn start(&mut self) {
+        self.episode_return = 0.0;
+        self.episode_length = 0;
+    }
+}
+
+impl Environment for EpisodeStatistics {
+    fn reset(&mut self) -> State {
+        self.start();
+        self.env.reset()
+    }
+
+    fn step(&mut self, action: Action) -> (State, Reward, bool) {
+        let (state, reward, done) = self.env.step(action);
+        self.episode_return += reward;
+        self.episode_length += 1;
+        if done {
+            self.returns.push(self.episode_return);
+            self.lengths.push(self.episode_length);
+        }
+        (state, reward, done)
+    }
+
+    fn is_game_over(&self) -> bool {
+        self.env.is_game_over()
+    }
+
+    fn random_state(&mut self) {
+        self.start();
+        self.env.random_state()
+    }
+
+    delegate_environment!();
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Transition {
+    pub state: State,
+    pub action: Action,
+    pub reward: Reward,
+    pub next_state: State,
+    pub done: bool,
+}
+
+/// Steps of a finished episode with the `render_ascii` picture before the first step and after each one.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Episode {
+    pub transitions: Vec<Transition>,
+    pub frames: Vec<String>,
+}
+
+/// Keeps the finished episodes, an episode left by a reset before its end is dropped.
+pub struct RecordEpisode {
+    env: Box<dyn Environment>,
+    current: Episode,
+    episodes: Vec<Episode>,
+}
+
+impl RecordEpisode {
+    pub fn new(env: Box<dyn Environment>) -> RecordEpisode {
+        RecordEpisode { env, current: Episode::default(), episodes: Vec::new() }
+    }
+
+    pub fn episodes(&self) -> &[Episode] {
+        &self.episodes
+    }
+
+    /// Hands over the recorded episodes and starts a new record.
+    pub fn take_episodes(&mut self) -> Vec<Episode> {
+        std::mem::take(&mut self.episodes)
+    }
+
+    fn start(&mut self) {
+        self.current = Episode { transitions: Vec::new(), frames: vec![self.env.render_ascii()] };
+    }
+}
+
+impl Environment for RecordEpisode {
+    fn reset(&mut self) -> State {
+        let state = self.env.reset();
+        self.start();
+        state
+    }
+
+    fn step(&mut self, action: Action) -> (State, Reward, bool) {
+        let state = self.env.state_id();
+        let (next_state, reward, done) = self.env.step(action);
+        self.current.transitions.push(Transition { state, action, reward, next_state, done });
+        self.current.frames.push(self.env.render_ascii());
+        if done {
+            self.episodes.push(std::mem::take(&mut self.current));
+        }
+        (next_state, reward, done)
+    }
+
+    fn is_game_over(&self) -> bool {
+        self.env.is_game_over()
+    }
+
+    fn random_state(&mut self) {
+        self.env.random_state();
+        self.start();
+    }
+
+    delegate_environment!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environment::line_world::LineWorld;
+
+    // LineWorld of 5 cells with the agent on `state`, 0 stays, 1 goes left and 2 right
+    fn line_world(state: State) -> Box<dyn Environment> {
+        let mut env = LineWorld::new(5, false, 2, 0);
+        env.set_state(state);
+        env
+    }
+
+    #[test]
+    fn test_time_limit() {
+        let mut env = TimeLimit::new(line_world(2), 2);
+        assert_eq!(env.step(0), (2, 0.0, false));
+        assert_eq!(env.step(0), (2, 0.0, true));
+        assert!(env.truncated() && env.is_game_over());
+
+        env.reset();
+        env.set_state(3);
+        assert_eq!(env.elapsed_steps(), 0);
+        assert_eq!(env.step(2), (4, 1.0, true));
+        assert!(!env.truncated());
+    }
+
+    #[test]
+    fn test_wrappers_report_the_truncation() {
+        let inner = TimeLimit::new(line_world(2), 1);
+        let mut env = EpisodeStatistics::new(Box::new(TimeLimit::new(Box::new(inner), 10)));
+        assert_eq!(env.step(0), (2, 0.0, true));
+        assert!(env.truncated());
+        assert!(!line_world(2).truncated());
+    }
+
+    #[test]
+    fn test_reward_scale_and_clip() {
+        let mut env = RewardScale::new(line_world(3), 0.5);
+        assert_eq!(env.step(2), (4, 0.5, true));
+
+        let mut env = RewardClip::new(line_world(1), -0.25, 0.25);
+        assert_eq!(env.step(1), (0, -0.25, true));
+    }
+
+    #[test]
+    fn test_reward_shaping() {
+        // Closer to the right end, higher potential
+        let mut env = RewardShaping::new(line_world(1), |state| state as Reward, 0.9);
+        let (state, reward, _) = env.step(2);
+        assert_eq!(state, 2);
+        assert!((reward - (0.9 * 2.0 - 1.0)).abs() < 1e-6);
+        env.step(2);
+        // No potential once the episode is over
+        let (_, reward, done) = env.step(2);
+        assert!(done && (reward - (1.0 - 3.0)).abs() < 1e-6);
+
+        // A cut episode still ends in a state with a potential
+        let mut env = RewardShaping::new(Box::new(TimeLimit::new(line_world(1), 1)), |state| state as Reward, 0.9);
+        let (_, reward, done) = env.step(2);
+        assert!(done && (reward - (0.9 * 2.0 - 1.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_action_repeat() {
+        let mut env = ActionRepeat::new(line_world(1), 2);
+        assert_eq!(env.step(2), (3, 0.0, false));
+        // Stops at the end instead of playing the second repeat
+        assert_eq!(env.step(2), (4, 1.0, true));
+    }
+
+    #[test]
+    fn test_episode_statistics() {
+        let mut env = EpisodeStatistics::new(line_world(2));
+        env.step(0);
+        env.step(2);
+        assert_eq!((env.episode_return(), env.episode_length()), (0.0, 2));
+        env.step(2);
+        assert_eq!(env.returns(), &[1.0]);
+        assert_eq!(env.lengths(), &[3]);
+
+        env.reset();
+        assert_eq!((env.episode_return(), env.episode_length()), (0.0, 0));
+        assert_eq!(env.returns().len(), 1);
+    }
+
+    #[test]
+    fn test_record_episode() {
+        let mut env = RecordEpisode::new(line_world(2));
+        env.reset();
+        env.set_state(3);
+        env.step(0);
+        env.step(2);
+
+        let episodes = env.take_episodes();
+        assert_eq!(episodes.len(), 1);
+        assert_eq!(episodes[0].transitions, vec![
+            Transition { state: 3, action: 0, reward: 0.0, next_state: 3, done: false },
+            Transition { state: 3, action: 2, reward: 1.0, next_state: 4, done: true },
+        ]);
+        assert_eq!(episodes[0].frames.len(), 3);
+        assert_eq!(episodes[0].frames[2], "____X");
+        assert!(env.episodes().is_empty());
+    }
+
+    #[test]
+    fn test_wrappers_compose() {
+        let inner = EpisodeStatistics::new(line_world(2));
+        let mut env = TimeLimit::new(Box::new(RewardScale::new(Box::new(inner), 2.0)), 10);
+        assert_eq!(env.step(2), (3, 0.0, false));
+        assert_eq!(env.step(2), (4, 2.0, true));
+        assert_eq!(env.all_states().len(), 5);
+        assert_eq!(env.render_ascii(), "____X");
+    }
+}
@@ -0,0 +1,125 @@
+// Checked conversions of the raw export arguments. Pointers are only checked for null,
+// a non-null pointer is trusted to be valid for the given length, as with slice::from_raw_parts.
+use std::ffi::{c_char, CStr};
+use super::error::{FfiError, FfiResult, Status};
+
+pub(crate) fn length(len: i32, name: &str) -> FfiResult<usize> {
+    if len < 0 {
+        return Err(FfiError::new(Status::InvalidLength, format!("{} is negative ({})", name, len)));
+    }
+    Ok(len as usize)
+}
+
+/// Null is accepted for an empty array.
+pub(crate) fn slice<'a, T>(ptr: *const T, len: i32, name: &str) -> FfiResult<&'a [T]> {
+    let len = length(len, name)?;
+    if len == 0 {
+        return Ok(&[]);
+    }
+    if ptr.is_null() {
+        return Err(FfiError::null(name));
+    }
+    Ok(unsafe { std::slice::from_raw_parts(ptr, len) })
+}
+
+pub(crate) fn slice_mut<'a, T>(ptr: *mut T, len: i32, name: &str) -> FfiResult<&'a mut [T]> {
+    let len = length(len, name)?;
+    if len == 0 {
+        return Ok(&mut []);
+    }
+    if ptr.is_null() {
+        return Err(FfiError::null(name));
+    }
+    Ok(unsafe { std::slice::from_raw_parts_mut(ptr, len) })
+}
+
+/// Flat row-major array split in rows of `sub` values, like `recompose_2d_vec`.
+pub(crate) fn rows(ptr: *const f32, len: i32, sub: i32, name: &str) -> FfiResult<Vec<Vec<f32>>> {
+    let values = slice(ptr, len, name)?;
+    if values.is_empty() {
+        return Ok(Vec::new());
+    }
+    let sub = length(sub, name)?;
+    if sub == 0 || values.len() % sub != 0 {
+        return Err(FfiError::dimension(format!("{} length {} is not a multiple of its sub array size {}", name, values.len(), sub)));
+    }
+    Ok(values.chunks(sub).map(|row| row.to_vec()).collect())
+}
+
+/// Rows of `inputs` and `outputs` pair up one to one and match the model sizes.
+pub(crate) fn check_samples(inputs: &[Vec<f32>], outputs: &[Vec<f32>], input_size: usize, output_size: usize) -> FfiResult<()> {
+    if inputs.len() != outputs.len() {
+        return Err(FfiError::dimension(format!("{} input rows for {} output rows", inputs.len(), outputs.len())));
+    }
+    if let Some(row) = inputs.first() {
+        expect_len(row.len(), input_size, "input row")?;
+    }
+    if let Some(row) = outputs.first() {
+        expect_len(row.len(), output_size, "output row")?;
+    }
+    Ok(())
+}
+
+/// NaN and infinities are refused before they reach a solver.
+pub(crate) fn finite(rows: &[Vec<f32>], name: &str) -> FfiResult<()> {
+    if let Some((row, value)) = rows.iter().enumerate().find_map(|(i, row)| row.iter().find(|x| !x.is_finite()).map(|x| (i, x))) {
+        return Err(FfiError::invalid(format!("{} row {} holds {}", name, row, value)));
+    }
+    Ok(())
+}
+
+pub(crate) fn expect_len(actual: usize, expected: usize, name: &str) -> FfiResult<()> {
+    if actual != expected {
+        return Err(FfiError::dimension(format!("{} has {} values, the model expects {}", name, actual, expected)));
+    }
+    Ok(())
+}
+
+pub(crate) fn positive(value: i32, name: &str) -> FfiResult<usize> {
+    if value <= 0 {
+        return Err(FfiError::invalid(format!("{} should be positive ({})", name, value)));
+    }
+    Ok(value as usize)
+}
+
+pub(crate) fn path(ptr: *const c_char) -> FfiResult<String> {
+    if ptr.is_null() {
+        return Err(FfiError::null("path"));
+    }
+    Ok(unsafe { CStr::from_ptr(ptr) }.to_string_lossy().into_owned())
+}
+
+/// Copies as many values as `output` holds, `written` receives the count.
+pub(crate) fn copy_out(values: &[f32], output: *mut f32, output_len: i32, written: *mut i32) -> FfiResult<()> {
+    let output_slice = slice_mut(output, output_len, "output")?;
+    let count = values.len().min(output_slice.len());
+    output_slice[..count].copy_from_slice(&values[..count]);
+    write(written, count as i32, "written")
+}
+
+/// Writes a result in a host out parameter.
+pub(crate) fn write<T>(out: *mut T, value: T, name: &str) -> FfiResult<()> {
+    if out.is_null() {
+        return Err(FfiError::null(name));
+    }
+    unsafe { out.write(value) };
+    Ok(())
+}
+
+/// `TryFrom<i32>` for a fieldless enum taken by the exports as an i32 code. A C enum can hold any
+/// value, so an unknown code becomes an InvalidArgument instead of an invalid Rust enum.
+macro_rules! enum_code {
+    ($name:ident { $($variant:ident),+ $(,)? }) => {
+        impl TryFrom<i32> for $name {
+            type Error = $crate::ffi::error::FfiError;
+
+            fn try_from(code: i32) -> Result<$name, Self::Error> {
+                [$($name::$variant),+]
+                    .into_iter()
+                    .find(|variant| *variant as i32 == code)
+                    .ok_or_else(|| $crate::ffi::error::FfiError::invalid(format!("{} is not a valid {} code", code, stringify!($name))))
+            }
+        }
+    };
+}
+pub(crate) use enum_code;
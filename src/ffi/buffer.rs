@@ -0,0 +1,99 @@
+use std::mem::ManuallyDrop;
+use super::error::{ffi_call, Status};
+
+/// f32 array owned by Rust and handed to the host. `ptr`, `len` and `cap` come from a `Vec<f32>`,
+/// the host reads `len` values then gives the buffer back to `free_float_buffer`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct FloatBuffer {
+    pub ptr: *mut f32,
+    pub len: usize,
+    pub cap: usize,
+}
+
+/// Byte array owned by Rust, e.g. a serialized model. Released with `free_byte_buffer`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct ByteBuffer {
+    pub ptr: *mut u8,
+    pub len: usize,
+    pub cap: usize,
+}
+
+// Zeroed buffers, what an out parameter holds before a call
+impl Default for FloatBuffer {
+    fn default() -> Self {
+        FloatBuffer { ptr: std::ptr::null_mut(), len: 0, cap: 0 }
+    }
+}
+
+impl Default for ByteBuffer {
+    fn default() -> Self {
+        ByteBuffer { ptr: std::ptr::null_mut(), len: 0, cap: 0 }
+    }
+}
+
+impl FloatBuffer {
+    pub fn from_vec(values: Vec<f32>) -> FloatBuffer {
+        let mut values = ManuallyDrop::new(values);
+        FloatBuffer { ptr: values.as_mut_ptr(), len: values.len(), cap: values.capacity() }
+    }
+
+    /// Takes the allocation back.
+    ///
+    /// # Safety
+    /// The buffer must come from `from_vec` (or be null) and must not be used afterwards.
+    pub unsafe fn into_vec(self) -> Vec<f32> {
+        if self.ptr.is_null() {
+            return Vec::new();
+        }
+        Vec::from_raw_parts(self.ptr, self.len, self.cap)
+    }
+
+    /// # Safety
+    /// The buffer must come from `from_vec` (or be null) and not be freed yet.
+    pub unsafe fn as_slice(&self) -> &[f32] {
+        if self.ptr.is_null() { &[] } else { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl ByteBuffer {
+    pub fn from_vec(bytes: Vec<u8>) -> ByteBuffer {
+        let mut bytes = ManuallyDrop::new(bytes);
+        ByteBuffer { ptr: bytes.as_mut_ptr(), len: bytes.len(), cap: bytes.capacity() }
+    }
+
+    /// Takes the allocation back.
+    ///
+    /// # Safety
+    /// The buffer must come from `from_vec` (or be null) and must not be used afterwards.
+    pub unsafe fn into_vec(self) -> Vec<u8> {
+        if self.ptr.is_null() {
+            return Vec::new();
+        }
+        Vec::from_raw_parts(self.ptr, self.len, self.cap)
+    }
+
+    /// # Safety
+    /// The buffer must come from `from_vec` (or be null) and not be freed yet.
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        if self.ptr.is_null() { &[] } else { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+// A zeroed buffer (null ptr) is accepted so the host can free unconditionally
+#[no_mangle]
+pub(crate) extern "C" fn free_float_buffer(buffer: FloatBuffer) -> Status {
+    ffi_call(|| {
+        drop(unsafe { buffer.into_vec() });
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn free_byte_buffer(buffer: ByteBuffer) -> Status {
+    ffi_call(|| {
+        drop(unsafe { buffer.into_vec() });
+        Ok(())
+    })
+}
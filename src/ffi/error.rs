@@ -0,0 +1,100 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+use std::io;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Returned by every export. Anything but `Ok` leaves a message in `rustml_last_error`
+/// and the out parameters untouched.
+#[repr(C)]
+#[must_use]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Ok = 0,
+    NullPointer = 1,
+    /// Negative length or size
+    InvalidLength = 2,
+    /// Buffer length that does not match the model or the sub array size
+    DimensionMismatch = 3,
+    InvalidArgument = 4,
+    /// File could not be read or written, or is not a valid model file
+    Io = 5,
+    /// Rust code panicked, the model may be left half updated
+    Panic = 6,
+    /// Model handle that was deleted, never created, or belongs to another kind of model
+    InvalidHandle = 7,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct FfiError {
+    pub status: Status,
+    pub message: String,
+}
+
+pub type FfiResult<T> = Result<T, FfiError>;
+
+impl FfiError {
+    pub fn new(status: Status, message: impl Into<String>) -> FfiError {
+        FfiError { status, message: message.into() }
+    }
+
+    pub fn null(name: &str) -> FfiError {
+        FfiError::new(Status::NullPointer, format!("{} is null", name))
+    }
+
+    pub fn dimension(message: impl Into<String>) -> FfiError {
+        FfiError::new(Status::DimensionMismatch, message)
+    }
+
+    pub fn invalid(message: impl Into<String>) -> FfiError {
+        FfiError::new(Status::InvalidArgument, message)
+    }
+}
+
+impl From<io::Error> for FfiError {
+    fn from(error: io::Error) -> FfiError {
+        FfiError::new(Status::Io, error.to_string())
+    }
+}
+
+thread_local! {
+    // Per host thread, so concurrent callers only see their own failures
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: &str) {
+    // Interior NUL would truncate the C string, replace it instead of failing
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "unknown panic".to_string(),
+    }
+}
+
+/// Body of an export: errors and panics become a `Status`, nothing unwinds into the host.
+pub(crate) fn ffi_call(body: impl FnOnce() -> FfiResult<()>) -> Status {
+    LAST_ERROR.with(|last| *last.borrow_mut() = None);
+    match catch_unwind(AssertUnwindSafe(body)) {
+        Ok(Ok(())) => Status::Ok,
+        Ok(Err(error)) => {
+            set_last_error(&error.message);
+            error.status
+        }
+        Err(payload) => {
+            set_last_error(&format!("panic: {}", panic_message(payload.as_ref())));
+            Status::Panic
+        }
+    }
+}
+
+/// Message of the last failed call on this thread, null when it succeeded.
+/// The string is owned by the library and valid until the next call on this thread.
+#[no_mangle]
+pub extern "C" fn rustml_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ref().map_or(std::ptr::null(), |message| message.as_ptr()))
+}
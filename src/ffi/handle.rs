@@ -0,0 +1,112 @@
+// Models handed to the host live in one global registry, the host only sees a `Handle`.
+// A handle packs the slot index (low 32 bits) and the generation of the model stored in it
+// (high 32 bits), so a deleted handle, or one whose slot was reused, is reported instead of
+// dereferenced.
+use std::any::{type_name, Any};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, RwLock};
+use super::error::{FfiError, FfiResult, Status};
+
+/// Opaque id of a model, 0 is never a valid handle.
+pub type Handle = u64;
+
+type Model = Arc<dyn Any + Send + Sync>;
+
+struct Slot {
+    // Starts at 1 and changes on every delete, so no handle is 0
+    generation: u32,
+    model: Option<Model>,
+}
+
+struct Registry {
+    slots: Vec<Slot>,
+    free: Vec<usize>,
+}
+
+static REGISTRY: Mutex<Registry> = Mutex::new(Registry { slots: Vec::new(), free: Vec::new() });
+
+// Only held to look a slot up, never while a model runs
+fn registry() -> MutexGuard<'static, Registry> {
+    REGISTRY.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn split(handle: Handle) -> (usize, u32) {
+    ((handle & 0xffff_ffff) as usize, (handle >> 32) as u32)
+}
+
+fn short_name<T>() -> &'static str {
+    type_name::<T>().rsplit("::").next().unwrap_or_default()
+}
+
+fn invalid(handle: Handle) -> FfiError {
+    FfiError::new(Status::InvalidHandle, format!("handle {:#x} was deleted or never created", handle))
+}
+
+fn wrong_type<T>(handle: Handle) -> FfiError {
+    FfiError::new(Status::InvalidHandle, format!("handle {:#x} is not a {}", handle, short_name::<T>()))
+}
+
+/// Stores a new model and returns its handle, the model lives until `remove`.
+pub(crate) fn insert<T: Any + Send + Sync>(model: T) -> Handle {
+    let model: Model = Arc::new(RwLock::new(model));
+    let mut registry = registry();
+    let index = registry.free.pop().unwrap_or_else(|| {
+        registry.slots.push(Slot { generation: 1, model: None });
+        registry.slots.len() - 1
+    });
+    let slot = &mut registry.slots[index];
+    slot.model = Some(model);
+    ((slot.generation as u64) << 32) | index as u64
+}
+
+/// Writes the handle of a new model in a host out parameter.
+pub(crate) fn write<T: Any + Send + Sync>(out: *mut Handle, model: T) -> FfiResult<()> {
+    // Checked first, a model nobody can delete would stay in the registry forever
+    if out.is_null() {
+        return Err(FfiError::null("out"));
+    }
+    unsafe { out.write(insert(model)) };
+    Ok(())
+}
+
+fn get<T: Any + Send + Sync>(handle: Handle) -> FfiResult<Arc<RwLock<T>>> {
+    let (index, generation) = split(handle);
+    let model = registry().slots.get(index)
+        .filter(|slot| slot.generation == generation)
+        .and_then(|slot| slot.model.clone())
+        .ok_or_else(|| invalid(handle))?;
+    model.downcast::<RwLock<T>>().map_err(|_| wrong_type::<T>(handle))
+}
+
+/// Shared access, several host threads can read the same model at once.
+pub(crate) fn with<T: Any + Send + Sync, R>(handle: Handle, body: impl FnOnce(&T) -> FfiResult<R>) -> FfiResult<R> {
+    let model = get::<T>(handle)?;
+    // A panic in an earlier call is already reported as Status::Panic, keep serving the model
+    let model = model.read().unwrap_or_else(PoisonError::into_inner);
+    body(&model)
+}
+
+/// Exclusive access, calls on the same model from other threads wait for this one.
+pub(crate) fn with_mut<T: Any + Send + Sync, R>(handle: Handle, body: impl FnOnce(&mut T) -> FfiResult<R>) -> FfiResult<R> {
+    let model = get::<T>(handle)?;
+    let mut model = model.write().unwrap_or_else(PoisonError::into_inner);
+    body(&mut model)
+}
+
+/// Deletes the model behind `handle`, it fails with `Status::InvalidHandle` from now on.
+/// A call still running on another thread keeps the model alive until it returns.
+pub(crate) fn remove<T: Any + Send + Sync>(handle: Handle) -> FfiResult<Arc<RwLock<T>>> {
+    let mut registry = registry();
+    let (index, generation) = split(handle);
+    let slot = registry.slots.get_mut(index)
+        .filter(|slot| slot.generation == generation)
+        .ok_or_else(|| invalid(handle))?;
+    let model = slot.model.clone()
+        .ok_or_else(|| invalid(handle))?
+        .downcast::<RwLock<T>>()
+        .map_err(|_| wrong_type::<T>(handle))?;
+
+    slot.model = None;
+    slot.generation = slot.generation.checked_add(1).unwrap_or(1);
+    registry.free.push(index);
+    Ok(model)
+}
@@ -0,0 +1,7 @@
+pub(crate) mod args;
+pub mod buffer;
+pub mod error;
+pub mod handle;
+#[cfg(test)]
+pub(crate) mod test_support;
+mod tester;
@@ -0,0 +1,23 @@
+// Call an export from a test and unwrap its out parameter
+use std::any::Any;
+use std::sync::Arc;
+use super::error::Status;
+use super::handle::{self, Handle};
+
+pub(crate) fn created(call: impl FnOnce(*mut Handle) -> Status) -> Handle {
+    let handle = output(call);
+    assert_ne!(handle, 0);
+    handle
+}
+
+pub(crate) fn output<T: Default>(call: impl FnOnce(*mut T) -> Status) -> T {
+    let mut value = T::default();
+    assert_eq!(call(&mut value), Status::Ok);
+    value
+}
+
+/// Deletes the model behind `handle` and hands it back, to check its fields.
+pub(crate) fn taken<T: Any + Send + Sync>(handle: Handle) -> T {
+    let model = handle::remove::<T>(handle).unwrap();
+    Arc::try_unwrap(model).ok().expect("model still in use").into_inner().unwrap()
+}
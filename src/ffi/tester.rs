@@ -0,0 +1,240 @@
+// Ownership round trips, also run under Miri and ASan by .github/workflows/sanitizers.yml:
+// MIRIFLAGS=-Zmiri-disable-isolation cargo +nightly miri test --lib ffi::
+// RUSTFLAGS=-Zsanitizer=address cargo +nightly test --lib --target x86_64-unknown-linux-gnu ffi::
+#[cfg(test)]
+mod tests {
+    use std::ffi::{CStr, CString};
+    use crate::ffi::buffer::{free_byte_buffer, free_float_buffer, ByteBuffer, FloatBuffer};
+    use crate::ffi::error::{ffi_call, rustml_last_error, FfiError, Status};
+    use crate::deep_reinforce_learning::deep_q_learning::create::init_dql;
+    use crate::deep_reinforce_learning::deep_q_learning::delete::delete_dql;
+    use crate::deep_reinforce_learning::deep_q_learning::dql_struct::DeepQLearning;
+    use crate::deep_reinforce_learning::deep_q_learning::train::set_dql_regularization;
+    use crate::ffi::handle;
+    use crate::ffi::test_support::{created, output};
+    use crate::metrics::classification::{metrics_f1, metrics_precision, metrics_recall};
+    use crate::linear_model::create::init_linear_model;
+    use crate::linear_model::lm_struct::LinearModelKind;
+    use crate::linear_model::predict::predict_linear_model;
+    use crate::neural_network::create::{init, set_hidden_activation};
+    use crate::neural_network::delete::delete_pmc;
+    use crate::neural_network::initializer::WeightInit;
+    use crate::neural_network::manager::load_pmc;
+    use crate::neural_network::predict::predict;
+    use crate::neural_network::regularization::{Regularization, RegularizationParams};
+    use crate::neural_network::train::{one_step_train_pmc, train_pmc_model};
+    use crate::preprocessing::transform::fit_scaler;
+    use crate::rbf::create::init_rbf;
+    use crate::rbf::rbf_struct::{RbfCenters, RbfTraining};
+    use crate::svm::create::init_svm;
+    use crate::svm::kernel::KernelParams;
+
+    #[test]
+    fn float_round_trip() {
+        let mut values = Vec::with_capacity(8);
+        values.extend([1.0f32, -2.5, 3.0]);
+        let buffer = FloatBuffer::from_vec(values);
+        assert_eq!((buffer.len, buffer.cap), (3, 8));
+        assert_eq!(unsafe { buffer.as_slice() }, &[1.0, -2.5, 3.0]);
+
+        let values = unsafe { buffer.into_vec() };
+        assert_eq!(values, vec![1.0, -2.5, 3.0]);
+        assert_eq!(values.capacity(), 8);
+    }
+
+    #[test]
+    fn byte_round_trip() {
+        let buffer = ByteBuffer::from_vec(b"rustml".to_vec());
+        assert_eq!(unsafe { buffer.as_slice() }, b"rustml");
+        assert_eq!(free_byte_buffer(buffer), Status::Ok);
+    }
+
+    #[test]
+    fn empty_and_null_buffers() {
+        let empty = FloatBuffer::from_vec(Vec::new());
+        assert_eq!(empty.len, 0);
+        assert_eq!(free_float_buffer(empty), Status::Ok);
+
+        // Zeroed struct from the host side
+        assert_eq!(free_float_buffer(FloatBuffer::default()), Status::Ok);
+        let null = ByteBuffer { ptr: std::ptr::null_mut(), len: 0, cap: 0 };
+        assert!(unsafe { null.as_slice() }.is_empty());
+        assert_eq!(free_byte_buffer(null), Status::Ok);
+    }
+
+    #[test]
+    fn predict_buffers_are_freed() {
+        let topology = [2, 3, 2];
+        let model = created(|out| init(topology.as_ptr(), 3, WeightInit::XavierUniform as i32, 0.0, 1, out));
+        let inputs = [0.5f32, -0.5];
+        for _ in 0..100 {
+            let buffer = output(|out| predict(model, inputs.as_ptr(), 2, false, out));
+            assert_eq!(buffer.len, 2);
+            assert_eq!(free_float_buffer(buffer), Status::Ok);
+        }
+
+        let linear = created(|out| init_linear_model(LinearModelKind::LinearRegression as i32, 2, 3, 0, out));
+        let buffer = output(|out| predict_linear_model(linear, inputs.as_ptr(), 2, out));
+        assert_eq!(unsafe { buffer.as_slice() }.len(), 3);
+        assert_eq!(free_float_buffer(buffer), Status::Ok);
+    }
+
+    fn last_error() -> Option<String> {
+        let message = rustml_last_error();
+        (!message.is_null()).then(|| unsafe { CStr::from_ptr(message) }.to_string_lossy().into_owned())
+    }
+
+    #[test]
+    fn invalid_arguments_are_reported() {
+        let topology = [2, 3, 1];
+        let mut model = 0;
+
+        assert_eq!(init(std::ptr::null(), 3, WeightInit::Uniform as i32, 0.0, 0, &mut model), Status::NullPointer);
+        assert_eq!(last_error().unwrap(), "arr is null");
+        assert_eq!(init(topology.as_ptr(), -3, WeightInit::Uniform as i32, 0.0, 0, &mut model), Status::InvalidLength);
+        assert_eq!(init(topology.as_ptr(), 1, WeightInit::Uniform as i32, 0.0, 0, &mut model), Status::InvalidArgument);
+        assert_eq!(init(topology.as_ptr(), 3, WeightInit::Uniform as i32, 0.0, 0, std::ptr::null_mut()), Status::NullPointer);
+        assert_eq!(model, 0);
+
+        // A successful call clears the message
+        assert_eq!(init(topology.as_ptr(), 3, WeightInit::Uniform as i32, 0.0, 0, &mut model), Status::Ok);
+        assert!(last_error().is_none());
+        let inputs = [1.0f32, 2.0, 3.0];
+        let mut buffer = FloatBuffer::default();
+        assert_eq!(predict(model, inputs.as_ptr(), 3, true, &mut buffer), Status::DimensionMismatch);
+        assert_eq!(last_error().unwrap(), "inputs has 3 values, the model expects 2");
+        assert!(buffer.ptr.is_null());
+        assert_eq!(predict(0, inputs.as_ptr(), 2, true, &mut buffer), Status::InvalidHandle);
+
+        let mut written = 0;
+        assert_eq!(train_pmc_model(model, inputs.as_ptr(), 3, 2, inputs.as_ptr(), 1, 1,
+                                   std::ptr::null(), 0, 2, std::ptr::null(), 0, 1,
+                                   0.1, 10, true, None, 0, 0, std::ptr::null_mut(), 0, &mut written), Status::DimensionMismatch);
+        assert_eq!(last_error().unwrap(), "inputs length 3 is not a multiple of its sub array size 2");
+
+        assert_eq!(delete_pmc(model), Status::Ok);
+        assert_eq!(delete_pmc(0), Status::InvalidHandle);
+    }
+
+    #[test]
+    fn unknown_enum_codes_are_rejected() {
+        // Codes past the last variant, and negative ones, never reach a Rust enum
+        let topology = [2, 3, 1];
+        let mut model = 0;
+        for code in [5, -1, i32::MAX] {
+            assert_eq!(init(topology.as_ptr(), 3, code, 0.0, 0, &mut model), Status::InvalidArgument);
+            assert_eq!(last_error().unwrap(), format!("{} is not a valid WeightInit code", code));
+        }
+        assert_eq!(model, 0);
+
+        let network = created(|out| init(topology.as_ptr(), 3, WeightInit::Uniform as i32, 0.0, 0, out));
+        assert_eq!(set_hidden_activation(network, 4), Status::InvalidArgument);
+        assert_eq!(last_error().unwrap(), "4 is not a valid Activation code");
+        let clipping = RegularizationParams { l2: 0.0, dropout: 0.0, clipping: 3, clip_value: 1.0 };
+        let inputs = [1.0f32, 0.0];
+        let outputs = [1.0f32];
+        assert_eq!(one_step_train_pmc(network, inputs.as_ptr(), 2, outputs.as_ptr(), 1, 0.1, true, Some(&clipping)), Status::InvalidArgument);
+        let mut written = 0;
+        assert_eq!(train_pmc_model(network, inputs.as_ptr(), 2, 2, outputs.as_ptr(), 1, 1,
+                                   std::ptr::null(), 0, 2, std::ptr::null(), 0, 1,
+                                   0.1, 1, true, Some(&clipping), 0, 0, std::ptr::null_mut(), 0, &mut written), Status::InvalidArgument);
+        assert_eq!(last_error().unwrap(), "3 is not a valid GradientClipping code");
+        assert_eq!(delete_pmc(network), Status::Ok);
+
+        assert_eq!(init_linear_model(3, 2, 1, 0, &mut model), Status::InvalidArgument);
+        assert_eq!(init_rbf(2, 1, 2, 1.0, 2, RbfTraining::PseudoInverse as i32, false, 0, &mut model), Status::InvalidArgument);
+        assert_eq!(init_rbf(2, 1, 2, 1.0, RbfCenters::KMeans as i32, 2, false, 0, &mut model), Status::InvalidArgument);
+        let kernel = KernelParams { kind: 3, degree: 1, gamma: 1.0, coef0: 0.0 };
+        assert_eq!(init_svm(2, 1, kernel, 1.0, &mut model), Status::InvalidArgument);
+        assert_eq!(last_error().unwrap(), "3 is not a valid KernelKind code");
+        assert_eq!(fit_scaler(3, inputs.as_ptr(), 2, 2, &mut model), Status::InvalidArgument);
+        assert_eq!(model, 0);
+
+        let labels = [1.0f32, -1.0];
+        let mut score = -1.0;
+        assert_eq!(metrics_precision(inputs.as_ptr(), labels.as_ptr(), 2, 1, 2, &mut score), Status::InvalidArgument);
+        assert_eq!(metrics_recall(inputs.as_ptr(), labels.as_ptr(), 2, 1, 2, &mut score), Status::InvalidArgument);
+        assert_eq!(metrics_f1(inputs.as_ptr(), labels.as_ptr(), 2, 1, -1, &mut score), Status::InvalidArgument);
+        assert_eq!(last_error().unwrap(), "-1 is not a valid Average code");
+        assert_eq!(score, -1.0);
+
+        let agent = created(|out| init_dql(2, 2, 0.01, 0.9, 1.0, 0.01, 0.99, 0, out));
+        assert_eq!(set_dql_regularization(agent, clipping), Status::InvalidArgument);
+        assert_eq!(handle::with(agent, |agent: &DeepQLearning| Ok(agent.regularization)), Ok(Regularization::default()));
+        assert_eq!(delete_dql(agent), Status::Ok);
+    }
+
+    #[test]
+    fn deleted_handles_are_rejected() {
+        let topology = [2, 3, 1];
+        let model = created(|out| init(topology.as_ptr(), 3, WeightInit::Uniform as i32, 0.0, 0, out));
+        assert_eq!(delete_pmc(model), Status::Ok);
+        assert_eq!(delete_pmc(model), Status::InvalidHandle);
+        assert_eq!(last_error().unwrap(), format!("handle {:#x} was deleted or never created", model));
+
+        let inputs = [1.0f32, 2.0];
+        let mut buffer = FloatBuffer::default();
+        assert_eq!(predict(model, inputs.as_ptr(), 2, true, &mut buffer), Status::InvalidHandle);
+        assert!(buffer.ptr.is_null());
+
+        // The slot is reused under a new generation, the old handle stays invalid
+        let reused = created(|out| init(topology.as_ptr(), 3, WeightInit::Uniform as i32, 0.0, 0, out));
+        assert_ne!(reused, model);
+        assert_eq!(predict(model, inputs.as_ptr(), 2, true, &mut buffer), Status::InvalidHandle);
+        assert_eq!(delete_pmc(reused), Status::Ok);
+    }
+
+    #[test]
+    fn handles_keep_their_model_kind() {
+        let linear = created(|out| init_linear_model(LinearModelKind::LinearRegression as i32, 2, 1, 0, out));
+        let inputs = [1.0f32, 2.0];
+        let mut buffer = FloatBuffer::default();
+        assert_eq!(predict(linear, inputs.as_ptr(), 2, true, &mut buffer), Status::InvalidHandle);
+        assert_eq!(last_error().unwrap(), format!("handle {:#x} is not a NeuralNetwork", linear));
+        assert_eq!(delete_pmc(linear), Status::InvalidHandle);
+
+        // Still a live linear model
+        let buffer = output(|out| predict_linear_model(linear, inputs.as_ptr(), 2, out));
+        assert_eq!(free_float_buffer(buffer), Status::Ok);
+    }
+
+    #[test]
+    fn handles_are_shared_across_threads() {
+        let topology = [2, 3, 1];
+        let model = created(|out| init(topology.as_ptr(), 3, WeightInit::XavierUniform as i32, 0.0, 1, out));
+        let threads: Vec<_> = (0..4).map(|_| std::thread::spawn(move || {
+            let inputs = [0.5f32, -0.5];
+            for _ in 0..50 {
+                let buffer = output(|out| predict(model, inputs.as_ptr(), 2, false, out));
+                assert_eq!(free_float_buffer(buffer), Status::Ok);
+            }
+        })).collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        assert_eq!(delete_pmc(model), Status::Ok);
+    }
+
+    #[test]
+    fn panics_do_not_unwind() {
+        let status = ffi_call(|| panic!("boom"));
+        assert_eq!(status, Status::Panic);
+        assert_eq!(last_error().unwrap(), "panic: boom");
+    }
+
+    #[test]
+    fn last_error_is_per_thread() {
+        assert_eq!(ffi_call(|| Err(FfiError::invalid("main thread"))), Status::InvalidArgument);
+        std::thread::spawn(|| assert!(last_error().is_none())).join().unwrap();
+        assert_eq!(last_error().unwrap(), "main thread");
+    }
+
+    #[test]
+    fn io_errors_carry_the_reason() {
+        let missing = CString::new("/nonexistent/rustml_model.bin").unwrap();
+        let mut model = 0;
+        assert_eq!(load_pmc(missing.as_ptr(), &mut model), Status::Io);
+        assert!(last_error().unwrap().contains("No such file"));
+        assert_eq!(load_pmc(std::ptr::null(), &mut model), Status::NullPointer);
+    }
+}
@@ -1,5 +1,18 @@
 pub mod environment;
 pub mod neural_network;
+pub mod linear_model;
+pub mod rbf;
+pub mod svm;
+pub mod dataset;
+pub mod preprocessing;
+pub mod metrics;
+pub mod model_selection;
+pub mod clustering;
+pub mod decomposition;
 pub mod basic_function;
+pub mod ffi;
+pub mod linalg;
 pub mod deep_reinforce_learning;
-pub mod reinforce_learning;
\ No newline at end of file
+pub mod reinforce_learning;
+#[cfg(feature = "python")]
+pub mod python;
@@ -0,0 +1,366 @@
+use super::matrix::Matrix;
+use super::scalar::Scalar;
+
+// Relative ridge added to X^T X when it is singular
+const RIDGE: f64 = 1e-6;
+
+// Jacobi sweeps before the SVD stops, it converges in a handful for well scaled matrices
+const JACOBI_SWEEPS: usize = 60;
+
+/// A = U diag(singular_values) V^T, U is m x k and V is n x k with k = min(m, n).
+#[derive(Clone, Debug)]
+pub struct Svd<T: Scalar> {
+    pub u: Matrix<T>,
+    pub singular_values: Vec<T>,
+    pub v: Matrix<T>,
+}
+
+/// PA = LU with partial pivoting, L (unit diagonal) and U packed in one matrix.
+#[derive(Clone, Debug)]
+pub struct Lu<T: Scalar> {
+    pub lu: Matrix<T>,
+    /// Row i of PA is row permutation[i] of A
+    pub permutation: Vec<usize>,
+    odd_permutation: bool,
+}
+
+impl<T: Scalar> Matrix<T> {
+    /// None when the matrix is not square, holds a NaN or an infinity, or is singular to working precision.
+    pub fn lu(&self) -> Option<Lu<T>> {
+        if self.rows != self.cols || !self.data.iter().all(|x| x.is_finite()) {
+            return None;
+        }
+        let n = self.rows;
+        let scale = self.data.iter().fold(T::ZERO, |max, &x| if x.abs() > max { x.abs() } else { max });
+        let threshold = scale * T::from_usize(n) * T::EPSILON;
+
+        let mut lu = self.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut odd_permutation = false;
+        for col in 0..n {
+            let pivot = (col..n).max_by(|&i, &j| lu[(i, col)].abs().total_cmp(&lu[(j, col)].abs()))?;
+            // Elimination can still overflow a finite matrix
+            if !lu[(pivot, col)].is_finite() || lu[(pivot, col)].abs() <= threshold {
+                return None;
+            }
+            if pivot != col {
+                for k in 0..n {
+                    lu.data.swap(col * n + k, pivot * n + k);
+                }
+                permutation.swap(col, pivot);
+                odd_permutation = !odd_permutation;
+            }
+
+            let pivot_row = lu.row(col).to_vec();
+            for row in col + 1..n {
+                let factor = lu[(row, col)] / pivot_row[col];
+                lu[(row, col)] = factor;
+                for (value, &pivot_value) in lu.row_mut(row)[col + 1..].iter_mut().zip(&pivot_row[col + 1..]) {
+                    *value -= factor * pivot_value;
+                }
+            }
+        }
+        Some(Lu { lu, permutation, odd_permutation })
+    }
+
+    /// Lower triangular L with A = L L^T, None when A is not symmetric positive definite.
+    pub fn cholesky(&self) -> Option<Matrix<T>> {
+        if self.rows != self.cols {
+            return None;
+        }
+        let n = self.rows;
+        let mut lower = Matrix::zeros(n, n);
+        for i in 0..n {
+            for j in 0..=i {
+                let dot: T = (0..j).map(|k| lower[(i, k)] * lower[(j, k)]).sum();
+                if i == j {
+                    let diagonal = self[(i, i)] - dot;
+                    if diagonal <= T::ZERO || diagonal.is_nan() {
+                        return None;
+                    }
+                    lower[(i, j)] = diagonal.sqrt();
+                } else {
+                    lower[(i, j)] = (self[(i, j)] - dot) / lower[(j, j)];
+                }
+            }
+        }
+        Some(lower)
+    }
+
+    /// x with A x = b, b has one column per right-hand side.
+    pub fn solve(&self, b: &Matrix<T>) -> Option<Matrix<T>> {
+        self.lu().map(|lu| lu.solve(b))
+    }
+
+    pub fn inverse(&self) -> Option<Matrix<T>> {
+        self.solve(&Matrix::identity(self.rows))
+    }
+
+    /// argmin ||A x - b|| through the normal equation (A^T A) x = A^T b.
+    /// A tiny ridge is added when the columns of A are collinear. None for a non-finite A or b,
+    /// or when even the ridge leaves no solution.
+    pub fn least_squares(&self, b: &Matrix<T>) -> Option<Matrix<T>> {
+        if !self.data.iter().chain(&b.data).all(|x| x.is_finite()) {
+            return None;
+        }
+        let transposed = self.transpose();
+        let mut gram = transposed.matmul(self);
+        let rhs = transposed.matmul(b);
+
+        gram.solve(&rhs).or_else(|| {
+            let trace: T = (0..gram.rows).map(|i| gram[(i, i)]).sum();
+            let mean_diagonal = trace / T::from_usize(gram.rows.max(1));
+            let ridge = T::from_f64(RIDGE) * if mean_diagonal > T::ONE { mean_diagonal } else { T::ONE };
+            for i in 0..gram.rows {
+                gram[(i, i)] += ridge;
+            }
+            gram.cholesky().map(|lower| cholesky_solve(&lower, &rhs))
+        })
+    }
+
+    /// Thin singular value decomposition by one-sided Jacobi rotations, which orthogonalize
+    /// the columns of A until A V = U diag(s).
+    pub fn svd(&self) -> Svd<T> {
+        if self.rows < self.cols {
+            let Svd { u, singular_values, v } = self.transpose().svd();
+            return Svd { u: v, singular_values, v: u };
+        }
+        let (m, n) = self.shape();
+        let mut u = self.clone();
+        let mut v = Matrix::identity(n);
+        for _ in 0..JACOBI_SWEEPS {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in p + 1..n {
+                    let (mut alpha, mut beta, mut gamma) = (T::ZERO, T::ZERO, T::ZERO);
+                    for i in 0..m {
+                        alpha += u[(i, p)] * u[(i, p)];
+                        beta += u[(i, q)] * u[(i, q)];
+                        gamma += u[(i, p)] * u[(i, q)];
+                    }
+                    if gamma.abs() <= T::EPSILON * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+                    // Rotation angle that zeroes the dot product of columns p and q
+                    let zeta = (beta - alpha) / (T::from_f64(2.0) * gamma);
+                    let t = T::ONE / (zeta.abs() + (T::ONE + zeta * zeta).sqrt());
+                    let t = if zeta < T::ZERO { -t } else { t };
+                    let c = T::ONE / (T::ONE + t * t).sqrt();
+                    let s = c * t;
+                    for matrix in [&mut u, &mut v] {
+                        for i in 0..matrix.rows {
+                            let (x, y) = (matrix[(i, p)], matrix[(i, q)]);
+                            matrix[(i, p)] = c * x - s * y;
+                            matrix[(i, q)] = s * x + c * y;
+                        }
+                    }
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let singular_values: Vec<T> = (0..n).map(|j| (0..m).map(|i| u[(i, j)] * u[(i, j)]).sum::<T>().sqrt()).collect();
+        for (j, &sigma) in singular_values.iter().enumerate() {
+            if sigma > T::ZERO {
+                for i in 0..m {
+                    u[(i, j)] = u[(i, j)] / sigma;
+                }
+            }
+        }
+        Svd { u, singular_values, v }
+    }
+
+    /// Moore-Penrose pseudo-inverse V diag(1 / s) U^T from the SVD, the singular values below
+    /// max(m, n) * epsilon * the largest one count as zero. Rank deficient matrices get the minimum norm solution.
+    pub fn pinv(&self) -> Matrix<T> {
+        let Svd { u, singular_values, v } = self.svd();
+        let largest = singular_values.iter().fold(T::ZERO, |max, &sigma| if sigma > max { sigma } else { max });
+        let threshold = T::from_usize(self.rows.max(self.cols)) * T::EPSILON * largest;
+
+        let mut pinv = Matrix::zeros(self.cols, self.rows);
+        for (k, &sigma) in singular_values.iter().enumerate() {
+            if sigma <= threshold {
+                continue;
+            }
+            for i in 0..self.cols {
+                let factor = v[(i, k)] / sigma;
+                for j in 0..self.rows {
+                    pinv[(i, j)] += factor * u[(j, k)];
+                }
+            }
+        }
+        pinv
+    }
+}
+
+impl<T: Scalar> Lu<T> {
+    pub fn solve(&self, b: &Matrix<T>) -> Matrix<T> {
+        let n = self.lu.rows;
+        assert_eq!(b.rows, n, "right-hand side has {} rows, expected {}", b.rows, n);
+
+        let mut x = Matrix::zeros(n, b.cols);
+        for (row, &source) in self.permutation.iter().enumerate() {
+            x.row_mut(row).copy_from_slice(b.row(source));
+        }
+        // Forward substitution with the unit lower triangle
+        for row in 0..n {
+            for k in 0..row {
+                let factor = self.lu[(row, k)];
+                for col in 0..b.cols {
+                    let value = x[(k, col)];
+                    x[(row, col)] -= factor * value;
+                }
+            }
+        }
+        // Back substitution with the upper triangle
+        for row in (0..n).rev() {
+            for k in row + 1..n {
+                let factor = self.lu[(row, k)];
+                for col in 0..b.cols {
+                    let value = x[(k, col)];
+                    x[(row, col)] -= factor * value;
+                }
+            }
+            let diagonal = self.lu[(row, row)];
+            for value in x.row_mut(row) {
+                *value = *value / diagonal;
+            }
+        }
+        x
+    }
+
+    pub fn determinant(&self) -> T {
+        let product = (0..self.lu.rows).fold(T::ONE, |product, i| product * self.lu[(i, i)]);
+        if self.odd_permutation { -product } else { product }
+    }
+}
+
+/// x with L L^T x = b.
+pub fn cholesky_solve<T: Scalar>(lower: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    let n = lower.rows;
+    let mut x = b.clone();
+    for col in 0..b.cols {
+        // L y = b
+        for row in 0..n {
+            let dot: T = (0..row).map(|k| lower[(row, k)] * x[(k, col)]).sum();
+            x[(row, col)] = (x[(row, col)] - dot) / lower[(row, row)];
+        }
+        // L^T x = y
+        for row in (0..n).rev() {
+            let dot: T = (row + 1..n).map(|k| lower[(k, row)] * x[(k, col)]).sum();
+            x[(row, col)] = (x[(row, col)] - dot) / lower[(row, row)];
+        }
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: &Matrix<f64>, b: &Matrix<f64>) {
+        assert_eq!(a.shape(), b.shape());
+        for (x, y) in a.data.iter().zip(&b.data) {
+            assert!((x - y).abs() < 1e-9, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn lu_solve_by_hand() {
+        // 4x + 3y = 10, 6x + 3y = 12
+        let a = Matrix::new(2, 2, vec![4.0, 3.0, 6.0, 3.0]);
+        let lu = a.lu().unwrap();
+        assert_close(&lu.solve(&Matrix::new(2, 1, vec![10.0, 12.0])), &Matrix::new(2, 1, vec![1.0, 2.0]));
+        assert!((lu.determinant() + 6.0).abs() < 1e-12);
+
+        // 2x + y = 5, x + 3y = 10
+        let a = Matrix::new(2, 2, vec![2.0, 1.0, 1.0, 3.0]);
+        assert_close(&a.solve(&Matrix::new(2, 1, vec![5.0, 10.0])).unwrap(), &Matrix::new(2, 1, vec![1.0, 3.0]));
+
+        let singular = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+        assert!(singular.lu().is_none());
+    }
+
+    #[test]
+    fn inverse_by_hand() {
+        let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        assert_close(&a.inverse().unwrap(), &Matrix::new(2, 2, vec![-2.0, 1.0, 1.5, -0.5]));
+    }
+
+    #[test]
+    fn lu_rejects_non_finite_matrices() {
+        assert!(Matrix::new(2, 2, vec![1.0, f64::NAN, 3.0, 4.0]).lu().is_none());
+        assert!(Matrix::new(2, 2, vec![f64::INFINITY, 2.0, 3.0, 4.0]).inverse().is_none());
+        // Finite entries whose elimination overflows
+        assert!(Matrix::new(2, 2, vec![1.0, -1e308, 0.5, 1e308]).lu().is_none());
+    }
+
+    #[test]
+    fn cholesky_by_hand() {
+        let a = Matrix::new(2, 2, vec![4.0, 2.0, 2.0, 3.0]);
+        let lower = a.cholesky().unwrap();
+        assert_close(&lower, &Matrix::new(2, 2, vec![2.0, 0.0, 1.0, 2f64.sqrt()]));
+        // 4x + 2y = 8, 2x + 3y = 8
+        assert_close(&cholesky_solve(&lower, &Matrix::new(2, 1, vec![8.0, 8.0])), &Matrix::new(2, 1, vec![1.0, 2.0]));
+
+        assert!(Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 1.0]).cholesky().is_none());
+    }
+
+    #[test]
+    fn least_squares_line() {
+        // Best line through (0, 1), (1, 2), (2, 2): y = 7/6 + x / 2
+        let x = Matrix::new(3, 2, vec![1.0, 0.0, 1.0, 1.0, 1.0, 2.0]);
+        let y = Matrix::new(3, 1, vec![1.0, 2.0, 2.0]);
+        assert_close(&x.least_squares(&y).unwrap(), &Matrix::new(2, 1, vec![7.0 / 6.0, 0.5]));
+
+        let mut broken = x.clone();
+        broken[(1, 1)] = f64::NAN;
+        assert!(broken.least_squares(&y).is_none());
+        assert!(x.least_squares(&Matrix::new(3, 1, vec![1.0, f64::INFINITY, 2.0])).is_none());
+    }
+
+    #[test]
+    fn pinv_by_hand() {
+        let tall = Matrix::new(3, 2, vec![1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+        assert_close(&tall.pinv(), &Matrix::new(2, 3, vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0]));
+        assert_close(&tall.transpose().pinv(), &tall);
+
+        // Square invertible: pinv is the inverse
+        let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        assert_close(&a.pinv(), &a.inverse().unwrap());
+
+        // A A+ A = A
+        let a = Matrix::new(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 7.0]);
+        assert_close(&a.matmul(&a.pinv()).matmul(&a), &a);
+    }
+
+    #[test]
+    fn svd_rebuilds_the_matrix() {
+        let a = Matrix::new(3, 2, vec![3.0, 1.0, 1.0, 3.0, 1.0, 1.0]);
+        for a in [a.clone(), a.transpose()] {
+            let svd = a.svd();
+            let mut sigma = Matrix::zeros(2, 2);
+            for (i, &value) in svd.singular_values.iter().enumerate() {
+                sigma[(i, i)] = value;
+            }
+            assert_close(&svd.u.matmul(&sigma).matmul(&svd.v.transpose()), &a);
+        }
+    }
+
+    #[test]
+    fn pinv_of_singular_matrices() {
+        // Rank one: pinv of x y^T is y x^T / (|x|^2 |y|^2)
+        let a = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+        assert_close(&a.pinv(), &Matrix::new(2, 2, vec![0.04, 0.08, 0.08, 0.16]));
+
+        // Duplicated column: the weight is split between the copies, the minimum norm solution
+        let a = Matrix::new(3, 2, vec![1.0, 1.0, 2.0, 2.0, 3.0, 3.0]);
+        let x = a.pinv().matmul(&Matrix::new(3, 1, vec![2.0, 4.0, 6.0]));
+        assert_close(&x, &Matrix::new(2, 1, vec![1.0, 1.0]));
+
+        assert_close(&Matrix::<f64>::zeros(2, 3).pinv(), &Matrix::zeros(3, 2));
+    }
+}
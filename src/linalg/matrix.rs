@@ -0,0 +1,231 @@
+use std::ops::{Add, Div, Index, IndexMut, Mul, Sub};
+use super::scalar::Scalar;
+use super::vector::Vector;
+
+/// Dense row-major matrix.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Matrix<T: Scalar> {
+    pub rows: usize,
+    pub cols: usize,
+    pub data: Vec<T>,
+}
+
+impl<T: Scalar> Matrix<T> {
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Matrix<T> {
+        assert_eq!(data.len(), rows * cols, "matrix data does not match its shape");
+        Matrix { rows, cols, data }
+    }
+
+    pub fn zeros(rows: usize, cols: usize) -> Matrix<T> {
+        Matrix { rows, cols, data: vec![T::ZERO; rows * cols] }
+    }
+
+    pub fn identity(size: usize) -> Matrix<T> {
+        let mut identity = Matrix::zeros(size, size);
+        for i in 0..size {
+            identity[(i, i)] = T::ONE;
+        }
+        identity
+    }
+
+    /// One inner Vec per row, all of the same length.
+    pub fn from_rows(rows: &[Vec<T>]) -> Matrix<T> {
+        let cols = rows.first().map_or(0, |row| row.len());
+        assert!(rows.iter().all(|row| row.len() == cols), "rows of different lengths");
+        Matrix { rows: rows.len(), cols, data: rows.concat() }
+    }
+
+    pub fn to_rows(&self) -> Vec<Vec<T>> {
+        self.data.chunks(self.cols.max(1)).map(|row| row.to_vec()).collect()
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn column(&self, col: usize) -> Vector<T> {
+        Vector::from((0..self.rows).map(|row| self[(row, col)]).collect::<Vec<T>>())
+    }
+
+    pub fn transpose(&self) -> Matrix<T> {
+        let mut transposed = Matrix::zeros(self.cols, self.rows);
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                transposed[(col, row)] = self[(row, col)];
+            }
+        }
+        transposed
+    }
+
+    /// (n x m) * (m x p), i-k-j order so the inner loop walks both operands contiguously
+    pub fn matmul(&self, other: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(self.cols, other.rows, "matmul of {:?} by {:?}", self.shape(), other.shape());
+        let mut product = Matrix::zeros(self.rows, other.cols);
+        for i in 0..self.rows {
+            for (k, &a) in self.row(i).iter().enumerate() {
+                for (out, &b) in product.row_mut(i).iter_mut().zip(other.row(k)) {
+                    *out += a * b;
+                }
+            }
+        }
+        product
+    }
+
+    pub fn matvec(&self, vector: &Vector<T>) -> Vector<T> {
+        assert_eq!(self.cols, vector.len(), "matvec of {:?} by a vector of {}", self.shape(), vector.len());
+        Vector::from((0..self.rows)
+            .map(|row| self.row(row).iter().zip(&vector.data).map(|(&a, &b)| a * b).sum())
+            .collect::<Vec<T>>())
+    }
+
+    pub fn scale(&self, factor: T) -> Matrix<T> {
+        self.map(|x| x * factor)
+    }
+
+    pub fn map(&self, f: impl Fn(T) -> T) -> Matrix<T> {
+        Matrix { rows: self.rows, cols: self.cols, data: self.data.iter().map(|&x| f(x)).collect() }
+    }
+
+    /// Elementwise operation with numpy broadcasting: a dimension of 1 is repeated to match the other side.
+    pub fn broadcast(&self, other: &Matrix<T>, f: impl Fn(T, T) -> T) -> Matrix<T> {
+        let rows = broadcast_dim(self.rows, other.rows).unwrap_or_else(|| panic!("cannot broadcast {:?} with {:?}", self.shape(), other.shape()));
+        let cols = broadcast_dim(self.cols, other.cols).unwrap_or_else(|| panic!("cannot broadcast {:?} with {:?}", self.shape(), other.shape()));
+
+        let mut result = Matrix::zeros(rows, cols);
+        for row in 0..rows {
+            for col in 0..cols {
+                result[(row, col)] = f(self.broadcast_get(row, col), other.broadcast_get(row, col));
+            }
+        }
+        result
+    }
+
+    fn broadcast_get(&self, row: usize, col: usize) -> T {
+        self[(if self.rows == 1 { 0 } else { row }, if self.cols == 1 { 0 } else { col })]
+    }
+
+    pub fn argmax(&self) -> Option<(usize, usize)> {
+        super::vector::argmax(&self.data).map(|index| (index / self.cols, index % self.cols))
+    }
+}
+
+fn broadcast_dim(a: usize, b: usize) -> Option<usize> {
+    match (a, b) {
+        _ if a == b => Some(a),
+        (1, _) => Some(b),
+        (_, 1) => Some(a),
+        _ => None,
+    }
+}
+
+impl<T: Scalar> From<Vector<T>> for Matrix<T> {
+    // Column matrix
+    fn from(vector: Vector<T>) -> Self {
+        Matrix { rows: vector.len(), cols: 1, data: vector.data }
+    }
+}
+
+impl<T: Scalar> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T: Scalar> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+impl<T: Scalar> Add for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, other: &Matrix<T>) -> Matrix<T> {
+        self.broadcast(other, |a, b| a + b)
+    }
+}
+
+impl<T: Scalar> Sub for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, other: &Matrix<T>) -> Matrix<T> {
+        self.broadcast(other, |a, b| a - b)
+    }
+}
+
+// Elementwise (Hadamard) product, `matmul` is the matrix product
+impl<T: Scalar> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, other: &Matrix<T>) -> Matrix<T> {
+        self.broadcast(other, |a, b| a * b)
+    }
+}
+
+impl<T: Scalar> Div for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn div(self, other: &Matrix<T>) -> Matrix<T> {
+        self.broadcast(other, |a, b| a / b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matmul_by_hand() {
+        let a = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let b = Matrix::new(3, 2, vec![7.0, 8.0, 9.0, 10.0, 11.0, 12.0]);
+        assert_eq!(a.matmul(&b), Matrix::new(2, 2, vec![58.0, 64.0, 139.0, 154.0]));
+        assert_eq!(a.matvec(&Vector::from(vec![1.0, 0.0, -1.0])).data, vec![-2.0, -2.0]);
+        assert_eq!(Matrix::identity(2).matmul(&a), a);
+    }
+
+    #[test]
+    fn transpose_by_hand() {
+        let a = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(a.transpose(), Matrix::new(3, 2, vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]));
+        assert_eq!(a.transpose().transpose(), a);
+        assert_eq!(a.column(1).data, vec![2.0, 5.0]);
+    }
+
+    #[test]
+    fn elementwise_and_broadcasting() {
+        let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b = Matrix::new(2, 2, vec![2.0, 2.0, 1.0, 0.5]);
+        assert_eq!(&a * &b, Matrix::new(2, 2, vec![2.0, 4.0, 3.0, 2.0]));
+        assert_eq!(&a / &b, Matrix::new(2, 2, vec![0.5, 1.0, 3.0, 8.0]));
+
+        // Row vector added to every row, column vector to every column
+        let row = Matrix::new(1, 2, vec![10.0, 20.0]);
+        let col = Matrix::new(2, 1, vec![-1.0, 1.0]);
+        assert_eq!(&a + &row, Matrix::new(2, 2, vec![11.0, 22.0, 13.0, 24.0]));
+        assert_eq!(&a - &col, Matrix::new(2, 2, vec![2.0, 3.0, 2.0, 3.0]));
+        // (2 x 1) with (1 x 2) gives the outer sum
+        assert_eq!(&col + &row, Matrix::new(2, 2, vec![9.0, 19.0, 11.0, 21.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot broadcast")]
+    fn incompatible_broadcast() {
+        let _ = &Matrix::<f64>::zeros(2, 3) + &Matrix::zeros(3, 2);
+    }
+
+    #[test]
+    fn matrix_argmax() {
+        let a = Matrix::new(2, 3, vec![1.0f32, 2.0, 3.0, 9.0, 5.0, 6.0]);
+        assert_eq!(a.argmax(), Some((1, 0)));
+    }
+}
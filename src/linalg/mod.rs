@@ -0,0 +1,4 @@
+pub mod scalar;
+pub mod vector;
+pub mod matrix;
+pub mod decomposition;
@@ -0,0 +1,138 @@
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+use super::scalar::Scalar;
+
+/// Dense column vector.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Vector<T: Scalar> {
+    pub data: Vec<T>,
+}
+
+/// Index of the largest value, NaN are skipped and the first index wins ties.
+/// None when there is no comparable value.
+pub fn argmax<T: Scalar>(values: &[T]) -> Option<usize> {
+    let mut best: Option<(usize, T)> = None;
+    for (index, &value) in values.iter().enumerate() {
+        if value.is_nan() {
+            continue;
+        }
+        if best.is_none_or(|(_, max)| value > max) {
+            best = Some((index, value));
+        }
+    }
+    best.map(|(index, _)| index)
+}
+
+impl<T: Scalar> Vector<T> {
+    pub fn zeros(len: usize) -> Vector<T> {
+        Vector { data: vec![T::ZERO; len] }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn dot(&self, other: &Vector<T>) -> T {
+        assert_eq!(self.len(), other.len(), "dot product of vectors of different sizes");
+        self.data.iter().zip(&other.data).map(|(&a, &b)| a * b).sum()
+    }
+
+    pub fn norm(&self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    pub fn scale(&self, factor: T) -> Vector<T> {
+        self.map(|x| x * factor)
+    }
+
+    pub fn map(&self, f: impl Fn(T) -> T) -> Vector<T> {
+        Vector { data: self.data.iter().map(|&x| f(x)).collect() }
+    }
+
+    pub fn zip_map(&self, other: &Vector<T>, f: impl Fn(T, T) -> T) -> Vector<T> {
+        assert_eq!(self.len(), other.len(), "elementwise operation on vectors of different sizes");
+        Vector { data: self.data.iter().zip(&other.data).map(|(&a, &b)| f(a, b)).collect() }
+    }
+
+    pub fn argmax(&self) -> Option<usize> {
+        argmax(&self.data)
+    }
+}
+
+impl<T: Scalar> From<Vec<T>> for Vector<T> {
+    fn from(data: Vec<T>) -> Self {
+        Vector { data }
+    }
+}
+
+impl<T: Scalar> Index<usize> for Vector<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.data[index]
+    }
+}
+
+impl<T: Scalar> IndexMut<usize> for Vector<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.data[index]
+    }
+}
+
+impl<T: Scalar> Add for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn add(self, other: &Vector<T>) -> Vector<T> {
+        self.zip_map(other, |a, b| a + b)
+    }
+}
+
+impl<T: Scalar> Sub for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, other: &Vector<T>) -> Vector<T> {
+        self.zip_map(other, |a, b| a - b)
+    }
+}
+
+// Elementwise (Hadamard) product
+impl<T: Scalar> Mul for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, other: &Vector<T>) -> Vector<T> {
+        self.zip_map(other, |a, b| a * b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vector_ops() {
+        let a = Vector::from(vec![1.0, 2.0, 2.0]);
+        let b = Vector::from(vec![3.0, -1.0, 0.5]);
+        assert_eq!(a.dot(&b), 2.0);
+        assert_eq!(a.norm(), 3.0);
+        assert_eq!((&a + &b).data, vec![4.0, 1.0, 2.5]);
+        assert_eq!((&a - &b).data, vec![-2.0, 3.0, 1.5]);
+        assert_eq!((&a * &b).data, vec![3.0, -2.0, 1.0]);
+        assert_eq!(a.scale(2.0).data, vec![2.0, 4.0, 4.0]);
+    }
+
+    #[test]
+    fn argmax_rules() {
+        assert_eq!(argmax(&[1.0f32, 4.0, 3.0, 4.0]), Some(1));
+        assert_eq!(argmax(&[f32::NAN, -2.0, -1.0]), Some(2));
+        assert_eq!(argmax::<f64>(&[]), None);
+        assert_eq!(argmax(&[f64::NAN]), None);
+        assert_eq!(Vector::from(vec![0.1, 0.7, 0.2]).argmax(), Some(1));
+    }
+}
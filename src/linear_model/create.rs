@@ -0,0 +1,31 @@
+use super::lm_struct::{LinearModel, LinearModelKind};
+use crate::basic_function::random::SeededRng;
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
+use crate::neural_network::initializer::WeightInit;
+
+#[no_mangle]
+pub(crate) extern "C" fn init_linear_model(kind: i32, input_size: i32, output_size: i32, seed: u64, out: *mut Handle) -> Status {
+    ffi_call(|| {
+        let model = LinearModel::new(LinearModelKind::try_from(kind)?, args::positive(input_size, "input_size")?, args::positive(output_size, "output_size")?, seed);
+        handle::write(out, model)
+    })
+}
+
+impl LinearModel {
+    pub fn new(kind: LinearModelKind, input_size: usize, output_size: usize, seed: u64) -> LinearModel {
+        // Same seeded uniform [-1, 1] draw as the PMC legacy scheme
+        let mut rng = SeededRng::new(seed);
+        LinearModel {
+            kind,
+            input_size,
+            output_size,
+            weights: (0..input_size * output_size)
+                .map(|_| WeightInit::Uniform.sample(&mut rng, input_size, output_size))
+                .collect(),
+            biases: vec![0.0; output_size],
+            rng,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use super::lm_struct::LinearModel;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
+
+#[no_mangle]
+extern "C" fn delete_linear_model(model: Handle) -> Status {
+    ffi_call(|| {
+        handle::remove::<LinearModel>(model)?;
+        println!("Model deleted");
+        Ok(())
+    })
+}
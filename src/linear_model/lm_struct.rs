@@ -0,0 +1,51 @@
+use bincode::{Decode, Encode};
+use crate::basic_function::random::SeededRng;
+use crate::ffi::args::enum_code;
+
+#[repr(C)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Debug)]
+pub enum LinearModelKind {
+    /// Rosenblatt rule, labels and predictions in {-1, 1}
+    Perceptron,
+    /// Least squares solved with the normal equation
+    LinearRegression,
+    /// Sigmoid trained by gradient descent, labels > 0 are the positive class, predictions are probabilities
+    LogisticRegression,
+}
+
+enum_code!(LinearModelKind { Perceptron, LinearRegression, LogisticRegression });
+
+#[derive(Encode, Decode, PartialEq, Debug, Clone)]
+pub struct LinearModel {
+    pub kind: LinearModelKind,
+    pub input_size: usize,
+    pub output_size: usize,
+    // Row-major: output_size rows of input_size weights
+    pub weights: Vec<f32>,
+    pub biases: Vec<f32>,
+    /// Picks the samples of the perceptron and logistic steps, seeded by `new`
+    pub rng: SeededRng,
+}
+
+impl LinearModel {
+    pub fn predict_into(&self, inputs: &[f32], outputs: &mut [f32]) {
+        for ((out, row), &bias) in outputs.iter_mut().zip(self.weights.chunks_exact(self.input_size)).zip(&self.biases) {
+            let total = bias + row.iter().zip(inputs).map(|(w, x)| w * x).sum::<f32>();
+            *out = match self.kind {
+                LinearModelKind::Perceptron => if total >= 0.0 { 1.0 } else { -1.0 },
+                LinearModelKind::LinearRegression => total,
+                LinearModelKind::LogisticRegression => sigmoid(total),
+            };
+        }
+    }
+
+    pub fn predict(&self, inputs: &[f32]) -> Vec<f32> {
+        let mut outputs = vec![0.0; self.output_size];
+        self.predict_into(inputs, &mut outputs);
+        outputs
+    }
+}
+
+pub(crate) fn sigmoid(x: f32) -> f32 {
+    1.0 / (1.0 + (-x).exp())
+}
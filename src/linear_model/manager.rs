@@ -0,0 +1,28 @@
+use std::ffi::c_char;
+use std::io;
+use super::lm_struct::LinearModel;
+use crate::basic_function::storage::{load_model_file, save_model_file, ModelKind};
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
+
+impl LinearModel {
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        save_model_file(self, ModelKind::LinearModel, path)
+    }
+
+    pub fn load(path: &str) -> io::Result<LinearModel> {
+        load_model_file(ModelKind::LinearModel, path)
+    }
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn save_linear_model(model: Handle, path: *const c_char) -> Status {
+    ffi_call(|| handle::with(model, |model: &LinearModel| Ok(model.save(&args::path(path)?)?)))
+}
+
+// Status::Io with the reason when the file cannot be read
+#[no_mangle]
+pub(crate) extern "C" fn load_linear_model(path: *const c_char, out: *mut Handle) -> Status {
+    ffi_call(|| handle::write(out, LinearModel::load(&args::path(path)?)?))
+}
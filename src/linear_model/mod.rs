@@ -0,0 +1,7 @@
+pub mod create;
+pub mod lm_struct;
+pub mod delete;
+pub mod train;
+pub mod predict;
+pub mod manager;
+mod tester;
@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+    use std::ffi::{CStr, CString};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use crate::ffi::error::{rustml_last_error, Status};
+    use crate::ffi::test_support::{created, output, taken};
+    use crate::linear_model::create::init_linear_model;
+    use crate::linear_model::lm_struct::{LinearModel, LinearModelKind};
+    use crate::linear_model::manager::{load_linear_model, save_linear_model};
+    use crate::linear_model::predict::predict_linear_model;
+    use crate::linear_model::train::train_linear_model;
+
+    fn last_error() -> String {
+        unsafe { CStr::from_ptr(rustml_last_error()) }.to_string_lossy().into_owned()
+    }
+
+    // AND gate with -1 / 1 labels, linearly separable
+    fn and_dataset() -> (Vec<Vec<f32>>, Vec<Vec<f32>>) {
+        let inputs = vec![vec![0.0, 0.0], vec![0.0, 1.0], vec![1.0, 0.0], vec![1.0, 1.0]];
+        let outputs = vec![vec![-1.0], vec![-1.0], vec![-1.0], vec![1.0]];
+        (inputs, outputs)
+    }
+
+    #[test]
+    fn init_linear() {
+        let model = taken::<LinearModel>(created(|out| init_linear_model(LinearModelKind::Perceptron as i32, 3, 2, 1, out)));
+        assert_eq!(model.weights.len(), 6);
+        assert_eq!(model.biases, vec![0.0, 0.0]);
+        assert_eq!(model, taken(created(|out| init_linear_model(LinearModelKind::Perceptron as i32, 3, 2, 1, out))));
+
+        let mut invalid = 0;
+        assert_eq!(init_linear_model(LinearModelKind::Perceptron as i32, 0, 2, 1, &mut invalid), Status::InvalidArgument);
+        assert_eq!(invalid, 0);
+    }
+
+    #[test]
+    fn perceptron_separates_and() {
+        let (inputs, outputs) = and_dataset();
+        let mut model = LinearModel::new(LinearModelKind::Perceptron, 2, 1, 2);
+        model.fit(&inputs, &outputs, 0.1, 2000, &mut StdRng::seed_from_u64(0)).unwrap();
+
+        for (input, output) in inputs.iter().zip(&outputs) {
+            assert_eq!(model.predict(input), *output);
+        }
+    }
+
+    #[test]
+    fn logistic_separates_and() {
+        let (inputs, outputs) = and_dataset();
+        let mut model = LinearModel::new(LinearModelKind::LogisticRegression, 2, 1, 3);
+        model.fit(&inputs, &outputs, 0.5, 20000, &mut StdRng::seed_from_u64(0)).unwrap();
+
+        for (input, output) in inputs.iter().zip(&outputs) {
+            let probability = model.predict(input)[0];
+            assert_eq!(probability > 0.5, output[0] > 0.0);
+        }
+    }
+
+    #[test]
+    fn linear_regression_is_exact() {
+        // y0 = 2 x0 - 3 x1 + 1, y1 = x0 + x1
+        let inputs: Vec<f32> = vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 2.0, 3.0, -1.0, 4.0];
+        let outputs: Vec<f32> = inputs.chunks(2)
+            .flat_map(|x| [2.0 * x[0] - 3.0 * x[1] + 1.0, x[0] + x[1]])
+            .collect();
+        let model = created(|out| init_linear_model(LinearModelKind::LinearRegression as i32, 2, 2, 4, out));
+        let status = train_linear_model(model, inputs.as_ptr(), inputs.len() as i32, 2, outputs.as_ptr(), outputs.len() as i32, 2, 0.0, 0);
+        assert_eq!(status, Status::Ok);
+
+        let sample = [5.0f32, -2.0f32];
+        let res = output(|out| predict_linear_model(model, sample.as_ptr(), 2, out));
+        let prediction = unsafe { res.into_vec() };
+        assert!((prediction[0] - 17.0).abs() < 1e-3);
+        assert!((prediction[1] - 3.0).abs() < 1e-3);
+
+        let model = taken::<LinearModel>(model);
+        assert!((model.weights[0] - 2.0).abs() < 1e-4);
+        assert!((model.weights[1] + 3.0).abs() < 1e-4);
+        assert!((model.biases[0] - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn non_finite_samples_are_rejected() {
+        let model = created(|out| init_linear_model(LinearModelKind::LinearRegression as i32, 2, 1, 4, out));
+        let inputs = [0.0f32, 0.0, 1.0, f32::NAN, 0.0, 1.0];
+        let outputs = [1.0f32, 2.0, 3.0];
+        let status = train_linear_model(model, inputs.as_ptr(), 6, 2, outputs.as_ptr(), 3, 1, 0.0, 0);
+        assert_eq!(status, Status::InvalidArgument);
+        assert_eq!(last_error(), "inputs row 1 holds NaN");
+
+        let outputs = [1.0f32, f32::INFINITY, 3.0];
+        let inputs = [0.0f32, 0.0, 1.0, 0.0, 0.0, 1.0];
+        let status = train_linear_model(model, inputs.as_ptr(), 6, 2, outputs.as_ptr(), 3, 1, 0.0, 0);
+        assert_eq!(status, Status::InvalidArgument);
+        assert_eq!(last_error(), "outputs row 1 holds inf");
+        taken::<LinearModel>(model);
+    }
+
+    #[test]
+    fn same_seed_trains_the_same_model() {
+        let (inputs, outputs) = and_dataset();
+        let (inputs, outputs) = (inputs.concat(), outputs.concat());
+        let trained = |seed| {
+            let model = created(|out| init_linear_model(LinearModelKind::Perceptron as i32, 2, 1, seed, out));
+            assert_eq!(train_linear_model(model, inputs.as_ptr(), 8, 2, outputs.as_ptr(), 4, 1, 0.1, 50), Status::Ok);
+            taken::<LinearModel>(model)
+        };
+        assert_eq!(trained(3), trained(3));
+        assert_ne!(trained(3).weights, trained(4).weights);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let model = created(|out| init_linear_model(LinearModelKind::LogisticRegression as i32, 4, 1, 5, out));
+        let path = std::env::temp_dir().join("rustml_linear_model_test.bin");
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+
+        assert_eq!(save_linear_model(model, c_path.as_ptr()), Status::Ok);
+        let loaded = created(|out| load_linear_model(c_path.as_ptr(), out));
+        // The generator is not saved, see SeededRng
+        let (mut loaded, model) = (taken::<LinearModel>(loaded), taken::<LinearModel>(model));
+        assert_ne!(loaded.rng, model.rng);
+        loaded.rng = model.rng.clone();
+        assert_eq!(loaded, model);
+        std::fs::remove_file(path).unwrap();
+
+        let missing = CString::new("/nonexistent/rustml_model.bin").unwrap();
+        let mut loaded = 0;
+        assert_eq!(load_linear_model(missing.as_ptr(), &mut loaded), Status::Io);
+        assert_eq!(loaded, 0);
+    }
+}
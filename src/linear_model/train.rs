@@ -0,0 +1,86 @@
+use rand::Rng;
+use super::lm_struct::{sigmoid, LinearModel, LinearModelKind};
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, FfiError, FfiResult, Status};
+use crate::ffi::handle::{self, Handle};
+use crate::linalg::matrix::Matrix;
+
+#[no_mangle]
+pub(crate) extern "C" fn train_linear_model(model: Handle,
+                                inputs_ptr: *const f32, input_length: i32, inputs_sub: i32,
+                                output_ptr: *const f32, output_length: i32, output_sub: i32,
+                                learning_rate: f32, iteration: i32) -> Status {
+    ffi_call(|| handle::with_mut(model, |model: &mut LinearModel| {
+        // Recompose Vec<Vec<f32>>
+        let inputs = args::rows(inputs_ptr, input_length, inputs_sub, "inputs")?;
+        let outputs = args::rows(output_ptr, output_length, output_sub, "outputs")?;
+        args::check_samples(&inputs, &outputs, model.input_size, model.output_size)?;
+        if inputs.is_empty() {
+            return Err(FfiError::invalid("no training samples"));
+        }
+        args::finite(&inputs, "inputs")?;
+        args::finite(&outputs, "outputs")?;
+
+        // Drawn from the model generator, so the same seed trains the same model
+        let mut rng = model.rng.clone();
+        model.fit(&inputs, &outputs, learning_rate, args::length(iteration, "iteration")?, &mut rng)?;
+        model.rng = rng;
+        Ok(())
+    }))
+}
+
+impl LinearModel {
+    /// Linear regression ignores `learning_rate` and `iterations`, the solution is exact.
+    pub fn fit<R: Rng>(&mut self, inputs: &[Vec<f32>], outputs: &[Vec<f32>], learning_rate: f32, iterations: usize, rng: &mut R) -> FfiResult<()> {
+        match self.kind {
+            LinearModelKind::LinearRegression => self.solve_least_squares(inputs, outputs)?,
+            LinearModelKind::Perceptron | LinearModelKind::LogisticRegression => {
+                for _ in 0..iterations {
+                    // pick random data in the dataset
+                    let rand = rng.random_range(0..inputs.len());
+                    self.gradient_step(&inputs[rand], &outputs[rand], learning_rate);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Rosenblatt: w += lr * (y - sign(wx)) * x, logistic: w -= lr * (sigmoid(wx) - y) * x
+    fn gradient_step(&mut self, input: &[f32], output: &[f32], learning_rate: f32) {
+        for (j, row) in self.weights.chunks_exact_mut(self.input_size).enumerate() {
+            let total = self.biases[j] + row.iter().zip(input).map(|(w, x)| w * x).sum::<f32>();
+            let error = match self.kind {
+                LinearModelKind::Perceptron => {
+                    let prediction = if total >= 0.0 { 1.0 } else { -1.0 };
+                    prediction - output[j]
+                }
+                _ => {
+                    let label = if output[j] > 0.0 { 1.0 } else { 0.0 };
+                    sigmoid(total) - label
+                }
+            };
+            for (weight, &x) in row.iter_mut().zip(input) {
+                *weight -= learning_rate * error * x;
+            }
+            self.biases[j] -= learning_rate * error;
+        }
+    }
+
+    // X with a leading column of ones for the bias
+    fn solve_least_squares(&mut self, inputs: &[Vec<f32>], outputs: &[Vec<f32>]) -> FfiResult<()> {
+        let rows: Vec<Vec<f64>> = inputs.iter()
+            .map(|input| std::iter::once(1.0).chain(input.iter().map(|&x| x as f64)).collect())
+            .collect();
+        let targets: Vec<Vec<f64>> = outputs.iter().map(|output| output.iter().map(|&y| y as f64).collect()).collect();
+        let solution = Matrix::from_rows(&rows).least_squares(&Matrix::from_rows(&targets))
+            .ok_or_else(|| FfiError::invalid("the normal equation has no solution"))?;
+
+        for (o, (bias, row)) in self.biases.iter_mut().zip(self.weights.chunks_exact_mut(self.input_size)).enumerate() {
+            *bias = solution[(0, o)] as f32;
+            for (i, weight) in row.iter_mut().enumerate() {
+                *weight = solution[(i + 1, o)] as f32;
+            }
+        }
+        Ok(())
+    }
+}
@@ -42,7 +42,7 @@ fn main() {
      */
 
     /*      Line world
-    let mut env = line_world::LineWorld::new(4, false, 1);
+    let mut env = line_world::LineWorld::new(4, false, 1, 0);
     //tools::print_matrix(&env.all_position, &env.all_actions, &env.probabilities);
     //tools::print_matrix(&env.all_position, &env.all_actions, &env.rewards);
     let _ = env.display();
@@ -50,19 +50,19 @@ fn main() {
 
 
     //     Grid world
-    //let mut env = grid_world::GridWorld::new(3, 5, 1);
+    //let mut env = grid_world::GridWorld::new(3, 5, 1, 0);
     //tools::print_matrix(&env.all_position, &env.all_actions, &env.probabilities);
     //tools::print_matrix(&env.all_position, &env.all_actions, &env.rewards);
     // let _ = env.display();
     //
 
     //      PLAYABLE MONTY HALL
-    //let mut env = playable_monte_hall::playable_MontyHall::new(3);
+    //let mut env = playable_monte_hall::playable_MontyHall::new(3, 0);
     //
 
 
     // two_round_rock_paper_scissors
-    //let mut env = two_round_rock_paper_scissors::RPSGame::new();
+    //let mut env = two_round_rock_paper_scissors::RPSGame::new(0);
 
 
 // -------------------------------- ALGO -------------------------------------
@@ -86,7 +86,7 @@ fn main() {
     //model.save_policy("policy_POLICY_ITERATION.json").unwrap();
 
     //model.load_policy("policy_POLICY_ITERATION.json").unwrap();
-    //model.print_policy();
+    //println!("Policy: {:?}", model.policy);
     use_policy_in_game(&mut *env, Policy::Array(best_policy.clone()));
     //
 
@@ -107,14 +107,14 @@ fn main() {
     println!("Model trained for : {:?}", duration);
     //model.save_policy("policy_VALUE_ITERATION.json").unwrap();
     //model.load_policy("policy_VALUE_ITERATION.json").unwrap();
-    //model.print_policy();
+    //println!("Policy: {:?}", model.policy);
     use_policy_in_game(&mut *env, Policy::Array(model.policy.clone()));
 
      */
 
 
     /*    MONTE CARLO ES
-    let mut model = MonteCarloESModel::new(1000, 0.9, 20);
+    let mut model = MonteCarloESModel::new(1000, 0.9, 0);
     // Entraînement du modèle avec Monte Carlo ES
     let start = Instant::now();
     model.monte_carlo_es(&mut *env);
@@ -140,7 +140,7 @@ fn main() {
 
 
     /*      MONTE CARLO CONTROL
-    let mut model = MonteCarloControl::new(0.1, 0.9);
+    let mut model = MonteCarloControl::new(0.1, 0.9, 0);
     // Entraînement du modèle avec Monte Carlo Control
     let start = Instant::now();
     model.on_policy_mc_control(&mut *env, 10000, 100);
@@ -165,7 +165,7 @@ fn main() {
 
 
     /*      MONTE CARLO CONTROL OFF POLICY
-    let mut model = monte_carlo_control_struct_off::MonteCarloControlOff::new(0.1, 0.9);
+    let mut model = monte_carlo_control_struct_off::MonteCarloControlOff::new(0.1, 0.9, 0);
     // Entraînement du modèle avec Monte Carlo Control hors politique
     let start = Instant::now();
     model.off_policy_mc_control(&mut *env, 10000, 100);
@@ -181,7 +181,7 @@ fn main() {
     //println!("Policy  : {:?}", model.derived_policy);*/
 
     /* SARSA
-    let mut model = SarsaModel::new(&mut *env, 0.1, 0.9, 0.9, 100);
+    let mut model = SarsaModel::new(&mut *env, 0.1, 0.9, 0.9, 100, 0);
     //tools::print_matrix(&env.all_position, &env.all_actions, &model.q_table)
     let start = Instant::now();
     let best_policy = model.process_episode(&mut *env);
@@ -201,18 +201,18 @@ fn main() {
     let alpha = 0.7;
     let epsilon = 0.1;
 
-    let mut model = QLearning::new(iterations, gamma, alpha, epsilon);
+    let mut model = QLearning::new(iterations, gamma, alpha, epsilon, 0);
     let start = Instant::now();
     model.q_learning(&mut *env);
     println!("Q-values: {:?}", model.q_values);
     let policy = model.derive_policy();
     let duration = start.elapsed();
-    model.print_policy();
+    println!("Policy: {:?}", model.policy);
     println!("Model trained for : {:?}", duration);
     use_policy_in_game(&mut *env, Policy::Map(model.policy.clone()));
      model.save_policy("policy_QLearning.json").unwrap();
 
-    //let mut model = QLearning::new(iterations, gamma, alpha, epsilon);
+    //let mut model = QLearning::new(iterations, gamma, alpha, epsilon, 0);
     //model.load_policy("policy_QLearning.json").unwrap();
     */
 
@@ -226,17 +226,17 @@ fn main() {
     let epsilon = 0.8;
     let n = 10;
 
-    let mut model = DynaQModel::new(iterations, gamma, alpha, epsilon, n);
+    let mut model = DynaQModel::new(iterations, gamma, alpha, epsilon, n, 0);
     let start = Instant::now();
     model.dyna_q(&mut *env);
     println!("Q-values: {:?}", model.q_values);
     model.derive_and_assign_policy();
     let duration = start.elapsed();
-    model.print_policy();
+    println!("Policy: {:?}", model.policy);
     println!("Model trained for : {:?}", duration);
     use_policy_in_game(&mut *env, Policy::Map(model.policy.clone()));
     model.save_policy( "policy_DYNQ.json").unwrap();
-    //let mut model = DynaQModel::new(iterations, gamma, alpha, epsilon, n);
+    //let mut model = DynaQModel::new(iterations, gamma, alpha, epsilon, n, 0);
     //model.load_policy("policy.json").unwrap();*/
 
 
@@ -249,17 +249,17 @@ fn main() {
     let planning_steps = 10;
     let kappa = 0.001;
 
-    let mut model = DynaQPlusModel::new(iterations, gamma, alpha, epsilon, planning_steps, kappa);
+    let mut model = DynaQPlusModel::new(iterations, gamma, alpha, epsilon, planning_steps, kappa, 0);
     let start = Instant::now();
     model.dyna_q_plus(&mut *env);
     println!("Q-values: {:?}", model.q_values);
     model.derive_and_assign_policy();
     let duration = start.elapsed();
-    model.print_policy();
+    println!("Policy: {:?}", model.policy);
     println!("Model trained for : {:?}", duration);
     use_policy_in_game(&mut *env, Policy::Map(model.policy.clone()));
     model.save_policy("policy_DYNQ_PLUS.json").unwrap();
-    //let mut model = DynaQPlusModel::new(iterations, gamma, alpha, epsilon, planning_steps, kappa);
+    //let mut model = DynaQPlusModel::new(iterations, gamma, alpha, epsilon, planning_steps, kappa, 0);
     //model.load_policy("policy_DYNQ_PLUS.json").unwrap();*/
 
     // env / algo / train - play / save
@@ -283,11 +283,11 @@ fn main() {
             "secretenv1" => unsafe { SecretEnv1Dp::new() },
             "secretenv2" => unsafe { SecretEnv2Dp::new() },
             "secretenv3" => unsafe { SecretEnv3Dp::new() },
-            "lineworld" => line_world::LineWorld::new(4, false, 1),
-            "gridworld" => grid_world::GridWorld::new(3, 5, 1),
-            "montyhall" => playable_monte_hall::playable_MontyHall::new(3),
-            "rps" => two_round_rock_paper_scissors::RPSGame::new(),
-            "MontyHall" => monteHall::MonteHall::new(3),
+            "lineworld" => line_world::LineWorld::new(4, false, 1, 0),
+            "gridworld" => grid_world::GridWorld::new(3, 5, 1, 0),
+            "montyhall" => playable_monte_hall::playable_MontyHall::new(3, 0),
+            "rps" => two_round_rock_paper_scissors::RPSGame::new(0),
+            "MontyHall" => monteHall::MonteHall::new(3, 0),
             _ => panic!("Unknown environment: {}", environment),
         };
         println!("Env : {}, action : {:?}, state : {:}", environment, env.all_action(), env.all_states().len());
@@ -319,25 +319,25 @@ fn main() {
                 )
             },*/
             "monte_carlo_es" => { Algo::MonteCarloES(
-                MonteCarloESModel::new(1000, 0.3, 8000)
+                MonteCarloESModel::new(1000, 0.3, 0)
             )},
             "monte_carlo_control_on" => { Algo::MonteCarloControlOn(
-                MonteCarloControl::new(0.1, 0.9)
+                MonteCarloControl::new(0.1, 0.9, 0)
             )},
             "monte_carlo_control_off" => { Algo::MonteCarloControlOff(
-                MonteCarloControlOff::new(0.1, 0.9)
+                MonteCarloControlOff::new(0.1, 0.9, 0)
             )},
             "sarsa" => { Algo::Sarsa(
-                SarsaModel::new(&mut *env, 0.1, 0.9, 0.9, 10)
+                SarsaModel::new(&mut *env, 0.1, 0.9, 0.9, 10, 0)
             )},
             "q_learning" => { Algo::QLearning(
-                QLearning::new(100,  0.6, 0.7, 0.7)
+                QLearning::new(100,  0.6, 0.7, 0.7, 0)
             )},
             "dyna_q" => { Algo::DynaQ(
-                DynaQModel::new(10000,  0.95,  0.5,  0.8, 10)
+                DynaQModel::new(10000,  0.95,  0.5,  0.8, 10, 0)
             )},
             "dyna_q+" => { Algo::DynaQPlus(
-                DynaQPlusModel::new(10000, 0.95, 0.1, 0.1, 10, 0.001)
+                DynaQPlusModel::new(10000, 0.95, 0.1, 0.1, 10, 0.001, 0)
             )},
             _ => panic!("Unknown algorithm: {}", algorithm),
 
@@ -431,7 +431,7 @@ fn main() {
                     println!("Model trained for : {:?}", duration);
                 }
                 println!("Q-values: {:?}", ql.q_values);
-                ql.print_policy();
+                println!("Policy: {:?}", ql.policy);
 
                 if save { ql.save_policy("policy_QLearning.json").unwrap();
                     ql.save_q_values("Q_value_QLearning.bin").unwrap();}
@@ -449,7 +449,7 @@ fn main() {
                     println!("Model trained for : {:?}", duration);
                 }
                 println!("Q-values: {:?}", dq.q_values);
-                dq.print_policy();
+                println!("Policy: {:?}", dq.policy);
 
                 if save {
                     dq.save_policy( "policy_DYNQ.json").unwrap();
@@ -469,7 +469,7 @@ fn main() {
                     println!("Model trained for : {:?}", duration);
                 }
                 println!("Q-values: {:?}", dqp.q_values);
-                dqp.print_policy();
+                println!("Policy: {:?}", dqp.policy);
 
                 if save { dqp.save_policy("policy_DYNQ_PLUS.json").unwrap();
                     dqp.save_q_values("Qlearing_Q_value_DYNQ_plus_.bin").unwrap()
@@ -0,0 +1,202 @@
+use crate::ffi::args::{self, enum_code};
+use crate::ffi::error::{ffi_call, FfiResult, Status};
+use crate::preprocessing::split::class_ids;
+
+// Probabilities are clipped away from 0 and 1 so log-loss stays finite
+const LOG_EPSILON: f32 = 1e-7;
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Average {
+    /// Unweighted mean of the per-class scores
+    Macro,
+    /// Score of the pooled true / false positives of every class
+    Micro,
+}
+
+enum_code!(Average { Macro, Micro });
+
+/// counts[actual][predicted]
+pub fn confusion_matrix(predicted: &[usize], actual: &[usize], n_classes: usize) -> Vec<Vec<usize>> {
+    let mut counts = vec![vec![0; n_classes]; n_classes];
+    for (&p, &a) in predicted.iter().zip(actual) {
+        counts[a][p] += 1;
+    }
+    counts
+}
+
+pub fn accuracy(predicted: &[usize], actual: &[usize]) -> f32 {
+    let correct = predicted.iter().zip(actual).filter(|(p, a)| p == a).count();
+    correct as f32 / actual.len() as f32
+}
+
+// (true positives, false positives, false negatives) of every class
+fn class_counts(predicted: &[usize], actual: &[usize], n_classes: usize) -> Vec<(usize, usize, usize)> {
+    let counts = confusion_matrix(predicted, actual, n_classes);
+    (0..n_classes)
+        .map(|c| {
+            let true_positives = counts[c][c];
+            let predicted_total: usize = counts.iter().map(|row| row[c]).sum();
+            let actual_total: usize = counts[c].iter().sum();
+            (true_positives, predicted_total - true_positives, actual_total - true_positives)
+        })
+        .collect()
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f32 {
+    if denominator == 0 { 0.0 } else { numerator as f32 / denominator as f32 }
+}
+
+fn harmonic_mean(precision: f32, recall: f32) -> f32 {
+    if precision + recall == 0.0 { 0.0 } else { 2.0 * precision * recall / (precision + recall) }
+}
+
+/// Per-class (precision, recall, f1), 0 when a class is never predicted or never present.
+pub fn per_class_scores(predicted: &[usize], actual: &[usize], n_classes: usize) -> Vec<(f32, f32, f32)> {
+    class_counts(predicted, actual, n_classes).iter()
+        .map(|&(tp, fp, fn_)| {
+            let (precision, recall) = (ratio(tp, tp + fp), ratio(tp, tp + fn_));
+            (precision, recall, harmonic_mean(precision, recall))
+        })
+        .collect()
+}
+
+/// Averaged (precision, recall, f1).
+pub fn precision_recall_f1(predicted: &[usize], actual: &[usize], n_classes: usize, average: Average) -> (f32, f32, f32) {
+    match average {
+        Average::Macro => {
+            let scores = per_class_scores(predicted, actual, n_classes);
+            let mean = |f: fn(&(f32, f32, f32)) -> f32| scores.iter().map(f).sum::<f32>() / n_classes as f32;
+            (mean(|s| s.0), mean(|s| s.1), mean(|s| s.2))
+        }
+        Average::Micro => {
+            let (tp, fp, fn_) = class_counts(predicted, actual, n_classes).iter()
+                .fold((0, 0, 0), |acc, c| (acc.0 + c.0, acc.1 + c.1, acc.2 + c.2));
+            let (precision, recall) = (ratio(tp, tp + fp), ratio(tp, tp + fn_));
+            (precision, recall, harmonic_mean(precision, recall))
+        }
+    }
+}
+
+/// Cross-entropy of predicted probabilities: one column is P(positive) with labels > 0 positive,
+/// several columns are normalized per row and the label is the argmax class.
+pub fn log_loss(probabilities: &[Vec<f32>], labels: &[Vec<f32>]) -> f32 {
+    let classes = class_ids(labels);
+    let total: f32 = probabilities.iter().zip(&classes)
+        .map(|(row, &class)| {
+            let p = if row.len() == 1 {
+                if class == 1 { row[0] } else { 1.0 - row[0] }
+            } else {
+                row[class] / row.iter().sum::<f32>()
+            };
+            -p.clamp(LOG_EPSILON, 1.0 - LOG_EPSILON).ln()
+        })
+        .sum();
+    total / probabilities.len() as f32
+}
+
+/// Area under the ROC curve from the rank statistic, ties get their average rank.
+/// NaN when only one class is present.
+pub fn roc_auc(scores: &[f32], positives: &[bool]) -> f32 {
+    let mut order: Vec<usize> = (0..scores.len()).collect();
+    order.sort_by(|&a, &b| scores[a].total_cmp(&scores[b]));
+
+    let mut ranks = vec![0.0f32; scores.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start;
+        while end + 1 < order.len() && scores[order[end + 1]] == scores[order[start]] {
+            end += 1;
+        }
+        // Ranks are 1-based
+        let rank = (start + end) as f32 / 2.0 + 1.0;
+        for &index in &order[start..=end] {
+            ranks[index] = rank;
+        }
+        start = end + 1;
+    }
+
+    let positive_count = positives.iter().filter(|&&p| p).count() as f32;
+    let negative_count = positives.len() as f32 - positive_count;
+    let positive_ranks: f32 = ranks.iter().zip(positives).filter(|(_, &p)| p).map(|(r, _)| r).sum();
+    (positive_ranks - positive_count * (positive_count + 1.0) / 2.0) / (positive_count * negative_count)
+}
+
+// Class ids of flat prediction and label buffers, sign for one output and argmax otherwise
+pub(crate) fn flat_classes(predictions: *const f32, labels: *const f32, length: i32, sub: i32) -> FfiResult<(Vec<usize>, Vec<usize>, usize)> {
+    let predicted = class_ids(&args::rows(predictions, length, sub, "predictions")?);
+    let actual = class_ids(&args::rows(labels, length, sub, "labels")?);
+    let n_classes = args::positive(sub, "sub")?;
+    Ok((predicted, actual, if n_classes == 1 { 2 } else { n_classes }))
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn metrics_accuracy(predictions: *const f32, labels: *const f32, length: i32, sub: i32, score: *mut f32) -> Status {
+    ffi_call(|| {
+        let (predicted, actual, _) = flat_classes(predictions, labels, length, sub)?;
+        args::write(score, accuracy(&predicted, &actual), "score")
+    })
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn metrics_precision(predictions: *const f32, labels: *const f32, length: i32, sub: i32, average: i32, score: *mut f32) -> Status {
+    ffi_call(|| {
+        let average = Average::try_from(average)?;
+        let (predicted, actual, n_classes) = flat_classes(predictions, labels, length, sub)?;
+        args::write(score, precision_recall_f1(&predicted, &actual, n_classes, average).0, "score")
+    })
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn metrics_recall(predictions: *const f32, labels: *const f32, length: i32, sub: i32, average: i32, score: *mut f32) -> Status {
+    ffi_call(|| {
+        let average = Average::try_from(average)?;
+        let (predicted, actual, n_classes) = flat_classes(predictions, labels, length, sub)?;
+        args::write(score, precision_recall_f1(&predicted, &actual, n_classes, average).1, "score")
+    })
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn metrics_f1(predictions: *const f32, labels: *const f32, length: i32, sub: i32, average: i32, score: *mut f32) -> Status {
+    ffi_call(|| {
+        let average = Average::try_from(average)?;
+        let (predicted, actual, n_classes) = flat_classes(predictions, labels, length, sub)?;
+        args::write(score, precision_recall_f1(&predicted, &actual, n_classes, average).2, "score")
+    })
+}
+
+// Writes the n_classes x n_classes counts (row = actual, column = predicted) and n_classes in `classes`
+#[no_mangle]
+pub(crate) extern "C" fn metrics_confusion_matrix(predictions: *const f32, labels: *const f32, length: i32, sub: i32,
+                                                  output: *mut i32, output_len: i32, classes: *mut i32) -> Status {
+    ffi_call(|| {
+        let (predicted, actual, n_classes) = flat_classes(predictions, labels, length, sub)?;
+        let output_slice = args::slice_mut(output, output_len, "output")?;
+        args::expect_len(output_slice.len(), n_classes * n_classes, "output")?;
+        for (out, &count) in output_slice.iter_mut().zip(confusion_matrix(&predicted, &actual, n_classes).concat().iter()) {
+            *out = count as i32;
+        }
+        args::write(classes, n_classes as i32, "classes")
+    })
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn metrics_log_loss(probabilities: *const f32, labels: *const f32, length: i32, sub: i32, score: *mut f32) -> Status {
+    ffi_call(|| {
+        let probabilities = args::rows(probabilities, length, sub, "probabilities")?;
+        let labels = args::rows(labels, length, sub, "labels")?;
+        args::write(score, log_loss(&probabilities, &labels), "score")
+    })
+}
+
+// One score per sample, labels > 0 are positive
+#[no_mangle]
+pub(crate) extern "C" fn metrics_roc_auc(scores: *const f32, labels: *const f32, length: i32, score: *mut f32) -> Status {
+    ffi_call(|| {
+        let scores_slice = args::slice(scores, length, "scores")?;
+        let labels_slice = args::slice(labels, length, "labels")?;
+        let positives: Vec<bool> = labels_slice.iter().map(|&label| label > 0.0).collect();
+        args::write(score, roc_auc(scores_slice, &positives), "score")
+    })
+}
+
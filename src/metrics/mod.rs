@@ -0,0 +1,4 @@
+pub mod classification;
+pub mod regression;
+pub mod report;
+mod tester;
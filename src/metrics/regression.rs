@@ -0,0 +1,55 @@
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, Status};
+
+pub fn mean_squared_error(predictions: &[f32], labels: &[f32]) -> f32 {
+    predictions.iter().zip(labels).map(|(p, y)| (p - y).powi(2)).sum::<f32>() / labels.len() as f32
+}
+
+pub fn mean_absolute_error(predictions: &[f32], labels: &[f32]) -> f32 {
+    predictions.iter().zip(labels).map(|(p, y)| (p - y).abs()).sum::<f32>() / labels.len() as f32
+}
+
+/// Coefficient of determination, averaged over the outputs. 1 is a perfect fit, 0 predicts the mean.
+pub fn r2_score(predictions: &[Vec<f32>], labels: &[Vec<f32>]) -> f32 {
+    let outputs = labels.first().map_or(0, |row| row.len());
+    let total: f32 = (0..outputs)
+        .map(|o| {
+            let mean = labels.iter().map(|row| row[o]).sum::<f32>() / labels.len() as f32;
+            let residual: f32 = predictions.iter().zip(labels).map(|(p, y)| (y[o] - p[o]).powi(2)).sum();
+            let variance: f32 = labels.iter().map(|y| (y[o] - mean).powi(2)).sum();
+            if variance == 0.0 {
+                if residual == 0.0 { 1.0 } else { 0.0 }
+            } else {
+                1.0 - residual / variance
+            }
+        })
+        .sum();
+    total / outputs as f32
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn metrics_mse(predictions: *const f32, labels: *const f32, length: i32, score: *mut f32) -> Status {
+    ffi_call(|| {
+        let predictions_slice = args::slice(predictions, length, "predictions")?;
+        let labels_slice = args::slice(labels, length, "labels")?;
+        args::write(score, mean_squared_error(predictions_slice, labels_slice), "score")
+    })
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn metrics_mae(predictions: *const f32, labels: *const f32, length: i32, score: *mut f32) -> Status {
+    ffi_call(|| {
+        let predictions_slice = args::slice(predictions, length, "predictions")?;
+        let labels_slice = args::slice(labels, length, "labels")?;
+        args::write(score, mean_absolute_error(predictions_slice, labels_slice), "score")
+    })
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn metrics_r2(predictions: *const f32, labels: *const f32, length: i32, sub: i32, score: *mut f32) -> Status {
+    ffi_call(|| {
+        let predictions = args::rows(predictions, length, sub, "predictions")?;
+        let labels = args::rows(labels, length, sub, "labels")?;
+        args::write(score, r2_score(&predictions, &labels), "score")
+    })
+}
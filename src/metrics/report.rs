@@ -0,0 +1,68 @@
+use std::fmt::Write;
+use super::classification::{accuracy, confusion_matrix, flat_classes, per_class_scores, precision_recall_f1, Average};
+use super::regression::{mean_absolute_error, mean_squared_error, r2_score};
+use crate::ffi::args;
+use crate::ffi::buffer::ByteBuffer;
+use crate::ffi::error::{ffi_call, Status};
+
+/// Per-class precision / recall / f1 / support, accuracy, the averages and the confusion matrix.
+/// Missing class names default to the class id.
+pub fn classification_report(predicted: &[usize], actual: &[usize], n_classes: usize, class_names: &[String]) -> String {
+    let names: Vec<String> = (0..n_classes)
+        .map(|c| class_names.get(c).cloned().unwrap_or_else(|| c.to_string()))
+        .collect();
+    let width = names.iter().map(|name| name.len()).max().unwrap_or(0).max(12);
+    let counts = confusion_matrix(predicted, actual, n_classes);
+    let mut report = String::new();
+
+    writeln!(report, "{:>width$} {:>9} {:>9} {:>9} {:>9}", "", "precision", "recall", "f1-score", "support").unwrap();
+    writeln!(report).unwrap();
+    for (c, (precision, recall, f1)) in per_class_scores(predicted, actual, n_classes).iter().enumerate() {
+        let support: usize = counts[c].iter().sum();
+        writeln!(report, "{:>width$} {:>9.2} {:>9.2} {:>9.2} {:>9}", names[c], precision, recall, f1, support).unwrap();
+    }
+    writeln!(report).unwrap();
+    writeln!(report, "{:>width$} {:>9} {:>9} {:>9.2} {:>9}", "accuracy", "", "", accuracy(predicted, actual), actual.len()).unwrap();
+    for (label, average) in [("macro avg", Average::Macro), ("micro avg", Average::Micro)] {
+        let (precision, recall, f1) = precision_recall_f1(predicted, actual, n_classes, average);
+        writeln!(report, "{:>width$} {:>9.2} {:>9.2} {:>9.2} {:>9}", label, precision, recall, f1, actual.len()).unwrap();
+    }
+
+    writeln!(report).unwrap();
+    writeln!(report, "confusion matrix (rows actual, columns predicted)").unwrap();
+    for (name, row) in names.iter().zip(&counts) {
+        let cells: Vec<String> = row.iter().map(|count| format!("{:>6}", count)).collect();
+        writeln!(report, "{:>width$} {}", name, cells.join("")).unwrap();
+    }
+    report
+}
+
+pub fn regression_report(predictions: &[Vec<f32>], labels: &[Vec<f32>]) -> String {
+    let (flat_predictions, flat_labels) = (predictions.concat(), labels.concat());
+    let mut report = String::new();
+    writeln!(report, "{:>8} {:>12.6}", "mse", mean_squared_error(&flat_predictions, &flat_labels)).unwrap();
+    writeln!(report, "{:>8} {:>12.6}", "mae", mean_absolute_error(&flat_predictions, &flat_labels)).unwrap();
+    writeln!(report, "{:>8} {:>12.6}", "r2", r2_score(predictions, labels)).unwrap();
+    report
+}
+
+// Both reports are UTF-8 text without a trailing nul, released by the caller with free_byte_buffer
+#[no_mangle]
+pub(crate) extern "C" fn metrics_classification_report(predictions: *const f32, labels: *const f32, length: i32, sub: i32,
+                                                       out: *mut ByteBuffer) -> Status {
+    ffi_call(|| {
+        let (predicted, actual, n_classes) = flat_classes(predictions, labels, length, sub)?;
+        let report = classification_report(&predicted, &actual, n_classes, &[]);
+        args::write(out, ByteBuffer::from_vec(report.into_bytes()), "out")
+    })
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn metrics_regression_report(predictions: *const f32, labels: *const f32, length: i32, sub: i32,
+                                                   out: *mut ByteBuffer) -> Status {
+    ffi_call(|| {
+        let predictions = args::rows(predictions, length, sub, "predictions")?;
+        let labels = args::rows(labels, length, sub, "labels")?;
+        args::write(out, ByteBuffer::from_vec(regression_report(&predictions, &labels).into_bytes()), "out")
+    })
+}
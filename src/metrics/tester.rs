@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests {
+    use crate::ffi::buffer::{free_byte_buffer, ByteBuffer};
+    use crate::ffi::error::Status;
+    use crate::ffi::test_support::output;
+    use crate::metrics::classification::*;
+    use crate::metrics::regression::*;
+    use crate::metrics::report::{classification_report, metrics_classification_report, metrics_regression_report, regression_report};
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-5
+    }
+
+    // actual:    0 0 1 1 2 2
+    // predicted: 0 1 1 1 2 0
+    fn three_classes() -> (Vec<usize>, Vec<usize>) {
+        (vec![0, 1, 1, 1, 2, 0], vec![0, 0, 1, 1, 2, 2])
+    }
+
+    #[test]
+    fn confusion_and_accuracy() {
+        let (predicted, actual) = three_classes();
+        assert_eq!(confusion_matrix(&predicted, &actual, 3), vec![vec![1, 1, 0], vec![0, 2, 0], vec![1, 0, 1]]);
+        assert!(close(accuracy(&predicted, &actual), 4.0 / 6.0));
+    }
+
+    #[test]
+    fn precision_recall_f1_by_hand() {
+        let (predicted, actual) = three_classes();
+        // class 0: p 1/2 r 1/2, class 1: p 2/3 r 1, class 2: p 1 r 1/2
+        let scores = per_class_scores(&predicted, &actual, 3);
+        assert!(close(scores[1].0, 2.0 / 3.0) && close(scores[1].1, 1.0) && close(scores[1].2, 0.8));
+
+        let (precision, recall, f1) = precision_recall_f1(&predicted, &actual, 3, Average::Macro);
+        assert!(close(precision, (0.5 + 2.0 / 3.0 + 1.0) / 3.0));
+        assert!(close(recall, (0.5 + 1.0 + 0.5) / 3.0));
+        assert!(close(f1, (0.5 + 0.8 + 2.0 / 3.0) / 3.0));
+
+        // Micro scores equal the accuracy for single label problems
+        let (precision, recall, f1) = precision_recall_f1(&predicted, &actual, 3, Average::Micro);
+        assert!(close(precision, 4.0 / 6.0) && close(recall, 4.0 / 6.0) && close(f1, 4.0 / 6.0));
+    }
+
+    #[test]
+    fn ffi_on_pmc_outputs() {
+        // Binary tanh outputs and -1 / 1 labels
+        let predictions = [0.8f32, -0.3, 0.1, -0.9];
+        let labels = [1.0f32, 1.0, -1.0, -1.0];
+        assert!(close(output(|score| metrics_accuracy(predictions.as_ptr(), labels.as_ptr(), 4, 1, score)), 0.5));
+        assert!(close(output(|score| metrics_precision(predictions.as_ptr(), labels.as_ptr(), 4, 1, Average::Macro as i32, score)), 0.5));
+
+        let mut counts = [0i32; 4];
+        let classes = output(|classes| metrics_confusion_matrix(predictions.as_ptr(), labels.as_ptr(), 4, 1, counts.as_mut_ptr(), 4, classes));
+        assert_eq!(classes, 2);
+        assert_eq!(counts, [1, 1, 1, 1]);
+        assert_eq!(metrics_confusion_matrix(predictions.as_ptr(), labels.as_ptr(), 4, 1, counts.as_mut_ptr(), 3, &mut 0), Status::DimensionMismatch);
+        assert_eq!(metrics_accuracy(predictions.as_ptr(), labels.as_ptr(), 4, 3, &mut 0.0), Status::DimensionMismatch);
+        assert_eq!(metrics_mse(std::ptr::null(), labels.as_ptr(), 4, &mut 0.0), Status::NullPointer);
+
+        // One-hot rows
+        let predictions = [0.9f32, -0.2, -0.5, 0.4, 0.7, -0.1];
+        let labels = [1.0f32, -1.0, -1.0, 1.0, -1.0, 1.0];
+        assert!(close(output(|score| metrics_accuracy(predictions.as_ptr(), labels.as_ptr(), 6, 2, score)), 2.0 / 3.0));
+        assert!(close(output(|score| metrics_recall(predictions.as_ptr(), labels.as_ptr(), 6, 2, Average::Micro as i32, score)), 2.0 / 3.0));
+        assert!(close(output(|score| metrics_f1(predictions.as_ptr(), labels.as_ptr(), 6, 2, Average::Macro as i32, score)), 2.0 / 3.0));
+    }
+
+    #[test]
+    fn log_loss_by_hand() {
+        let probabilities = [0.8f32, 0.4];
+        let labels = [1.0f32, -1.0];
+        let expected = -(0.8f32.ln() + 0.6f32.ln()) / 2.0;
+        assert!(close(output(|score| metrics_log_loss(probabilities.as_ptr(), labels.as_ptr(), 2, 1, score)), expected));
+
+        // Rows are normalized before taking the true class
+        let loss = log_loss(&[vec![2.0, 6.0]], &[vec![0.0, 1.0]]);
+        assert!(close(loss, -0.75f32.ln()));
+        assert!(log_loss(&[vec![0.0]], &[vec![1.0]]).is_finite());
+    }
+
+    #[test]
+    fn roc_auc_by_hand() {
+        // 3 of the 4 positive / negative pairs are ordered
+        let scores = [0.1f32, 0.4, 0.35, 0.8];
+        let labels = [-1.0f32, -1.0, 1.0, 1.0];
+        assert!(close(output(|score| metrics_roc_auc(scores.as_ptr(), labels.as_ptr(), 4, score)), 0.75));
+        // A tie counts for half
+        assert!(close(roc_auc(&[0.5, 0.5], &[false, true]), 0.5));
+        assert!(roc_auc(&[0.5, 0.7], &[true, true]).is_nan());
+    }
+
+    #[test]
+    fn regression_by_hand() {
+        let predictions = [2.5f32, 0.0, 2.0, 8.0];
+        let labels = [3.0f32, -0.5, 2.0, 7.0];
+        assert!(close(output(|score| metrics_mse(predictions.as_ptr(), labels.as_ptr(), 4, score)), 0.375));
+        assert!(close(output(|score| metrics_mae(predictions.as_ptr(), labels.as_ptr(), 4, score)), 0.5));
+        // 1 - 1.5 / 29.1875
+        assert!(close(output(|score| metrics_r2(predictions.as_ptr(), labels.as_ptr(), 4, 1, score)), 1.0 - 1.5 / 29.1875));
+        assert!(close(r2_score(&[vec![1.0], vec![1.0]], &[vec![1.0], vec![1.0]]), 1.0));
+    }
+
+    #[test]
+    fn reports() {
+        let (predicted, actual) = three_classes();
+        let names = vec!["cat".to_string(), "dog".to_string()];
+        let report = classification_report(&predicted, &actual, 3, &names);
+        assert!(report.contains("precision"));
+        assert!(report.lines().any(|line| line.trim_start().starts_with("dog") && line.contains("0.67") && line.contains("1.00")));
+        // Unnamed class falls back to its id
+        assert!(report.lines().any(|line| line.trim_start().starts_with("2 ")));
+        assert!(report.lines().any(|line| line.trim_start().starts_with("accuracy") && line.contains("0.67")));
+
+        let report = regression_report(&[vec![1.0], vec![2.0]], &[vec![1.0], vec![3.0]]);
+        assert!(report.contains("mse") && report.contains("0.500000"));
+    }
+
+    fn text(buffer: ByteBuffer) -> String {
+        let text = String::from_utf8(unsafe { buffer.as_slice() }.to_vec()).unwrap();
+        assert_eq!(free_byte_buffer(buffer), Status::Ok);
+        text
+    }
+
+    #[test]
+    fn report_exports_return_the_text() {
+        let predictions = [0.9f32, -0.2, 0.4, -0.7];
+        let labels = [1.0f32, -1.0, -1.0, -1.0];
+        let report = text(output(|out| metrics_classification_report(predictions.as_ptr(), labels.as_ptr(), 4, 1, out)));
+        assert!(report.lines().any(|line| line.trim_start().starts_with("accuracy") && line.contains("0.75")));
+        assert!(report.contains("confusion matrix"));
+
+        let report = text(output(|out| metrics_regression_report(predictions.as_ptr(), labels.as_ptr(), 4, 1, out)));
+        assert_eq!(report, regression_report(&[vec![0.9], vec![-0.2], vec![0.4], vec![-0.7]], &[vec![1.0], vec![-1.0], vec![-1.0], vec![-1.0]]));
+        assert!(report.starts_with("     mse"));
+
+        assert_eq!(metrics_regression_report(predictions.as_ptr(), labels.as_ptr(), 4, 1, std::ptr::null_mut()), Status::NullPointer);
+    }
+}
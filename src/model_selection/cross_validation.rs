@@ -0,0 +1,57 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use super::space::Candidate;
+use crate::dataset::dataset_struct::Dataset;
+use crate::ffi::error::FfiResult;
+use crate::neural_network::initializer::WeightInit;
+use crate::neural_network::nn_struct::NeuralNetwork;
+use crate::neural_network::regularization::Regularization;
+use crate::neural_network::train::{fit, TrainConfig};
+use crate::neural_network::validation::evaluate;
+use crate::preprocessing::split::{class_ids, KFold};
+
+/// Accuracy for classification, negated mean squared error for regression: higher is always better.
+pub fn score(model: &NeuralNetwork, inputs: &[Vec<f32>], labels: &[Vec<f32>], is_classification: bool) -> f32 {
+    let (loss, accuracy) = evaluate(model, inputs, labels, is_classification);
+    if is_classification { accuracy } else { -loss }
+}
+
+/// Candidate trained on the given rows, weights and sampling seeded by `seed`.
+pub fn train_candidate(candidate: &Candidate, inputs: &[Vec<f32>], labels: &[Vec<f32>],
+                       is_classification: bool, weight_init: WeightInit, seed: u64) -> NeuralNetwork {
+    let topology: Vec<usize> = std::iter::once(inputs[0].len())
+        .chain(candidate.hidden_layers.iter().copied())
+        .chain(std::iter::once(labels[0].len()))
+        .collect();
+    let mut model = NeuralNetwork::new(&topology, weight_init, 0.0, seed).with_hidden_activation(candidate.activation);
+
+    let config = TrainConfig {
+        learning_rate: candidate.learning_rate,
+        iterations: candidate.epochs * inputs.len(),
+        is_classification,
+        regularization: Regularization::default(),
+        eval_every: 0,
+        patience: 0,
+    };
+    fit(&mut model, inputs, labels, &[], &[], &config, &mut StdRng::seed_from_u64(seed));
+    model
+}
+
+/// Score of each fold, stratified on the classes for classification.
+pub fn cross_validate(candidate: &Candidate, dataset: &Dataset, folds: usize,
+                      is_classification: bool, weight_init: WeightInit, seed: u64) -> FfiResult<Vec<f32>> {
+    let k_fold = if is_classification {
+        KFold::stratified(&class_ids(&dataset.label_rows()), folds, seed)?
+    } else {
+        KFold::new(dataset.n_samples, folds, seed)?
+    };
+
+    Ok(k_fold.enumerate()
+        .map(|(fold, (train, validation))| {
+            let (train, validation) = (dataset.subset(&train), dataset.subset(&validation));
+            let model = train_candidate(candidate, &train.feature_rows(), &train.label_rows(),
+                                        is_classification, weight_init, seed.wrapping_add(fold as u64));
+            score(&model, &validation.feature_rows(), &validation.label_rows(), is_classification)
+        })
+        .collect())
+}
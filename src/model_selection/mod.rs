@@ -0,0 +1,4 @@
+pub mod space;
+pub mod cross_validation;
+pub mod search;
+mod tester;
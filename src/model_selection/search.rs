@@ -0,0 +1,86 @@
+use std::fmt::Write;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use super::cross_validation::{cross_validate, train_candidate};
+use super::space::{Candidate, ParameterSpace};
+use crate::dataset::dataset_struct::Dataset;
+use crate::ffi::error::{FfiError, FfiResult};
+use crate::neural_network::initializer::WeightInit;
+use crate::neural_network::nn_struct::NeuralNetwork;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchConfig {
+    pub folds: usize,
+    pub is_classification: bool,
+    pub weight_init: WeightInit,
+    /// Seeds the folds, the candidate draws and every training run
+    pub seed: u64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CandidateScore {
+    pub candidate: Candidate,
+    pub fold_scores: Vec<f32>,
+    pub mean_score: f32,
+    pub std_score: f32,
+}
+
+pub struct SearchResult {
+    /// Best mean score first
+    pub ranking: Vec<CandidateScore>,
+    /// Best candidate trained again on the whole dataset
+    pub best_model: NeuralNetwork,
+}
+
+/// Every combination of the space, scored by k-fold cross-validation.
+pub fn grid_search(dataset: &Dataset, space: &ParameterSpace, config: &SearchConfig) -> FfiResult<SearchResult> {
+    search(dataset, space.grid(), config)
+}
+
+/// `count` random combinations of the space, scored by k-fold cross-validation.
+pub fn random_search(dataset: &Dataset, space: &ParameterSpace, count: usize, config: &SearchConfig) -> FfiResult<SearchResult> {
+    let candidates = space.sample(count, &mut StdRng::seed_from_u64(config.seed));
+    search(dataset, candidates, config)
+}
+
+// Candidates are independent and seeded, so running them in parallel gives the same ranking
+fn search(dataset: &Dataset, candidates: Vec<Candidate>, config: &SearchConfig) -> FfiResult<SearchResult> {
+    if candidates.is_empty() {
+        return Err(FfiError::invalid("the parameter space is empty"));
+    }
+    let evaluate = |candidate: Candidate| -> FfiResult<CandidateScore> {
+        let fold_scores = cross_validate(&candidate, dataset, config.folds, config.is_classification, config.weight_init, config.seed)?;
+        let mean_score = fold_scores.iter().sum::<f32>() / fold_scores.len() as f32;
+        let variance = fold_scores.iter().map(|s| (s - mean_score).powi(2)).sum::<f32>() / fold_scores.len() as f32;
+        Ok(CandidateScore { candidate, fold_scores, mean_score, std_score: variance.sqrt() })
+    };
+
+    #[cfg(feature = "parallel")]
+    let mut ranking: Vec<CandidateScore> = candidates.into_par_iter().map(evaluate).collect::<FfiResult<_>>()?;
+    #[cfg(not(feature = "parallel"))]
+    let mut ranking: Vec<CandidateScore> = candidates.into_iter().map(evaluate).collect::<FfiResult<_>>()?;
+
+    // Stable sort keeps the grid order between equal scores
+    ranking.sort_by(|a, b| b.mean_score.total_cmp(&a.mean_score));
+
+    let best_model = train_candidate(&ranking[0].candidate, &dataset.feature_rows(), &dataset.label_rows(),
+                                     config.is_classification, config.weight_init, config.seed);
+    Ok(SearchResult { ranking, best_model })
+}
+
+impl SearchResult {
+    /// One line per candidate, best first.
+    pub fn table(&self) -> String {
+        let mut table = String::new();
+        writeln!(table, "{:>4} {:>10} {:>8} {:>16} {:>13} {:>8} {:>8}", "rank", "mean", "std", "hidden", "learning_rate", "act", "epochs").unwrap();
+        for (rank, score) in self.ranking.iter().enumerate() {
+            let candidate = &score.candidate;
+            writeln!(table, "{:>4} {:>10.4} {:>8.4} {:>16} {:>13} {:>8} {:>8}",
+                     rank + 1, score.mean_score, score.std_score, format!("{:?}", candidate.hidden_layers),
+                     candidate.learning_rate, format!("{:?}", candidate.activation), candidate.epochs).unwrap();
+        }
+        table
+    }
+}
@@ -0,0 +1,55 @@
+use rand::seq::index::sample;
+use rand::Rng;
+use crate::neural_network::activation::Activation;
+
+/// Values tried for each PMC hyperparameter, every combination is a candidate.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParameterSpace {
+    /// Hidden layer sizes, input and output sizes come from the dataset
+    pub hidden_layers: Vec<Vec<usize>>,
+    pub learning_rates: Vec<f32>,
+    pub activations: Vec<Activation>,
+    /// Passes over the training folds, one pass is one random sample per training row
+    pub epochs: Vec<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Candidate {
+    pub hidden_layers: Vec<usize>,
+    pub learning_rate: f32,
+    pub activation: Activation,
+    pub epochs: usize,
+}
+
+impl ParameterSpace {
+    pub fn size(&self) -> usize {
+        self.hidden_layers.len() * self.learning_rates.len() * self.activations.len() * self.epochs.len()
+    }
+
+    /// Candidate at `index` of the grid, epochs vary fastest.
+    pub fn candidate(&self, index: usize) -> Candidate {
+        let epochs = index % self.epochs.len();
+        let index = index / self.epochs.len();
+        let activation = index % self.activations.len();
+        let index = index / self.activations.len();
+        let learning_rate = index % self.learning_rates.len();
+        let hidden_layers = index / self.learning_rates.len();
+
+        Candidate {
+            hidden_layers: self.hidden_layers[hidden_layers].clone(),
+            learning_rate: self.learning_rates[learning_rate],
+            activation: self.activations[activation],
+            epochs: self.epochs[epochs],
+        }
+    }
+
+    pub fn grid(&self) -> Vec<Candidate> {
+        (0..self.size()).map(|index| self.candidate(index)).collect()
+    }
+
+    /// `count` distinct candidates drawn uniformly, the whole grid when it is smaller.
+    pub fn sample<R: Rng>(&self, count: usize, rng: &mut R) -> Vec<Candidate> {
+        let count = count.min(self.size());
+        sample(rng, self.size(), count).iter().map(|index| self.candidate(index)).collect()
+    }
+}
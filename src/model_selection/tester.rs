@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    use crate::dataset::dataset_struct::{Dataset, NEGATIVE_LABEL, POSITIVE_LABEL};
+    use crate::model_selection::search::{grid_search, random_search, SearchConfig};
+    use crate::model_selection::space::ParameterSpace;
+    use crate::neural_network::activation::Activation;
+    use crate::neural_network::initializer::WeightInit;
+    use crate::neural_network::validation::evaluate;
+
+    // Two separable blobs, 10 samples each, one tanh output
+    fn blobs() -> Dataset {
+        let mut features = Vec::new();
+        let mut labels = Vec::new();
+        for i in 0..20 {
+            let (center, label) = if i % 2 == 0 { (-1.0, NEGATIVE_LABEL) } else { (1.0, POSITIVE_LABEL) };
+            let offset = (i / 2) as f32 * 0.03;
+            features.extend([center + offset, center - offset]);
+            labels.push(label);
+        }
+        Dataset { features, labels, n_samples: 20, feature_size: 2, label_size: 1, ..Default::default() }
+    }
+
+    fn space() -> ParameterSpace {
+        ParameterSpace {
+            hidden_layers: vec![vec![], vec![4]],
+            learning_rates: vec![0.0001, 0.05],
+            activations: vec![Activation::Tanh, Activation::Relu],
+            epochs: vec![20],
+        }
+    }
+
+    fn config() -> SearchConfig {
+        SearchConfig { folds: 4, is_classification: true, weight_init: WeightInit::XavierUniform, seed: 7 }
+    }
+
+    #[test]
+    fn grid_covers_every_combination() {
+        let grid = space().grid();
+        assert_eq!(grid.len(), 8);
+        for (i, a) in grid.iter().enumerate() {
+            assert!(grid[i + 1..].iter().all(|b| b != a));
+        }
+    }
+
+    #[test]
+    fn grid_search_ranks_and_refits() {
+        let dataset = blobs();
+        let result = grid_search(&dataset, &space(), &config()).unwrap();
+
+        assert_eq!(result.ranking.len(), 8);
+        assert!(result.ranking.windows(2).all(|w| w[0].mean_score >= w[1].mean_score));
+        assert!(result.ranking.iter().all(|score| score.fold_scores.len() == 4));
+        assert!(result.ranking[0].mean_score >= 0.9);
+
+        let (_, accuracy) = evaluate(&result.best_model, &dataset.feature_rows(), &dataset.label_rows(), true);
+        assert!(accuracy >= 0.9);
+        assert_eq!(result.best_model.hidden_activation, result.ranking[0].candidate.activation);
+        assert_eq!(result.table().lines().count(), 9);
+    }
+
+    #[test]
+    fn random_search_draws_distinct_candidates() {
+        let result = random_search(&blobs(), &space(), 3, &config()).unwrap();
+        assert_eq!(result.ranking.len(), 3);
+        for (i, a) in result.ranking.iter().enumerate() {
+            assert!(result.ranking[i + 1..].iter().all(|b| b.candidate != a.candidate));
+        }
+    }
+
+    #[test]
+    fn search_rejects_more_folds_than_samples() {
+        let config = SearchConfig { folds: 100, ..config() };
+        let error = grid_search(&blobs(), &space(), &config).err().unwrap();
+        assert_eq!(error.message, format!("100 folds for {} samples", blobs().n_samples));
+    }
+
+    #[test]
+    fn search_is_deterministic() {
+        let first = random_search(&blobs(), &space(), 4, &config()).unwrap();
+        let second = random_search(&blobs(), &space(), 4, &config()).unwrap();
+        assert_eq!(first.ranking, second.ranking);
+        assert_eq!(first.best_model.weights, second.best_model.weights);
+    }
+}
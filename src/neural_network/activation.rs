@@ -0,0 +1,45 @@
+use bincode::{Decode, Encode};
+use crate::ffi::args::enum_code;
+
+/// Activation of the hidden layers. The output layer stays tanh for classification and linear for regression.
+#[repr(C)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Activation {
+    /// tanh for classification, ReLU for regression
+    #[default]
+    Auto,
+    Tanh,
+    Relu,
+    Sigmoid,
+}
+
+enum_code!(Activation { Auto, Tanh, Relu, Sigmoid });
+
+impl Activation {
+    pub(crate) fn resolve(self, is_classification: bool) -> Activation {
+        match self {
+            Activation::Auto if is_classification => Activation::Tanh,
+            Activation::Auto => Activation::Relu,
+            other => other,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn apply(self, total: f32) -> f32 {
+        match self {
+            Activation::Tanh | Activation::Auto => total.tanh(),
+            Activation::Relu => total.max(0.0),
+            Activation::Sigmoid => 1.0 / (1.0 + (-total).exp()),
+        }
+    }
+
+    // Expressed from the activated value
+    #[inline]
+    pub(crate) fn derivative(self, activation: f32) -> f32 {
+        match self {
+            Activation::Tanh | Activation::Auto => 1.0 - activation * activation,
+            Activation::Relu => if activation > 0.0 { 1.0 } else { 0.0 },
+            Activation::Sigmoid => activation * (1.0 - activation),
+        }
+    }
+}
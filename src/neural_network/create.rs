@@ -1,67 +1,99 @@
 use super::nn_struct::NeuralNetwork;
-use rand::Rng;
+use super::activation::Activation;
+use super::initializer::WeightInit;
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, FfiError, FfiResult, Status};
+use crate::ffi::handle::{self, Handle};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
 #[no_mangle]
-pub(crate) extern "C" fn init(arr: *const i32, len: i32) -> Box<NeuralNetwork> {
-    // Convert the raw pointer to a slice
-    let arr_slice = unsafe { std::slice::from_raw_parts(arr, len as usize) };
-
-    // Initialize the neural_network model
-    let mut model = Box::new(NeuralNetwork {
-        num_layers: len as usize,
-        neurons_per_layer: arr_slice.iter().map(|&x| x as usize).collect(),
-        weights: Vec::new(),
-        activations: Vec::new(),
-        deltas: Vec::new()
-    });
-
-    // Create a random number generator
-    let mut rng = rand::rng();
-
-    for layer in 0..model.num_layers {
-        if layer == 0 {
-            // No weights for the input layer, just initialize an empty vector
-            model.weights.push(Vec::new());
-        } else {
-            // Initialize weights with bias terms
-            let previous_layer_neurons = model.neurons_per_layer[layer - 1];
+pub(crate) extern "C" fn init(arr: *const i32, len: i32, weight_init: i32, bias: f32, seed: u64, out: *mut Handle) -> Status {
+    ffi_call(|| {
+        let weight_init = WeightInit::try_from(weight_init)?;
+        // Convert the raw pointer to a slice
+        let arr_slice = args::slice(arr, len, "arr")?;
+        if arr_slice.len() < 2 {
+            return Err(FfiError::invalid("a network needs at least an input and an output layer"));
+        }
+        let neurons_per_layer = arr_slice.iter().map(|&x| args::positive(x, "layer size")).collect::<FfiResult<Vec<usize>>>()?;
+
+        handle::write(out, NeuralNetwork::new(&neurons_per_layer, weight_init, bias, seed))
+    })
+}
+
+impl NeuralNetwork {
+    pub fn new(neurons_per_layer: &[usize], weight_init: WeightInit, bias: f32, seed: u64) -> NeuralNetwork {
+        // Initialize the neural_network model
+        let mut model = NeuralNetwork {
+            num_layers: neurons_per_layer.len(),
+            neurons_per_layer: neurons_per_layer.to_vec(),
+            weights: Vec::new(),
+            biases: Vec::new(),
+            hidden_activation: Activation::Auto,
+            activations: Vec::new(),
+            deltas: Vec::new()
+        };
+
+        // Create a seeded random number generator, same seed gives the same network
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        for layer in 0..model.num_layers {
             let current_layer_neurons = model.neurons_per_layer[layer];
-            let layer_weights: Vec<Vec<f32>> = (0..=previous_layer_neurons)
-                .map(|_| {
-                    (0..=current_layer_neurons)
-                        .map(|j| if j == 0 { 0.0f32 } else { rng.random_range(-1.0..=1.0) })
-                        .collect()
-                })
-                .collect();
-            model.weights.push(layer_weights);
+            if layer == 0 {
+                // No weights for the input layer, just initialize empty vectors
+                model.weights.push(Vec::new());
+                model.biases.push(Vec::new());
+            } else {
+                // One contiguous row-major matrix per layer, one row per neuron
+                let previous_layer_neurons = model.neurons_per_layer[layer - 1];
+                let layer_weights: Vec<f32> = (0..current_layer_neurons * previous_layer_neurons)
+                    .map(|_| weight_init.sample(&mut rng, previous_layer_neurons, current_layer_neurons))
+                    .collect();
+                model.weights.push(layer_weights);
+                model.biases.push(vec![bias; current_layer_neurons]);
+            }
+
+            // Initialize activations and deltas for the current layer
+            model.activations.push(vec![0.0; current_layer_neurons]);
+            model.deltas.push(vec![0.0; current_layer_neurons]);
         }
 
-        // Initialize activations and deltas for the current layer
-        let current_layer_neurons = model.neurons_per_layer[layer];
-        let layer_activations: Vec<f32> = (0..=current_layer_neurons)
-            .map(|i| if i == 0 { 1.0 } else { 0.0 })
-            .collect();
-        let layer_deltas: Vec<f32> = vec![0.0; current_layer_neurons + 1];
-        model.activations.push(layer_activations);
-        model.deltas.push(layer_deltas);
+        model
     }
 
-    model
+    pub fn with_hidden_activation(mut self, hidden_activation: Activation) -> NeuralNetwork {
+        self.hidden_activation = hidden_activation;
+        self
+    }
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn set_hidden_activation(model: Handle, hidden_activation: i32) -> Status {
+    ffi_call(|| handle::with_mut(model, |model: &mut NeuralNetwork| {
+        model.hidden_activation = Activation::try_from(hidden_activation)?;
+        Ok(())
+    }))
 }
 
 #[cfg(test)]
 mod init_tests_simple {
     use super::*;
+    use crate::ffi::test_support::{created, taken};
 
     // TEST DATA
-    fn setup_model() -> Box<NeuralNetwork> {
+    fn setup_model() -> NeuralNetwork {
         // Init Data
         let slice: &[i32] = &[3, 2, 1];
         let ptr: *const i32 = slice.as_ptr();
         let len: i32 = slice.len() as i32;
 
-        let model = init(ptr, len);
-        model
+        taken(created(|out| init(ptr, len, WeightInit::Uniform as i32, 0.0, 42, out)))
+    }
+
+    fn setup_seeded_model(weight_init: WeightInit, seed: u64) -> NeuralNetwork {
+        let slice: &[i32] = &[4, 8, 2];
+        taken(created(|out| init(slice.as_ptr(), slice.len() as i32, weight_init as i32, 0.1, seed, out)))
     }
 
     #[test]
@@ -74,26 +106,21 @@ mod init_tests_simple {
     fn init_weight() {
         let model = setup_model();
         // layers
-        assert_eq!(model.num_layers, 2);
+        assert_eq!(model.num_layers, 3);
         // Weights[0]
         assert!(model.weights[0].is_empty());
+        assert!(model.biases[0].is_empty());
         // Weights[1]
-        assert_eq!(model.weights[1].len(), 4);
-        for neuron_weights in &model.weights[1] {
-            assert_eq!(neuron_weights.len(), 3);
-            assert_eq!(neuron_weights[0], 0.0);
-            for &weight in &neuron_weights[1..] {
-                assert!(weight >= -1.0 && weight <= 1.0);
-            }
+        assert_eq!(model.weights[1].len(), 2 * 3);
+        assert_eq!(model.biases[1], vec![0.0, 0.0]);
+        for &weight in &model.weights[1] {
+            assert!((-1.0..=1.0).contains(&weight));
         }
         // Weights[2]
-        assert_eq!(model.weights[2].len(), 3);
-        for neuron_weights in &model.weights[2] {
-            assert_eq!(neuron_weights.len(), 2);
-            assert_eq!(neuron_weights[0], 0.0);
-            for &weight in &neuron_weights[1..] {
-                assert!(weight >= -1.0 && weight <= 1.0);
-            }
+        assert_eq!(model.weights[2].len(), 2);
+        assert_eq!(model.biases[2], vec![0.0]);
+        for &weight in &model.weights[2] {
+            assert!((-1.0..=1.0).contains(&weight));
         }
     }
 
@@ -103,11 +130,11 @@ mod init_tests_simple {
         // Len
         assert_eq!(model.activations.len(), 3);
         // First Layer
-        assert_eq!(model.activations[0], vec![1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(model.activations[0], vec![0.0, 0.0, 0.0]);
         // Second Layer
-        assert_eq!(model.activations[1], vec![1.0, 0.0, 0.0]);
+        assert_eq!(model.activations[1], vec![0.0, 0.0]);
         // Third Layer
-        assert_eq!(model.activations[2], vec![1.0, 0.0]);
+        assert_eq!(model.activations[2], vec![0.0]);
     }
 
     #[test]
@@ -116,10 +143,35 @@ mod init_tests_simple {
         // Len
         assert_eq!(model.deltas.len(), 3);
         // First Layer
-        assert_eq!(model.deltas[0], vec![0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(model.deltas[0], vec![0.0, 0.0, 0.0]);
         // Second Layer
-        assert_eq!(model.deltas[1], vec![0.0, 0.0, 0.0]);
+        assert_eq!(model.deltas[1], vec![0.0, 0.0]);
         // Third Layer
-        assert_eq!(model.deltas[2], vec![0.0, 0.0]);
+        assert_eq!(model.deltas[2], vec![0.0]);
+    }
+
+    #[test]
+    fn init_same_seed_is_identical() {
+        for weight_init in [WeightInit::Uniform, WeightInit::XavierUniform, WeightInit::XavierNormal,
+                            WeightInit::HeUniform, WeightInit::HeNormal] {
+            let first = setup_seeded_model(weight_init, 7);
+            let second = setup_seeded_model(weight_init, 7);
+            assert_eq!(first, second);
+        }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn init_different_seed_differs() {
+        let first = setup_seeded_model(WeightInit::HeNormal, 1);
+        let second = setup_seeded_model(WeightInit::HeNormal, 2);
+        assert_ne!(first.weights, second.weights);
+    }
+
+    #[test]
+    fn init_constant_bias() {
+        let model = setup_seeded_model(WeightInit::XavierUniform, 3);
+        for layer in 1..model.num_layers {
+            assert_eq!(model.biases[layer], vec![0.1; model.neurons_per_layer[layer]]);
+        }
+    }
+}
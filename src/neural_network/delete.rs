@@ -1,9 +1,12 @@
 use super::nn_struct::NeuralNetwork;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
 
 #[no_mangle]
-extern "C" fn delete_pmc(model: &mut NeuralNetwork) {
-    unsafe {
-        let _ = Box::from_raw(model);
-    }
-    println!("Model deleted")
-}
\ No newline at end of file
+pub(crate) extern "C" fn delete_pmc(model: Handle) -> Status {
+    ffi_call(|| {
+        handle::remove::<NeuralNetwork>(model)?;
+        println!("Model deleted");
+        Ok(())
+    })
+}
@@ -0,0 +1,74 @@
+use rand::Rng;
+use rand_distr::{Distribution, Normal};
+use crate::ffi::args::enum_code;
+
+/// Weight initialization scheme used by `create::init`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WeightInit {
+    /// Legacy scheme: uniform in [-1, 1]
+    Uniform,
+    /// Glorot: uniform in [-sqrt(6 / (fan_in + fan_out)), +sqrt(...)]
+    XavierUniform,
+    /// Glorot: normal with std = sqrt(2 / (fan_in + fan_out))
+    XavierNormal,
+    /// Kaiming: uniform in [-sqrt(6 / fan_in), +sqrt(6 / fan_in)]
+    HeUniform,
+    /// Kaiming: normal with std = sqrt(2 / fan_in)
+    HeNormal,
+}
+
+enum_code!(WeightInit { Uniform, XavierUniform, XavierNormal, HeUniform, HeNormal });
+
+impl WeightInit {
+    pub fn sample<R: Rng>(&self, rng: &mut R, fan_in: usize, fan_out: usize) -> f32 {
+        let fan_in = fan_in.max(1) as f32;
+        let fan_out = fan_out.max(1) as f32;
+        match self {
+            WeightInit::Uniform => rng.random_range(-1.0..=1.0),
+            WeightInit::XavierUniform => {
+                let limit = (6.0 / (fan_in + fan_out)).sqrt();
+                rng.random_range(-limit..=limit)
+            }
+            WeightInit::XavierNormal => {
+                let std = (2.0 / (fan_in + fan_out)).sqrt();
+                Normal::new(0.0, std).unwrap().sample(rng)
+            }
+            WeightInit::HeUniform => {
+                let limit = (6.0 / fan_in).sqrt();
+                rng.random_range(-limit..=limit)
+            }
+            WeightInit::HeNormal => {
+                let std = (2.0 / fan_in).sqrt();
+                Normal::new(0.0, std).unwrap().sample(rng)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn xavier_uniform_bounds() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let limit = (6.0f32 / (64.0 + 64.0)).sqrt();
+        for _ in 0..1000 {
+            let w = WeightInit::XavierUniform.sample(&mut rng, 64, 64);
+            assert!(w.abs() <= limit);
+        }
+    }
+
+    #[test]
+    fn he_normal_std() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let samples: Vec<f32> = (0..20000).map(|_| WeightInit::HeNormal.sample(&mut rng, 50, 10)).collect();
+        let mean = samples.iter().sum::<f32>() / samples.len() as f32;
+        let var = samples.iter().map(|w| (w - mean).powi(2)).sum::<f32>() / samples.len() as f32;
+        assert!(mean.abs() < 0.01);
+        assert!((var.sqrt() - 0.2).abs() < 0.01);
+    }
+}
@@ -0,0 +1,108 @@
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use super::activation::Activation;
+use super::propagate::activate;
+
+// Samples processed together, each weight row is loaded once per block
+const ROW_BLOCK: usize = 4;
+// Independent accumulators per dot product, lets the compiler vectorize the reduction
+const LANES: usize = 8;
+
+/// outputs (n x out) = activation(inputs (n x in) * weights^T + biases), weights row-major (out x in).
+#[allow(clippy::too_many_arguments)]
+pub fn dense_batch(
+    weights: &[f32],
+    biases: &[f32],
+    inputs: &[f32],
+    outputs: &mut [f32],
+    input_size: usize,
+    is_output: bool,
+    is_classification: bool,
+    hidden_activation: Activation
+) {
+    let output_size = biases.len();
+    let n_samples = inputs.len() / input_size;
+    let inputs = &inputs[..n_samples * input_size];
+    let outputs = &mut outputs[..n_samples * output_size];
+
+    let block = |(input_block, output_block): (&[f32], &mut [f32])| {
+        dense_block(weights, biases, input_block, output_block, input_size, output_size, is_output, is_classification, hidden_activation)
+    };
+
+    #[cfg(feature = "parallel")]
+    inputs.par_chunks(ROW_BLOCK * input_size)
+        .zip(outputs.par_chunks_mut(ROW_BLOCK * output_size))
+        .for_each(block);
+
+    #[cfg(not(feature = "parallel"))]
+    inputs.chunks(ROW_BLOCK * input_size)
+        .zip(outputs.chunks_mut(ROW_BLOCK * output_size))
+        .for_each(block);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn dense_block(
+    weights: &[f32],
+    biases: &[f32],
+    inputs: &[f32],
+    outputs: &mut [f32],
+    input_size: usize,
+    output_size: usize,
+    is_output: bool,
+    is_classification: bool,
+    hidden_activation: Activation
+) {
+    let rows = inputs.len() / input_size;
+    let full_lanes = input_size - input_size % LANES;
+
+    for (c, (weight_row, &bias)) in weights.chunks_exact(input_size).zip(biases).enumerate() {
+        let mut acc = [[0.0f32; LANES]; ROW_BLOCK];
+
+        // Vectorizable part, ROW_BLOCK rows against the same weight chunk
+        for k in (0..full_lanes).step_by(LANES) {
+            let w: &[f32; LANES] = weight_row[k..k + LANES].try_into().unwrap();
+            for (r, acc_row) in acc.iter_mut().enumerate().take(rows) {
+                let start = r * input_size + k;
+                let x: &[f32; LANES] = inputs[start..start + LANES].try_into().unwrap();
+                for l in 0..LANES {
+                    acc_row[l] += x[l] * w[l];
+                }
+            }
+        }
+
+        for (r, acc_row) in acc.iter().enumerate().take(rows) {
+            let x = &inputs[r * input_size..(r + 1) * input_size];
+            // Remainder that does not fill a lane
+            let tail: f32 = x[full_lanes..].iter().zip(&weight_row[full_lanes..]).map(|(a, b)| a * b).sum();
+            let total = bias + tail + acc_row.iter().sum::<f32>();
+            outputs[r * output_size + c] = activate(total, is_output, is_classification, hidden_activation);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dense_batch_matches_naive() {
+        let (n, input_size, output_size) = (7, 19, 5);
+        let inputs: Vec<f32> = (0..n * input_size).map(|x| ((x * 7) % 13) as f32 / 13.0 - 0.5).collect();
+        let weights: Vec<f32> = (0..output_size * input_size).map(|x| ((x * 5) % 11) as f32 / 11.0 - 0.5).collect();
+        let biases: Vec<f32> = (0..output_size).map(|x| x as f32 * 0.1).collect();
+        let mut outputs = vec![0.0; n * output_size];
+
+        dense_batch(&weights, &biases, &inputs, &mut outputs, input_size, true, false, Activation::Auto);
+
+        for r in 0..n {
+            for c in 0..output_size {
+                let mut expected = biases[c];
+                for k in 0..input_size {
+                    expected += inputs[r * input_size + k] * weights[c * input_size + k];
+                }
+                assert!((outputs[r * output_size + c] - expected).abs() < 1e-5);
+            }
+        }
+    }
+}
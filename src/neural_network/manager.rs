@@ -0,0 +1,29 @@
+use std::ffi::c_char;
+use std::io;
+use super::nn_struct::NeuralNetwork;
+use crate::basic_function::storage::{load_model_file, save_model_file, ModelKind};
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
+
+impl NeuralNetwork {
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        save_model_file(self, ModelKind::NeuralNetwork, path)
+    }
+
+    /// Fails with InvalidData on headerless, corrupted, newer or non PMC files.
+    pub fn load(path: &str) -> io::Result<NeuralNetwork> {
+        load_model_file(ModelKind::NeuralNetwork, path)
+    }
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn save_pmc(model: Handle, path: *const c_char) -> Status {
+    ffi_call(|| handle::with(model, |model: &NeuralNetwork| Ok(model.save(&args::path(path)?)?)))
+}
+
+// Status::Io with the reason when the file cannot be read
+#[no_mangle]
+pub(crate) extern "C" fn load_pmc(path: *const c_char, out: *mut Handle) -> Status {
+    ffi_call(|| handle::write(out, NeuralNetwork::load(&args::path(path)?)?))
+}
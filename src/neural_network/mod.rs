@@ -1,7 +1,13 @@
+pub mod activation;
 pub mod create;
+pub mod initializer;
 pub mod nn_struct;
 pub mod delete;
+pub mod kernel;
 pub mod propagate;
+pub mod regularization;
 pub mod train;
 pub mod predict;
+pub mod manager;
+pub mod validation;
 mod tester;
\ No newline at end of file
@@ -1,11 +1,15 @@
 use bincode::{Decode, Encode};
+use super::activation::Activation;
 
-#[repr(C)]
 #[derive(Encode, Decode, PartialEq, Debug)]
 pub struct NeuralNetwork {
     pub num_layers: usize,
     pub neurons_per_layer: Vec<usize>,
-    pub weights: Vec<Vec<Vec<f32>>>,
+    // weights[layer] is row-major: neurons_per_layer[layer] rows of neurons_per_layer[layer - 1] inputs
+    pub weights: Vec<Vec<f32>>,
+    pub biases: Vec<Vec<f32>>,
+    pub hidden_activation: Activation,
+    // Training buffers, filled by propagate and train
     pub activations: Vec<Vec<f32>>,
     pub deltas: Vec<Vec<f32>>
 }
@@ -15,8 +19,69 @@ impl Clone for NeuralNetwork {
             num_layers: self.num_layers,
             neurons_per_layer: self.neurons_per_layer.clone(),
             weights: self.weights.clone(),
+            biases: self.biases.clone(),
+            hidden_activation: self.hidden_activation,
             activations: self.activations.clone(),
             deltas: self.deltas.clone(),
         }
     }
-}
\ No newline at end of file
+}
+
+impl NeuralNetwork {
+    pub fn input_size(&self) -> usize {
+        self.neurons_per_layer[0]
+    }
+
+    pub fn output_size(&self) -> usize {
+        self.neurons_per_layer[self.num_layers - 1]
+    }
+
+    pub fn max_layer_size(&self) -> usize {
+        self.neurons_per_layer.iter().copied().max().unwrap_or(0)
+    }
+}
+
+/// Preallocated ping-pong buffers used by `propagate::forward`, so a prediction never allocates.
+#[derive(Default, Debug)]
+pub struct ForwardScratch {
+    pub(crate) front: Vec<f32>,
+    pub(crate) back: Vec<f32>,
+}
+
+impl ForwardScratch {
+    pub fn new(model: &NeuralNetwork) -> Self {
+        let mut scratch = ForwardScratch::default();
+        scratch.reserve(model.max_layer_size());
+        scratch
+    }
+
+    // Only grows, so reusing a scratch across calls stays allocation-free
+    pub(crate) fn reserve(&mut self, size: usize) {
+        if self.front.len() < size {
+            self.front.resize(size, 0.0);
+            self.back.resize(size, 0.0);
+        }
+    }
+}
+
+/// Same as `ForwardScratch` for `propagate::propagate_batch`, one row per sample.
+#[derive(Default, Debug)]
+pub struct BatchScratch {
+    pub(crate) front: Vec<f32>,
+    pub(crate) back: Vec<f32>,
+}
+
+impl BatchScratch {
+    pub fn new(model: &NeuralNetwork, n_samples: usize) -> Self {
+        let mut scratch = BatchScratch::default();
+        scratch.reserve(model.max_layer_size() * n_samples);
+        scratch
+    }
+
+    pub(crate) fn reserve(&mut self, size: usize) {
+        if self.front.len() < size {
+            self.front.resize(size, 0.0);
+            self.back.resize(size, 0.0);
+        }
+    }
+}
@@ -1,24 +1,73 @@
-use crate::basic_function::tools::free_clone_neural_network_ptr;
-use super::nn_struct::NeuralNetwork;
-use super::propagate::propagate;
+use std::cell::RefCell;
+use super::nn_struct::{BatchScratch, ForwardScratch, NeuralNetwork};
+use super::propagate::{forward, propagate_batch};
+use crate::ffi::args;
+use crate::ffi::buffer::FloatBuffer;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
 
+thread_local! {
+    // One scratch per host thread, grown once to the widest layer seen
+    static SCRATCH: RefCell<ForwardScratch> = RefCell::new(ForwardScratch::default());
+    static BATCH_SCRATCH: RefCell<BatchScratch> = RefCell::new(BatchScratch::default());
+}
+
+pub(crate) fn with_scratch<R>(f: impl FnOnce(&mut ForwardScratch) -> R) -> R {
+    SCRATCH.with(|scratch| f(&mut scratch.borrow_mut()))
+}
 
 #[no_mangle]
-pub(crate) extern "C" fn predict(model: &mut NeuralNetwork, inputs: *const f32, inputs_len: i32, is_classification: bool) -> *mut [f32] {
-    let clone_model_box = Box::new(model.clone());
-    let clone_ptr = Box::into_raw(clone_model_box);
-    let clone_model = unsafe {&mut *clone_ptr};
+pub(crate) extern "C" fn predict(model: Handle, inputs: *const f32, inputs_len: i32, is_classification: bool, out: *mut FloatBuffer) -> Status {
+    ffi_call(|| handle::with(model, |model: &NeuralNetwork| {
+        let inputs_slice = args::slice(inputs, inputs_len, "inputs")?;
+        args::expect_len(inputs_slice.len(), model.input_size(), "inputs")?;
+
+        // Copy the output layer into a buffer released by the caller with free_float_buffer
+        let output = with_scratch(|scratch| forward(model, inputs_slice, is_classification, scratch).to_vec());
+        args::write(out, FloatBuffer::from_vec(output), "out")
+    }))
+}
 
-    propagate(clone_model, inputs, inputs_len, is_classification);
+#[no_mangle]
+pub(crate) extern "C" fn predict_into(model: Handle, inputs: *const f32, inputs_len: i32, output: *mut f32, output_len: i32, is_classification: bool) -> Status {
+    ffi_call(|| handle::with(model, |model: &NeuralNetwork| {
+        let inputs_slice = args::slice(inputs, inputs_len, "inputs")?;
+        let output_slice = args::slice_mut(output, output_len, "output")?;
+        args::expect_len(inputs_slice.len(), model.input_size(), "inputs")?;
+        args::expect_len(output_slice.len(), model.output_size(), "output")?;
 
-    // Clone the output layer activations (excluding bias)
-    let output_layer = &clone_model.activations[clone_model.num_layers - 1][1..];
-    let output_vec = output_layer.to_vec();
-    let output_box = output_vec.into_boxed_slice();
+        // Write straight into the caller buffer, no allocation
+        with_scratch(|scratch| {
+            let prediction = forward(model, inputs_slice, is_classification, scratch);
+            output_slice.copy_from_slice(prediction);
+        });
+        Ok(())
+    }))
+}
 
-    // Return the pointer to the cloned slice
-    let res = Box::into_raw(output_box);
+// Evaluate n_samples agents in one call, inputs is (n_samples x input_dim) and output (n_samples x output size)
+#[no_mangle]
+pub(crate) extern "C" fn predict_batch(
+    model: Handle,
+    inputs: *const f32,
+    n_samples: i32,
+    input_dim: i32,
+    output: *mut f32,
+    output_len: i32,
+    is_classification: bool
+) -> Status {
+    ffi_call(|| handle::with(model, |model: &NeuralNetwork| {
+        let samples = args::length(n_samples, "n_samples")?;
+        args::expect_len(args::length(input_dim, "input_dim")?, model.input_size(), "input_dim")?;
+        let inputs_slice = args::slice(inputs, n_samples.saturating_mul(input_dim), "inputs")?;
+        let output_slice = args::slice_mut(output, output_len, "output")?;
+        args::expect_len(output_slice.len(), samples * model.output_size(), "output")?;
 
-    free_clone_neural_network_ptr(clone_ptr);
-    res
-}
\ No newline at end of file
+        BATCH_SCRATCH.with(|scratch| {
+            let mut scratch = scratch.borrow_mut();
+            let prediction = propagate_batch(model, inputs_slice, samples, is_classification, &mut scratch);
+            output_slice.copy_from_slice(prediction);
+        });
+        Ok(())
+    }))
+}
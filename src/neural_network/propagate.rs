@@ -1,42 +1,130 @@
-use super::nn_struct::NeuralNetwork;
+use super::activation::Activation;
+use super::kernel::dense_batch;
+use super::nn_struct::{BatchScratch, ForwardScratch, NeuralNetwork};
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
 
-#[no_mangle]
-pub(crate) extern "C" fn propagate(model: &mut NeuralNetwork, inputs: *const f32, inputs_len: i32, is_classification: bool) {
-    // Convert arr to slice
-    let inputs_slice = unsafe { std::slice::from_raw_parts(inputs, inputs_len as usize) };
+// Hidden layers use `hidden_activation`, the output is tanh for classification and linear for regression
+#[inline]
+pub(crate) fn activate(total: f32, is_output: bool, is_classification: bool, hidden_activation: Activation) -> f32 {
+    if !is_output {
+        hidden_activation.resolve(is_classification).apply(total)
+    } else if is_classification {
+        total.tanh()
+    } else {
+        total
+    }
+}
+
+// Derivative of the activation, expressed from the activated value
+#[inline]
+pub(crate) fn activation_derivative(activation: f32, is_output: bool, is_classification: bool, hidden_activation: Activation) -> f32 {
+    if !is_output {
+        hidden_activation.resolve(is_classification).derivative(activation)
+    } else if is_classification {
+        1.0 - activation * activation
+    } else {
+        1.0
+    }
+}
 
-    // Fill inputs (skip the bias term at index 0)
-    for i in 0..model.neurons_per_layer[0] {
-        model.activations[0][i + 1] = inputs_slice[i];
+// outputs = activation(weights * inputs + biases), weights is row-major (outputs.len() x inputs.len())
+#[inline]
+pub(crate) fn layer_forward(weights: &[f32], biases: &[f32], inputs: &[f32], outputs: &mut [f32], is_output: bool, is_classification: bool, hidden_activation: Activation) {
+    for ((out, row), &bias) in outputs.iter_mut().zip(weights.chunks_exact(inputs.len())).zip(biases) {
+        let total = bias + row.iter().zip(inputs).map(|(w, x)| w * x).sum::<f32>();
+        *out = activate(total, is_output, is_classification, hidden_activation);
     }
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn propagate(model: Handle, inputs: *const f32, inputs_len: i32, is_classification: bool) -> Status {
+    ffi_call(|| handle::with_mut(model, |model: &mut NeuralNetwork| {
+        // Convert arr to slice
+        let inputs_slice = args::slice(inputs, inputs_len, "inputs")?;
+        args::expect_len(inputs_slice.len(), model.input_size(), "inputs")?;
+
+        propagate_train(model, inputs_slice, is_classification, &[]);
+        Ok(())
+    }))
+}
+
+// Training forward pass, keeps every layer for backpropagation. Non-empty `dropout_masks` are applied to hidden layers
+pub(crate) fn propagate_train(model: &mut NeuralNetwork, inputs: &[f32], is_classification: bool, dropout_masks: &[Vec<f32>]) {
+    // Fill inputs
+    let input_size = model.input_size();
+    model.activations[0].copy_from_slice(&inputs[..input_size]);
 
     // Update neuron output, layer after layer
     for layer in 1..model.num_layers {
-        for j in 1..=model.neurons_per_layer[layer] {
-            let mut total = 0.0;
-            for i in 0..=model.neurons_per_layer[layer - 1] {
-                total += model.weights[layer][i][j] * model.activations[layer - 1][i];
-            }
+        let is_output = layer == model.num_layers - 1;
+        let (previous, current) = model.activations.split_at_mut(layer);
+        layer_forward(
+            &model.weights[layer],
+            &model.biases[layer],
+            &previous[layer - 1],
+            &mut current[0],
+            is_output,
+            is_classification,
+            model.hidden_activation
+        );
 
-            if layer < model.num_layers - 1 { // Hidden layers
-                if is_classification {
-                    // Apply tanh activation to hidden layers
-                    total = total.tanh();
-                } else {
-                    // Apply ReLU activation to hidden layers
-                    total = total.max(0.0);
-                }
-            } else { // Output layer
-                if !is_classification {
-                    // Linear activation for output layer (no change)
-                    // This is already the case, so no need to do anything special here
-                } else {
-                    // Apply tanh activation to output layer
-                    total = total.tanh();
-                }
+        if !is_output && !dropout_masks.is_empty() {
+            for (activation, &mask) in current[0].iter_mut().zip(&dropout_masks[layer]) {
+                *activation *= mask;
             }
-
-            model.activations[layer][j] = total;
         }
     }
-}
\ No newline at end of file
+}
+
+/// Allocation-free forward pass: only the scratch buffers are written, the model is left untouched.
+pub fn forward<'a>(model: &NeuralNetwork, inputs: &[f32], is_classification: bool, scratch: &'a mut ForwardScratch) -> &'a [f32] {
+    scratch.reserve(model.max_layer_size());
+
+    let input_size = model.input_size();
+    scratch.front[..input_size].copy_from_slice(&inputs[..input_size]);
+
+    for layer in 1..model.num_layers {
+        let previous_size = model.neurons_per_layer[layer - 1];
+        let current_size = model.neurons_per_layer[layer];
+        layer_forward(
+            &model.weights[layer],
+            &model.biases[layer],
+            &scratch.front[..previous_size],
+            &mut scratch.back[..current_size],
+            layer == model.num_layers - 1,
+            is_classification,
+            model.hidden_activation
+        );
+        std::mem::swap(&mut scratch.front, &mut scratch.back);
+    }
+
+    &scratch.front[..model.output_size()]
+}
+
+/// Forward pass for `n_samples` rows of `inputs` (row-major, n_samples x input size), one matrix product per layer.
+pub fn propagate_batch<'a>(model: &NeuralNetwork, inputs: &[f32], n_samples: usize, is_classification: bool, scratch: &'a mut BatchScratch) -> &'a [f32] {
+    scratch.reserve(model.max_layer_size() * n_samples);
+
+    let input_len = model.input_size() * n_samples;
+    scratch.front[..input_len].copy_from_slice(&inputs[..input_len]);
+
+    for layer in 1..model.num_layers {
+        let previous_size = model.neurons_per_layer[layer - 1];
+        let current_size = model.neurons_per_layer[layer];
+        dense_batch(
+            &model.weights[layer],
+            &model.biases[layer],
+            &scratch.front[..previous_size * n_samples],
+            &mut scratch.back[..current_size * n_samples],
+            previous_size,
+            layer == model.num_layers - 1,
+            is_classification,
+            model.hidden_activation
+        );
+        std::mem::swap(&mut scratch.front, &mut scratch.back);
+    }
+
+    &scratch.front[..model.output_size() * n_samples]
+}
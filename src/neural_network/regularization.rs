@@ -0,0 +1,117 @@
+use bincode::{Decode, Encode};
+use rand::Rng;
+use crate::ffi::args::enum_code;
+use crate::ffi::error::FfiError;
+
+#[repr(C)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Debug)]
+pub enum GradientClipping {
+    None,
+    /// Clamp every gradient component to [-clip_value, clip_value]
+    Value,
+    /// Rescale the whole gradient when its global L2 norm exceeds clip_value
+    Norm,
+}
+
+enum_code!(GradientClipping { None, Value, Norm });
+
+/// Training-time regularization, passed to the `train` calls. All zero / `None` disables everything.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Debug)]
+pub struct Regularization {
+    /// L2 weight decay coefficient, biases are not decayed
+    pub l2: f32,
+    /// Probability to drop a hidden neuron (inverted dropout), only while training
+    pub dropout: f32,
+    pub clipping: GradientClipping,
+    pub clip_value: f32,
+}
+
+/// Regularization as passed to the exports, `clipping` is a `GradientClipping` code.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RegularizationParams {
+    /// L2 weight decay coefficient, biases are not decayed
+    pub l2: f32,
+    /// Probability to drop a hidden neuron (inverted dropout), only while training
+    pub dropout: f32,
+    pub clipping: i32,
+    pub clip_value: f32,
+}
+
+impl TryFrom<&RegularizationParams> for Regularization {
+    type Error = FfiError;
+
+    fn try_from(params: &RegularizationParams) -> Result<Regularization, FfiError> {
+        Ok(Regularization {
+            l2: params.l2,
+            dropout: params.dropout,
+            clipping: GradientClipping::try_from(params.clipping)?,
+            clip_value: params.clip_value,
+        })
+    }
+}
+
+impl From<Regularization> for RegularizationParams {
+    fn from(regularization: Regularization) -> RegularizationParams {
+        RegularizationParams {
+            l2: regularization.l2,
+            dropout: regularization.dropout,
+            clipping: regularization.clipping as i32,
+            clip_value: regularization.clip_value,
+        }
+    }
+}
+
+impl Default for Regularization {
+    fn default() -> Self {
+        Regularization {
+            l2: 0.0,
+            dropout: 0.0,
+            clipping: GradientClipping::None,
+            clip_value: 0.0,
+        }
+    }
+}
+
+impl Regularization {
+    // Kept neurons are scaled by 1 / (1 - p) so predict needs no rescaling
+    pub(crate) fn dropout_mask<R: Rng>(&self, size: usize, rng: &mut R) -> Vec<f32> {
+        let keep = 1.0 - self.dropout;
+        (0..size)
+            .map(|_| if rng.random::<f32>() < keep { 1.0 / keep } else { 0.0 })
+            .collect()
+    }
+
+    pub(crate) fn clip(&self, gradient: f32, norm_scale: f32) -> f32 {
+        match self.clipping {
+            GradientClipping::None => gradient,
+            GradientClipping::Value => gradient.clamp(-self.clip_value, self.clip_value),
+            GradientClipping::Norm => gradient * norm_scale,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn dropout_mask_is_inverted() {
+        let regularization = Regularization { dropout: 0.25, ..Default::default() };
+        let mut rng = StdRng::seed_from_u64(0);
+        let mask = regularization.dropout_mask(10000, &mut rng);
+        let dropped = mask.iter().filter(|&&m| m == 0.0).count() as f32 / mask.len() as f32;
+        assert!((dropped - 0.25).abs() < 0.02);
+        assert!(mask.iter().all(|&m| m == 0.0 || m == 1.0 / 0.75));
+    }
+
+    #[test]
+    fn clip_value() {
+        let regularization = Regularization { clipping: GradientClipping::Value, clip_value: 0.5, ..Default::default() };
+        assert_eq!(regularization.clip(2.0, 1.0), 0.5);
+        assert_eq!(regularization.clip(-2.0, 1.0), -0.5);
+        assert_eq!(regularization.clip(0.1, 1.0), 0.1);
+    }
+}
@@ -1,17 +1,31 @@
 #[cfg(test)]
 mod tests {
+    use std::ffi::CString;
+    use crate::basic_function::storage::{encode_model, ModelKind};
+    use crate::ffi::buffer::free_float_buffer;
+    use crate::ffi::error::Status;
+    use crate::ffi::handle;
+    use crate::ffi::test_support::{created, output, taken};
     use crate::neural_network::create::init;
-    use crate::neural_network::nn_struct::NeuralNetwork;
+    use crate::neural_network::initializer::WeightInit;
+    use crate::neural_network::manager::{load_pmc, save_pmc};
+    use crate::neural_network::nn_struct::{ForwardScratch, NeuralNetwork};
+    use crate::neural_network::predict::{predict, predict_batch, predict_into};
+    use crate::neural_network::propagate::{forward, propagate};
+    use crate::neural_network::regularization::{GradientClipping, Regularization};
+    use crate::neural_network::train::{fit, one_step_train_pmc, train_pmc_model, train_step, TrainConfig};
+    use crate::neural_network::validation::{evaluate, HISTORY_RECORD_SIZE};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     // TEST DATA
-    fn setup_model() -> Box<NeuralNetwork> {
+    fn setup_model() -> NeuralNetwork {
         // Init Data
         let slice = [10, 64, 64, 10];
         let ptr: *const i32 = slice.as_ptr();
         let len: i32 = slice.len() as i32;
 
-        let model = init(ptr, len);
-        model
+        taken(created(|out| init(ptr, len, WeightInit::Uniform as i32, 0.0, 42, out)))
     }
 
     #[test]
@@ -28,22 +42,16 @@ mod tests {
         // Weights[0]
         assert!(model.weights[0].is_empty());
         // Weights[1]
-        assert_eq!(model.weights[1].len(), 11);
-        for neuron_weights in &model.weights[1] {
-            assert_eq!(neuron_weights.len(), 65);
-            assert_eq!(neuron_weights[0], 0.0);
-            for &weight in &neuron_weights[1..] {
-                assert!(weight >= -1.0 && weight <= 1.0);
-            }
+        assert_eq!(model.weights[1].len(), 64 * 10);
+        assert_eq!(model.biases[1].len(), 64);
+        for &weight in &model.weights[1] {
+            assert!((-1.0..=1.0).contains(&weight));
         }
         // Weights[2]
-        assert_eq!(model.weights[2].len(), 65);
-        for neuron_weights in &model.weights[2] {
-            assert_eq!(neuron_weights.len(), 65);
-            assert_eq!(neuron_weights[0], 0.0);
-            for &weight in &neuron_weights[1..] {
-                assert!(weight >= -1.0 && weight <= 1.0);
-            }
+        assert_eq!(model.weights[2].len(), 64 * 64);
+        assert_eq!(model.biases[2].len(), 64);
+        for &weight in &model.weights[2] {
+            assert!((-1.0..=1.0).contains(&weight));
         }
     }
 
@@ -53,17 +61,11 @@ mod tests {
         // Len
         assert_eq!(model.activations.len(), 4);
         // First Layer
-        assert_eq!(model.activations[0], vec![1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(model.activations[0], vec![0.0; 10]);
         // Second Layer
-        assert_eq!(model.activations[1], vec![1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
-                                              0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
-                                              0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
-                                              0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
-                                              0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
-                                              0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
-                                              0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(model.activations[1], vec![0.0; 64]);
         // Third Layer
-        assert_eq!(model.activations[3], vec![1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(model.activations[3], vec![0.0; 10]);
     }
 
     #[test]
@@ -72,17 +74,286 @@ mod tests {
         // Len
         assert_eq!(model.deltas.len(), 4);
         // First Layer
-        assert_eq!(model.deltas[0], vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(model.deltas[0], vec![0.0; 10]);
         // Second Layer
-        assert_eq!(model.deltas[1], vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
-                                         0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
-                                         0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
-                                         0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
-                                         0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
-                                         0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
-                                         0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(model.deltas[1], vec![0.0; 64]);
         // Third Layer
-        assert_eq!(model.deltas[3], vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(model.deltas[3], vec![0.0; 10]);
+    }
+
+    #[test]
+    fn predict_hand_computed() {
+        let mut model = NeuralNetwork::new(&[2, 2, 1], WeightInit::Uniform, 0.0, 0);
+        model.weights[1] = vec![1.0, 2.0, -1.0, 0.5];
+        model.biases[1] = vec![0.5, 0.0];
+        model.weights[2] = vec![1.0, -2.0];
+        model.biases[2] = vec![0.25];
+
+        // hidden = relu([1 + 4 + 0.5, -1 + 1]) = [5.5, 0], output = 5.5 + 0.25
+        let model = handle::insert(model);
+        let inputs = [1.0f32, 2.0f32];
+        let mut result = [0.0f32];
+        assert_eq!(predict_into(model, inputs.as_ptr(), 2, result.as_mut_ptr(), 1, false), Status::Ok);
+        assert_eq!(result, [5.75]);
+        assert_eq!(predict_into(model, inputs.as_ptr(), 2, result.as_mut_ptr(), 0, false), Status::DimensionMismatch);
+
+        // tanh everywhere for classification
+        let expected = (5.5f32.tanh() - 2.0 * 0.0f32.tanh() + 0.25).tanh();
+        let res = output(|out| predict(model, inputs.as_ptr(), 2, true, out));
+        let prediction = unsafe { res.into_vec() };
+        assert!((prediction[0] - expected).abs() < 1e-6);
+        taken::<NeuralNetwork>(model);
+    }
+
+    #[test]
+    fn predict_keeps_model_untouched() {
+        let initial = setup_model();
+        let model = handle::insert(initial.clone());
+        let state: Vec<f32> = (0..10).map(|x| x as f32).collect();
+        let res = output(|out| predict(model, state.as_ptr(), state.len() as i32, false, out));
+        assert_eq!(free_float_buffer(res), Status::Ok);
+        assert_eq!(taken::<NeuralNetwork>(model), initial);
+    }
+
+    #[test]
+    fn forward_matches_propagate() {
+        let model = setup_model();
+        let state: Vec<f32> = (0..10).map(|x| x as f32 / 10.0).collect();
+        let mut scratch = ForwardScratch::new(&model);
+        let prediction = forward(&model, &state, true, &mut scratch).to_vec();
+
+        let model = handle::insert(model);
+        assert_eq!(propagate(model, state.as_ptr(), state.len() as i32, true), Status::Ok);
+        assert_eq!(prediction, taken::<NeuralNetwork>(model).activations[3]);
+    }
+
+    #[test]
+    fn predict_batch_matches_single() {
+        let model = NeuralNetwork::new(&[10, 64, 64, 10], WeightInit::HeNormal, 0.0, 5);
+        let batched = handle::insert(model.clone());
+        let n_samples = 37;
+        let inputs: Vec<f32> = (0..n_samples * 10).map(|x| ((x * 13) % 17) as f32 / 17.0 - 0.5).collect();
+
+        for is_classification in [false, true] {
+            let mut outputs = vec![0.0f32; n_samples * 10];
+            let status = predict_batch(batched, inputs.as_ptr(), n_samples as i32, 10, outputs.as_mut_ptr(), outputs.len() as i32, is_classification);
+            assert_eq!(status, Status::Ok);
+
+            let mut scratch = ForwardScratch::new(&model);
+            for (row, output) in inputs.chunks(10).zip(outputs.chunks(10)) {
+                let expected = forward(&model, row, is_classification, &mut scratch);
+                for (a, b) in expected.iter().zip(output) {
+                    assert!((a - b).abs() < 1e-4);
+                }
+            }
+        }
+        taken::<NeuralNetwork>(batched);
+    }
+
+    #[test]
+    fn train_xor() {
+        let model = handle::insert(NeuralNetwork::new(&[2, 4, 1], WeightInit::XavierUniform, 0.0, 3));
+        let inputs = [[0.0f32, 0.0], [0.0, 1.0], [1.0, 0.0], [1.0, 1.0]];
+        let outputs = [[-1.0f32], [1.0], [1.0], [-1.0]];
+
+        for _ in 0..5000 {
+            for (input, output) in inputs.iter().zip(outputs.iter()) {
+                assert_eq!(one_step_train_pmc(model, input.as_ptr(), 2, output.as_ptr(), 1, 0.05, true, None), Status::Ok);
+            }
+        }
+
+        let model = taken::<NeuralNetwork>(model);
+
+        let mut scratch = ForwardScratch::new(&model);
+        for (input, output) in inputs.iter().zip(outputs.iter()) {
+            let prediction = forward(&model, input, true, &mut scratch);
+            assert_eq!(prediction[0].signum(), output[0]);
+        }
+    }
+
+    #[test]
+    fn l2_decays_weights() {
+        let model = NeuralNetwork::new(&[2, 3, 1], WeightInit::XavierUniform, 0.0, 11);
+        let inputs = [0.5f32, -0.5f32];
+        let mut scratch = ForwardScratch::new(&model);
+        // Target equals the prediction, so only the decay moves the weights
+        let outputs = forward(&model, &inputs, false, &mut scratch).to_vec();
+        let initial = model.clone();
+
+        let regularization = Regularization { l2: 0.1, ..Default::default() }.into();
+        let model = handle::insert(model);
+        assert_eq!(one_step_train_pmc(model, inputs.as_ptr(), 2, outputs.as_ptr(), 1, 0.5, false, Some(&regularization)), Status::Ok);
+
+        let model = taken::<NeuralNetwork>(model);
+        for layer in 1..model.num_layers {
+            for (w, w0) in model.weights[layer].iter().zip(&initial.weights[layer]) {
+                assert!((w - w0 * 0.95).abs() < 1e-6);
+            }
+            assert_eq!(model.biases[layer], initial.biases[layer]);
+        }
+    }
+
+    #[test]
+    fn value_clipping_bounds_updates() {
+        let initial = NeuralNetwork::new(&[2, 3, 1], WeightInit::XavierUniform, 0.0, 12);
+        let model = handle::insert(initial.clone());
+        let inputs = [1.0f32, 2.0f32];
+        let outputs = [1000.0f32];
+
+        let regularization = Regularization { clipping: GradientClipping::Value, clip_value: 0.1, ..Default::default() }.into();
+        assert_eq!(one_step_train_pmc(model, inputs.as_ptr(), 2, outputs.as_ptr(), 1, 1.0, false, Some(&regularization)), Status::Ok);
+
+        let model = taken::<NeuralNetwork>(model);
+
+        for layer in 1..model.num_layers {
+            for (w, w0) in model.weights[layer].iter().zip(&initial.weights[layer]) {
+                assert!((w - w0).abs() <= 0.1 + 1e-6);
+            }
+        }
+        assert_ne!(model, initial);
+    }
+
+    #[test]
+    fn norm_clipping_bounds_updates() {
+        let initial = NeuralNetwork::new(&[2, 3, 1], WeightInit::XavierUniform, 0.0, 13);
+        let model = handle::insert(initial.clone());
+        let inputs = [1.0f32, 2.0f32];
+        let outputs = [1000.0f32];
+
+        let regularization = Regularization { clipping: GradientClipping::Norm, clip_value: 1.0, ..Default::default() }.into();
+        assert_eq!(one_step_train_pmc(model, inputs.as_ptr(), 2, outputs.as_ptr(), 1, 0.5, false, Some(&regularization)), Status::Ok);
+
+        let model = taken::<NeuralNetwork>(model);
+        let mut squared_norm = 0.0;
+        for layer in 1..model.num_layers {
+            let weights = model.weights[layer].iter().zip(&initial.weights[layer]);
+            let biases = model.biases[layer].iter().zip(&initial.biases[layer]);
+            squared_norm += weights.chain(biases).map(|(w, w0)| (w - w0).powi(2)).sum::<f32>();
+        }
+        assert!(squared_norm.sqrt() <= 0.5 + 1e-4);
+        assert!(squared_norm > 0.0);
+    }
+
+    #[test]
+    fn dropout_only_in_training() {
+        let mut model = NeuralNetwork::new(&[3, 16, 2], WeightInit::HeUniform, 0.0, 14);
+        let initial = model.clone();
+        let inputs = [1.0f32, -1.0f32, 0.5f32];
+        let outputs = [1.0f32, -1.0f32];
+        let regularization = Regularization { dropout: 0.5, ..Default::default() };
+
+        // Same seed as train_step to know which hidden neurons were dropped
+        let mask = regularization.dropout_mask(16, &mut StdRng::seed_from_u64(21));
+        assert!(mask.contains(&0.0));
+        train_step(&mut model, &inputs, &outputs, 0.1, false, &regularization, &mut StdRng::seed_from_u64(21));
+
+        for (j, &m) in mask.iter().enumerate() {
+            if m == 0.0 {
+                assert_eq!(model.weights[1][j * 3..(j + 1) * 3], initial.weights[1][j * 3..(j + 1) * 3]);
+                assert_eq!(model.weights[2][j], initial.weights[2][j]);
+            }
+        }
+
+        // predict never drops neurons
+        let mut scratch = ForwardScratch::new(&model);
+        let first = forward(&model, &inputs, false, &mut scratch).to_vec();
+        let second = forward(&model, &inputs, false, &mut scratch).to_vec();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn train_pmc_model_writes_history() {
+        let model = handle::insert(NeuralNetwork::new(&[2, 4, 1], WeightInit::XavierUniform, 0.0, 15));
+        let inputs = [0.0f32, 0.0, 0.0, 1.0, 1.0, 0.0, 1.0, 1.0];
+        let outputs = [-1.0f32, 1.0, 1.0, -1.0];
+        let mut history = vec![0.0f32; 20 * HISTORY_RECORD_SIZE];
+
+        let written = output(|written| train_pmc_model(
+            model,
+            inputs.as_ptr(), inputs.len() as i32, 2,
+            outputs.as_ptr(), outputs.len() as i32, 1,
+            inputs.as_ptr(), inputs.len() as i32, 2,
+            outputs.as_ptr(), outputs.len() as i32, 1,
+            0.05, 1000, true, None,
+            100, 0,
+            history.as_mut_ptr(), history.len() as i32,
+            written
+        ));
+
+        assert_eq!(written, 10);
+        for (i, record) in history.chunks(HISTORY_RECORD_SIZE).take(10).enumerate() {
+            assert_eq!(record[0], ((i + 1) * 100) as f32);
+            assert!(record[1] >= 0.0 && record[3] >= 0.0);
+            assert!((0.0..=1.0).contains(&record[2]) && (0.0..=1.0).contains(&record[4]));
+        }
+        assert!(history[10 * HISTORY_RECORD_SIZE..].iter().all(|&x| x == 0.0));
+        taken::<NeuralNetwork>(model);
+    }
+
+    #[test]
+    fn early_stopping_restores_best_weights() {
+        let mut model = NeuralNetwork::new(&[1, 4, 1], WeightInit::XavierUniform, 0.0, 16);
+        // Validation labels disagree with the training set, so validation only gets worse
+        let inputs = vec![vec![1.0f32]];
+        let outputs = vec![vec![1.0f32]];
+        let validations = vec![vec![1.0f32]];
+        let labels = vec![vec![-1.0f32]];
+        let config = TrainConfig {
+            learning_rate: 0.05,
+            iterations: 10000,
+            is_classification: true,
+            regularization: Regularization::default(),
+            eval_every: 10,
+            patience: 3,
+        };
+
+        let history = fit(&mut model, &inputs, &outputs, &validations, &labels, &config, &mut StdRng::seed_from_u64(0));
+
+        assert_eq!(history.len(), 4);
+        assert_eq!(history[3].iteration, 40.0);
+        let (validation_loss, _) = evaluate(&model, &validations, &labels, true);
+        assert_eq!(validation_loss, history[0].validation_loss);
+    }
+
+    #[test]
+    fn save_and_load_pmc() {
+        let model = setup_model();
+        let path = std::env::temp_dir().join("rustml_pmc_test.bin");
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+
+        let saved = handle::insert(model.clone());
+        assert_eq!(save_pmc(saved, c_path.as_ptr()), Status::Ok);
+        taken::<NeuralNetwork>(saved);
+        let loaded = created(|out| load_pmc(c_path.as_ptr(), out));
+        assert_eq!(taken::<NeuralNetwork>(loaded), model);
+
+        // Headerless files from older versions are rejected instead of panicking
+        let legacy = bincode::encode_to_vec(&model, bincode::config::standard()).unwrap();
+        std::fs::write(&path, legacy).unwrap();
+        assert_eq!(NeuralNetwork::load(path.to_str().unwrap()).unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+        let mut loaded = 0;
+        assert_eq!(load_pmc(c_path.as_ptr(), &mut loaded), Status::Io);
+        assert_eq!(loaded, 0);
+        std::fs::remove_file(path).unwrap();
+
+        let missing = CString::new("/nonexistent/rustml_model.bin").unwrap();
+        assert_eq!(load_pmc(missing.as_ptr(), &mut loaded), Status::Io);
+    }
+
+    #[test]
+    fn load_pmc_reads_version_1_files() {
+        let model = setup_model();
+        let path = std::env::temp_dir().join("rustml_pmc_v1_test.bin");
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+
+        // The NeuralNetwork payload is the same in versions 1 and 2, only the header field differs
+        let mut bytes = encode_model(&model, ModelKind::NeuralNetwork).unwrap();
+        bytes[4..6].copy_from_slice(&1u16.to_le_bytes());
+        std::fs::write(&path, bytes).unwrap();
+
+        let loaded = created(|out| load_pmc(c_path.as_ptr(), out));
+        assert_eq!(taken::<NeuralNetwork>(loaded), model);
+        std::fs::remove_file(path).unwrap();
     }
 
     /*
@@ -1,110 +1,167 @@
 use rand::Rng;
 use super::nn_struct::NeuralNetwork;
-use super::propagate::propagate;
-use crate::basic_function::tools::{recompose_2d_vec, recompose_vec};
+use super::propagate::{activation_derivative, propagate_train};
+use super::regularization::{GradientClipping, Regularization, RegularizationParams};
+use super::validation::{evaluate, is_correct, sample_loss, HistoryRecord, HISTORY_RECORD_SIZE};
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, FfiError, Status};
+use crate::ffi::handle::{self, Handle};
 
+/// Settings of `fit`. `eval_every == 0` disables validation, `patience == 0` disables early stopping.
+#[derive(Clone, Copy, Debug)]
+pub struct TrainConfig {
+    pub learning_rate: f32,
+    pub iterations: usize,
+    pub is_classification: bool,
+    pub regularization: Regularization,
+    pub eval_every: usize,
+    pub patience: usize,
+}
+
+/// Writes up to `history_capacity / 5` records `[iteration, train_loss, train_accuracy, val_loss, val_accuracy]`
+/// in `history_ptr` and how many were written in `written`.
 #[no_mangle]
-pub (crate) extern "C" fn train_pmc_model(model: &mut NeuralNetwork,
+pub (crate) extern "C" fn train_pmc_model(model: Handle,
                               inputs_ptr: *const f32, input_length: i32, inputs_sub: i32,
                               output_ptr: *const f32, output_length: i32, output_sub: i32,
                               valid_ptr: *const f32, valid_length: i32, valid_sub: i32,
                               label_ptr: *const f32, label_length: i32, label_sub: i32,
-                              learning_rate: f32, iteration: i32, is_classification: bool) {
-    // Recompose Vec<Vec<f32>>
-    let inputs =  recompose_2d_vec(inputs_ptr, input_length, inputs_sub);
-    let outputs = recompose_2d_vec(output_ptr, output_length, output_sub);
-    let validations = recompose_2d_vec(valid_ptr, valid_length, valid_sub);
-    let labels = recompose_2d_vec(label_ptr, label_length, label_sub);
-    let vals = (validations, labels);
+                              learning_rate: f32, iteration: i32, is_classification: bool,
+                              regularization: Option<&RegularizationParams>,
+                              eval_every: i32, patience: i32,
+                              history_ptr: *mut f32, history_capacity: i32,
+                              written: *mut i32) -> Status {
+    ffi_call(|| handle::with_mut(model, |model: &mut NeuralNetwork| {
+        // Recompose Vec<Vec<f32>>
+        let inputs = args::rows(inputs_ptr, input_length, inputs_sub, "inputs")?;
+        let outputs = args::rows(output_ptr, output_length, output_sub, "outputs")?;
+        let validations = args::rows(valid_ptr, valid_length, valid_sub, "validations")?;
+        let labels = args::rows(label_ptr, label_length, label_sub, "labels")?;
+        args::check_samples(&inputs, &outputs, model.input_size(), model.output_size())?;
+        args::check_samples(&validations, &labels, model.input_size(), model.output_size())?;
+
+        let config = TrainConfig {
+            learning_rate,
+            iterations: args::length(iteration, "iteration")?,
+            is_classification,
+            regularization: regularization.map(Regularization::try_from).transpose()?.unwrap_or_default(),
+            eval_every: args::length(eval_every, "eval_every")?,
+            patience: args::length(patience, "patience")?,
+        };
+        if config.iterations > 0 && inputs.is_empty() {
+            return Err(FfiError::invalid("no training samples"));
+        }
+        let history_slice = args::slice_mut(history_ptr, history_capacity, "history")?;
+
+        let history = fit(model, &inputs, &outputs, &validations, &labels, &config, &mut rand::rng());
+
+        // Copy as many records as the host buffer holds
+        let mut count = 0;
+        for (record, chunk) in history.iter().zip(history_slice.chunks_exact_mut(HISTORY_RECORD_SIZE)) {
+            chunk.copy_from_slice(&record.to_array());
+            count += 1;
+        }
+        args::write(written, count, "written")
+    }))
+}
+
+/// Stochastic training on random samples. Every `eval_every` iterations the validation set is evaluated,
+/// training stops after `patience` evaluations without improvement and the best weights are restored.
+pub fn fit<R: Rng>(
+    model: &mut NeuralNetwork,
+    inputs: &[Vec<f32>],
+    outputs: &[Vec<f32>],
+    validations: &[Vec<f32>],
+    labels: &[Vec<f32>],
+    config: &TrainConfig,
+    rng: &mut R,
+) -> Vec<HistoryRecord> {
+    let validate = config.eval_every > 0 && !validations.is_empty();
+    let mut history = Vec::new();
+
+    let mut best_loss = f32::INFINITY;
+    let mut best_weights = (model.weights.clone(), model.biases.clone());
+    let mut evaluations_without_improvement = 0;
 
-    let mut rng = rand::rng();
+    // Running training metrics since the last evaluation
+    let mut train_loss = 0.0;
+    let mut train_correct = 0;
+    let mut train_count = 0;
 
-    for _ in 0..iteration as usize {
+    for it in 0..config.iterations {
         // pick random data in the dataset
         let rand = rng.random_range(0..inputs.len());
-        let rand_input = inputs[rand].clone();
-        let rand_output = outputs[rand].clone();
+        let rand_input = &inputs[rand];
+        let rand_output = &outputs[rand];
 
-        let rand_val  =  rng.random_range(0..vals.0.len());
-        let _val_rand_inputs = vals.0[rand_val].clone();
-        let _val_rand_outputs = vals.1[rand_val].clone();
+        // Set neurons inputs with the random dataset and update the weights
+        train_step(model, rand_input, rand_output, config.learning_rate, config.is_classification, &config.regularization, rng);
 
-        let input_slice = rand_input.as_slice();
-
-        // Set neurons inputs with the random dataset
-        propagate(model, input_slice.as_ptr(), rand_input.len() as i32, is_classification);
+        if !validate {
+            continue;
+        }
 
-        // Calc semi gradient last layer
-        for j in 1..=model.neurons_per_layer[model.num_layers] {
-            model.deltas[model.num_layers][j] = model.activations[model.num_layers][j] - rand_output[j - 1];
-            if is_classification {
-                model.deltas[model.num_layers][j] *= 1.0 - model.activations[model.num_layers][j].powf(2.0);
-            }
+        // Output layer still holds the prediction made before the update
+        let prediction = &model.activations[model.num_layers - 1];
+        train_loss += sample_loss(prediction, rand_output);
+        if config.is_classification && is_correct(prediction, rand_output) {
+            train_correct += 1;
         }
+        train_count += 1;
 
-        // Calc other layer
-        for layer in (1..=model.num_layers).rev() {
-            for i in 1..= model.neurons_per_layer[layer] {
-                let mut total: f32 = 0.0;
-                for j in 1..=model.neurons_per_layer[layer] {
-                    total += model.weights[layer][i][j] * model.deltas[layer][j];
-                }
-                model.deltas[layer - 1][i] = (1.0 - model.activations[layer - 1][i].powf(2.0)) * total;
-            }
+        if (it + 1) % config.eval_every != 0 && it + 1 != config.iterations {
+            continue;
         }
 
-        // Update Weights
-        for layer in 1..=model.num_layers {
-            for i in 0..=model.neurons_per_layer[layer -1]{
-                for j in 1..=model.neurons_per_layer[layer] {
-                    model.weights[layer][i][j] -= learning_rate * model.activations[layer - 1][i] * model.deltas[layer][j];
-                }
+        let (validation_loss, validation_accuracy) = evaluate(model, validations, labels, config.is_classification);
+        history.push(HistoryRecord {
+            iteration: (it + 1) as f32,
+            train_loss: train_loss / train_count as f32,
+            train_accuracy: if config.is_classification { train_correct as f32 / train_count as f32 } else { f32::NAN },
+            validation_loss,
+            validation_accuracy,
+        });
+        train_loss = 0.0;
+        train_correct = 0;
+        train_count = 0;
+
+        if validation_loss < best_loss {
+            best_loss = validation_loss;
+            best_weights = (model.weights.clone(), model.biases.clone());
+            evaluations_without_improvement = 0;
+        } else {
+            evaluations_without_improvement += 1;
+            if config.patience > 0 && evaluations_without_improvement >= config.patience {
+                break;
             }
         }
+    }
 
+    if validate && best_loss.is_finite() {
+        (model.weights, model.biases) = best_weights;
     }
+
+    history
 }
 
 #[no_mangle]
 pub (crate) extern "C" fn one_step_train_pmc(
-    model: &mut NeuralNetwork,
+    model: Handle,
     inputs_ptr: *const f32, input_length: i32,
     output_ptr: *const f32, output_length: i32,
-    learning_rate: f32, is_classification: bool)
+    learning_rate: f32, is_classification: bool,
+    regularization: Option<&RegularizationParams>) -> Status
 {
-    // Recompose Vec<f32>
-    let outputs = recompose_vec(output_ptr, output_length);
-
-    // Set neurons inputs
-    propagate(model, inputs_ptr, input_length, is_classification);
-
-    // Calc semi gradient last layer
-    for j in 1..=model.neurons_per_layer[model.num_layers] {
-        model.deltas[model.num_layers][j] = model.activations[model.num_layers][j] - outputs[j - 1];
-        if is_classification {
-            model.deltas[model.num_layers][j] *= 1.0 - model.activations[model.num_layers][j].powf(2.0);
-        }
-    }
-
-    // Calc other layer
-    for layer in (1..=model.num_layers).rev() {
-        for i in 1..= model.neurons_per_layer[layer] {
-            let mut total: f32 = 0.0;
-            for j in 1..=model.neurons_per_layer[layer] {
-                total += model.weights[layer][i][j] * model.deltas[layer][j];
-            }
-            model.deltas[layer - 1][i] = (1.0 - model.activations[layer - 1][i].powf(2.0)) * total;
-        }
-    }
+    ffi_call(|| handle::with_mut(model, |model: &mut NeuralNetwork| {
+        let inputs = args::slice(inputs_ptr, input_length, "inputs")?;
+        let outputs = args::slice(output_ptr, output_length, "outputs")?;
+        args::expect_len(inputs.len(), model.input_size(), "inputs")?;
+        args::expect_len(outputs.len(), model.output_size(), "outputs")?;
+        let regularization = regularization.map(Regularization::try_from).transpose()?.unwrap_or_default();
 
-    // Update Weights
-    for layer in 1..=model.num_layers {
-        for i in 0..=model.neurons_per_layer[layer -1]{
-            for j in 1..=model.neurons_per_layer[layer] {
-                model.weights[layer][i][j] -= learning_rate * model.activations[layer - 1][i] * model.deltas[layer][j];
-            }
-        }
-    }
+        train_step(model, inputs, outputs, learning_rate, is_classification, &regularization, &mut rand::rng());
+        Ok(())
+    }))
 }
 
 pub(crate) extern "C" fn one_step_train_pmc2(
@@ -115,51 +172,110 @@ pub(crate) extern "C" fn one_step_train_pmc2(
     output_length: i32,
     learning_rate: f32,
     is_classification: bool,
+    regularization: &Regularization,
+) {
+    // Borrow inputs and expected outputs
+    let inputs = unsafe { std::slice::from_raw_parts(inputs_ptr, input_length as usize) };
+    let outputs = unsafe { std::slice::from_raw_parts(output_ptr, output_length as usize) };
+
+    train_step(model, inputs, outputs, learning_rate, is_classification, regularization, &mut rand::rng());
+}
+
+// Forward pass with dropout then one gradient step
+pub(crate) fn train_step<R: Rng>(
+    model: &mut NeuralNetwork,
+    inputs: &[f32],
+    outputs: &[f32],
+    learning_rate: f32,
+    is_classification: bool,
+    regularization: &Regularization,
+    rng: &mut R,
 ) {
-    // Recompose Vec<f32> for outputs
-    let outputs = unsafe { std::slice::from_raw_parts(output_ptr, output_length as usize).to_vec() };
-    let num_layers_with_no_input = model.num_layers - 1;
+    // Dropout masks for hidden layers only
+    let dropout_masks: Vec<Vec<f32>> = if regularization.dropout > 0.0 {
+        (0..model.num_layers - 1)
+            .map(|layer| if layer == 0 { Vec::new() } else { regularization.dropout_mask(model.neurons_per_layer[layer], rng) })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    propagate_train(model, inputs, is_classification, &dropout_masks);
 
-    // Set neurons inputs and propagate
-    propagate(model, inputs_ptr, input_length, is_classification);
+    backpropagate(model, outputs, learning_rate, is_classification, regularization, &dropout_masks);
+}
+
+// Gradient step on the activations left by `propagate_train`
+pub(crate) fn backpropagate(
+    model: &mut NeuralNetwork,
+    outputs: &[f32],
+    learning_rate: f32,
+    is_classification: bool,
+    regularization: &Regularization,
+    dropout_masks: &[Vec<f32>],
+) {
+    let last_layer = model.num_layers - 1;
+    let hidden_activation = model.hidden_activation;
 
     // Calculate semi-gradient for the last layer (output layer)
-    for j in 1..model.neurons_per_layer[num_layers_with_no_input] {
-        let activation = model.activations[num_layers_with_no_input][j];
-        let error = activation - outputs[j - 1]; // Zero-based index for outputs
-        if is_classification {
-            // Derivative of tanh: 1 - tanh^2(x)
-            model.deltas[num_layers_with_no_input][j] = error * (1.0 - activation.powf(2.0));
-        } else {
-            // For regression, use the error directly
-            model.deltas[num_layers_with_no_input][j] = error;
-        }
+    for ((delta, &activation), &output) in model.deltas[last_layer].iter_mut().zip(&model.activations[last_layer]).zip(outputs) {
+        *delta = (activation - output) * activation_derivative(activation, true, is_classification, model.hidden_activation);
     }
 
     // Calculate deltas for other layers
-    for layer in (1..num_layers_with_no_input).rev() {
-        for i in 1..=model.neurons_per_layer[layer] {
+    for layer in (1..last_layer).rev() {
+        let next_size = model.neurons_per_layer[layer + 1];
+        let current_size = model.neurons_per_layer[layer];
+        let (current, next) = model.deltas.split_at_mut(layer + 1);
+        let next_weights = &model.weights[layer + 1];
+
+        for i in 0..current_size {
             let mut total: f32 = 0.0;
-            for j in 1..=model.neurons_per_layer[layer + 1] {
-                total += model.weights[layer + 1][i][j] * model.deltas[layer + 1][j];
+            for j in 0..next_size {
+                total += next_weights[j * current_size + i] * next[0][j];
             }
             let activation = model.activations[layer][i];
-            if is_classification {
-                // Derivative of tanh: 1 - tanh^2(x)
-                model.deltas[layer][i] = total * (1.0 - activation.powf(2.0));
+            current[layer][i] = if dropout_masks.is_empty() {
+                total * activation_derivative(activation, false, is_classification, hidden_activation)
             } else {
-                // For ReLU, derivative is 1 if activation > 0, otherwise 0
-                model.deltas[layer][i] = total * if activation > 0.0 { 1.0 } else { 0.0 };
-            }
+                // Dropped neurons get no gradient, kept ones are unscaled before the derivative
+                let mask = dropout_masks[layer][i];
+                if mask > 0.0 {
+                    total * mask * activation_derivative(activation / mask, false, is_classification, hidden_activation)
+                } else {
+                    0.0
+                }
+            };
         }
     }
 
-    // Update weights
-    for layer in 1..num_layers_with_no_input {
-        for i in 0..=model.neurons_per_layer[layer - 1] {
-            for j in 1..=model.neurons_per_layer[layer] {
-                model.weights[layer][i][j] -= learning_rate * model.activations[layer - 1][i] * model.deltas[layer][j];
+    // Global gradient norm, |delta x activation|^2 = |delta|^2 * |activation|^2 plus the bias part
+    let norm_scale = if regularization.clipping == GradientClipping::Norm {
+        let squared_norm: f32 = (1..model.num_layers)
+            .map(|layer| {
+                let deltas: f32 = model.deltas[layer].iter().map(|d| d * d).sum();
+                let inputs: f32 = model.activations[layer - 1].iter().map(|a| a * a).sum();
+                deltas * (inputs + 1.0)
+            })
+            .sum();
+        let norm = squared_norm.sqrt();
+        if norm > regularization.clip_value { regularization.clip_value / norm } else { 1.0 }
+    } else {
+        1.0
+    };
+
+    // Update weights and biases
+    for layer in 1..model.num_layers {
+        let previous_size = model.neurons_per_layer[layer - 1];
+        let inputs = &model.activations[layer - 1];
+        let deltas = &model.deltas[layer];
+
+        for (j, row) in model.weights[layer].chunks_exact_mut(previous_size).enumerate() {
+            for (weight, &input) in row.iter_mut().zip(inputs) {
+                let gradient = regularization.clip(deltas[j] * input, norm_scale) + regularization.l2 * *weight;
+                *weight -= learning_rate * gradient;
             }
+            model.biases[layer][j] -= learning_rate * regularization.clip(deltas[j], norm_scale);
         }
     }
-}
\ No newline at end of file
+}
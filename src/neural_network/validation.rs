@@ -0,0 +1,75 @@
+use super::nn_struct::{ForwardScratch, NeuralNetwork};
+use super::propagate::forward;
+use crate::basic_function::tools::argmax;
+
+/// One evaluation point of `train::fit`, written to the host as 5 consecutive f32.
+/// Accuracies are NaN for regression.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HistoryRecord {
+    pub iteration: f32,
+    pub train_loss: f32,
+    pub train_accuracy: f32,
+    pub validation_loss: f32,
+    pub validation_accuracy: f32,
+}
+
+pub const HISTORY_RECORD_SIZE: usize = 5;
+
+impl HistoryRecord {
+    pub fn to_array(&self) -> [f32; HISTORY_RECORD_SIZE] {
+        [self.iteration, self.train_loss, self.train_accuracy, self.validation_loss, self.validation_accuracy]
+    }
+}
+
+// Mean squared error over the outputs of one sample
+pub(crate) fn sample_loss(prediction: &[f32], expected: &[f32]) -> f32 {
+    let total: f32 = prediction.iter().zip(expected).map(|(p, e)| (p - e).powi(2)).sum();
+    total / expected.len() as f32
+}
+
+// Sign for a single tanh output, argmax for one-hot outputs
+pub(crate) fn is_correct(prediction: &[f32], expected: &[f32]) -> bool {
+    if expected.len() == 1 {
+        (prediction[0] >= 0.0) == (expected[0] >= 0.0)
+    } else {
+        argmax(prediction) == argmax(expected)
+    }
+}
+
+/// Mean loss and accuracy of the model on a labelled set.
+pub fn evaluate(model: &NeuralNetwork, inputs: &[Vec<f32>], labels: &[Vec<f32>], is_classification: bool) -> (f32, f32) {
+    let mut scratch = ForwardScratch::new(model);
+    let mut loss = 0.0;
+    let mut correct = 0;
+
+    for (input, label) in inputs.iter().zip(labels) {
+        let prediction = forward(model, input, is_classification, &mut scratch);
+        loss += sample_loss(prediction, label);
+        if is_classification && is_correct(prediction, label) {
+            correct += 1;
+        }
+    }
+
+    let count = inputs.len() as f32;
+    let accuracy = if is_classification { correct as f32 / count } else { f32::NAN };
+    (loss / count, accuracy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accuracy_rules() {
+        assert!(is_correct(&[0.3], &[1.0]));
+        assert!(!is_correct(&[-0.3], &[1.0]));
+        assert!(is_correct(&[0.1, 0.7, -0.2], &[-1.0, 1.0, -1.0]));
+        assert!(!is_correct(&[0.9, 0.7, -0.2], &[-1.0, 1.0, -1.0]));
+    }
+
+    #[test]
+    fn loss_is_mean_squared_error() {
+        assert_eq!(sample_loss(&[1.0, 2.0], &[0.0, 0.0]), 2.5);
+    }
+}
@@ -0,0 +1,22 @@
+use super::encoder::OneHotEncoder;
+use super::scaler::Scaler;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
+
+#[no_mangle]
+extern "C" fn delete_scaler(scaler: Handle) -> Status {
+    ffi_call(|| {
+        handle::remove::<Scaler>(scaler)?;
+        println!("Scaler deleted");
+        Ok(())
+    })
+}
+
+#[no_mangle]
+extern "C" fn delete_one_hot_encoder(encoder: Handle) -> Status {
+    ffi_call(|| {
+        handle::remove::<OneHotEncoder>(encoder)?;
+        println!("Encoder deleted");
+        Ok(())
+    })
+}
@@ -0,0 +1,77 @@
+use bincode::{Decode, Encode};
+
+/// Maps text labels to class ids 0..n, classes sorted by name.
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Default)]
+pub struct LabelEncoder {
+    pub classes: Vec<String>,
+}
+
+impl LabelEncoder {
+    pub fn fit(labels: &[&str]) -> LabelEncoder {
+        let mut classes: Vec<String> = labels.iter().map(|label| label.to_string()).collect();
+        classes.sort();
+        classes.dedup();
+        LabelEncoder { classes }
+    }
+
+    /// None for a label not seen by `fit`.
+    pub fn transform(&self, label: &str) -> Option<usize> {
+        self.classes.binary_search_by(|class| class.as_str().cmp(label)).ok()
+    }
+
+    pub fn inverse_transform(&self, class: usize) -> Option<&str> {
+        self.classes.get(class).map(|class| class.as_str())
+    }
+}
+
+/// Replaces categorical columns (numeric codes) by one 0 / 1 column per category seen by `fit`,
+/// the other columns are copied in order. An unknown category encodes as all zeros.
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Default)]
+pub struct OneHotEncoder {
+    pub input_size: usize,
+    pub columns: Vec<usize>,
+    // Sorted categories of each encoded column, same order as columns
+    pub categories: Vec<Vec<f32>>,
+}
+
+impl OneHotEncoder {
+    pub fn fit(rows: &[Vec<f32>], columns: &[usize]) -> OneHotEncoder {
+        let categories = columns.iter()
+            .map(|&column| {
+                let mut values: Vec<f32> = rows.iter().map(|row| row[column]).collect();
+                values.sort_by(f32::total_cmp);
+                values.dedup();
+                values
+            })
+            .collect();
+        OneHotEncoder {
+            input_size: rows.first().map_or(0, |row| row.len()),
+            columns: columns.to_vec(),
+            categories,
+        }
+    }
+
+    pub fn output_size(&self) -> usize {
+        self.input_size - self.columns.len() + self.categories.iter().map(|c| c.len()).sum::<usize>()
+    }
+
+    pub fn transform_into(&self, row: &[f32], output: &mut Vec<f32>) {
+        output.clear();
+        for (column, &value) in row.iter().enumerate() {
+            match self.columns.iter().position(|&c| c == column) {
+                Some(encoded) => output.extend(self.categories[encoded].iter().map(|&category| if category == value { 1.0 } else { 0.0 })),
+                None => output.push(value),
+            }
+        }
+    }
+
+    pub fn transform(&self, rows: &[Vec<f32>]) -> Vec<Vec<f32>> {
+        rows.iter()
+            .map(|row| {
+                let mut output = Vec::with_capacity(self.output_size());
+                self.transform_into(row, &mut output);
+                output
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,61 @@
+use std::ffi::c_char;
+use std::io;
+use super::encoder::{LabelEncoder, OneHotEncoder};
+use super::scaler::Scaler;
+use crate::basic_function::storage::{load_model_file, save_model_file, ModelKind};
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
+
+// Fitted transformers are written next to the model file, e.g. model.bin and model.scaler.bin
+impl Scaler {
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        save_model_file(self, ModelKind::Scaler, path)
+    }
+
+    pub fn load(path: &str) -> io::Result<Scaler> {
+        load_model_file(ModelKind::Scaler, path)
+    }
+}
+
+impl OneHotEncoder {
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        save_model_file(self, ModelKind::OneHotEncoder, path)
+    }
+
+    pub fn load(path: &str) -> io::Result<OneHotEncoder> {
+        load_model_file(ModelKind::OneHotEncoder, path)
+    }
+}
+
+impl LabelEncoder {
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        save_model_file(self, ModelKind::LabelEncoder, path)
+    }
+
+    pub fn load(path: &str) -> io::Result<LabelEncoder> {
+        load_model_file(ModelKind::LabelEncoder, path)
+    }
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn save_scaler(scaler: Handle, path: *const c_char) -> Status {
+    ffi_call(|| handle::with(scaler, |scaler: &Scaler| Ok(scaler.save(&args::path(path)?)?)))
+}
+
+// Status::Io with the reason when the file cannot be read
+#[no_mangle]
+pub(crate) extern "C" fn load_scaler(path: *const c_char, out: *mut Handle) -> Status {
+    ffi_call(|| handle::write(out, Scaler::load(&args::path(path)?)?))
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn save_one_hot_encoder(encoder: Handle, path: *const c_char) -> Status {
+    ffi_call(|| handle::with(encoder, |encoder: &OneHotEncoder| Ok(encoder.save(&args::path(path)?)?)))
+}
+
+// Status::Io with the reason when the file cannot be read
+#[no_mangle]
+pub(crate) extern "C" fn load_one_hot_encoder(path: *const c_char, out: *mut Handle) -> Status {
+    ffi_call(|| handle::write(out, OneHotEncoder::load(&args::path(path)?)?))
+}
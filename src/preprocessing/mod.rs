@@ -0,0 +1,7 @@
+pub mod scaler;
+pub mod encoder;
+pub mod split;
+pub mod transform;
+pub mod delete;
+pub mod manager;
+mod tester;
@@ -0,0 +1,85 @@
+use bincode::{Decode, Encode};
+use crate::ffi::args::enum_code;
+
+#[repr(C)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Debug)]
+pub enum ScalerKind {
+    /// (x - min) / (max - min), features in [0, 1]
+    MinMax,
+    /// (x - mean) / std
+    Standard,
+    /// (x - median) / IQR, insensitive to outliers
+    Robust,
+}
+
+enum_code!(ScalerKind { MinMax, Standard, Robust });
+
+/// Per-feature affine transform x' = (x - offset) / scale, fitted on the training set only.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub struct Scaler {
+    pub kind: ScalerKind,
+    pub offsets: Vec<f32>,
+    pub scales: Vec<f32>,
+}
+
+impl Scaler {
+    /// A constant feature gets a scale of 1 so it maps to 0 instead of NaN.
+    pub fn fit(kind: ScalerKind, rows: &[Vec<f32>]) -> Scaler {
+        let feature_size = rows.first().map_or(0, |row| row.len());
+        let mut offsets = Vec::with_capacity(feature_size);
+        let mut scales = Vec::with_capacity(feature_size);
+
+        for feature in 0..feature_size {
+            let mut column: Vec<f32> = rows.iter().map(|row| row[feature]).collect();
+            let (offset, scale) = match kind {
+                ScalerKind::MinMax => {
+                    let min = column.iter().copied().fold(f32::INFINITY, f32::min);
+                    let max = column.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+                    (min, max - min)
+                }
+                ScalerKind::Standard => {
+                    let mean = column.iter().sum::<f32>() / column.len() as f32;
+                    let variance = column.iter().map(|x| (x - mean).powi(2)).sum::<f32>() / column.len() as f32;
+                    (mean, variance.sqrt())
+                }
+                ScalerKind::Robust => {
+                    column.sort_by(f32::total_cmp);
+                    (quantile(&column, 0.5), quantile(&column, 0.75) - quantile(&column, 0.25))
+                }
+            };
+            offsets.push(offset);
+            scales.push(if scale.abs() > f32::EPSILON { scale } else { 1.0 });
+        }
+
+        Scaler { kind, offsets, scales }
+    }
+
+    pub fn transform_in_place(&self, row: &mut [f32]) {
+        for ((x, offset), scale) in row.iter_mut().zip(&self.offsets).zip(&self.scales) {
+            *x = (*x - offset) / scale;
+        }
+    }
+
+    pub fn inverse_transform_in_place(&self, row: &mut [f32]) {
+        for ((x, offset), scale) in row.iter_mut().zip(&self.offsets).zip(&self.scales) {
+            *x = *x * scale + offset;
+        }
+    }
+
+    pub fn transform(&self, rows: &[Vec<f32>]) -> Vec<Vec<f32>> {
+        rows.iter()
+            .map(|row| {
+                let mut row = row.clone();
+                self.transform_in_place(&mut row);
+                row
+            })
+            .collect()
+    }
+}
+
+// Linear interpolation between the closest ranks of a sorted column
+fn quantile(sorted: &[f32], q: f32) -> f32 {
+    let position = q * (sorted.len() - 1) as f32;
+    let (low, high) = (position.floor() as usize, position.ceil() as usize);
+    sorted[low] + (sorted[high] - sorted[low]) * (position - low as f32)
+}
@@ -0,0 +1,120 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use crate::basic_function::tools::argmax;
+use crate::ffi::error::{FfiError, FfiResult};
+
+/// Class of each label row: sign for a single output, argmax for one-hot rows.
+pub fn class_ids(labels: &[Vec<f32>]) -> Vec<usize> {
+    labels.iter()
+        .map(|label| if label.len() == 1 { (label[0] > 0.0) as usize } else { argmax(label) as usize })
+        .collect()
+}
+
+/// Sample indices of each part, in shuffled order.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Split {
+    pub train: Vec<usize>,
+    pub validation: Vec<usize>,
+    pub test: Vec<usize>,
+}
+
+/// Shuffled split, with `stratify` every class keeps its proportion in each part.
+/// Both ratios lie in [0, 1] and add up to at most 1.
+pub fn train_validation_test_split(n_samples: usize, validation_ratio: f32, test_ratio: f32,
+                                   stratify: Option<&[usize]>, seed: u64) -> FfiResult<Split> {
+    for (name, ratio) in [("validation", validation_ratio), ("test", test_ratio)] {
+        if !(0.0..=1.0).contains(&ratio) {
+            return Err(FfiError::invalid(format!("{} ratio {} is outside [0, 1]", name, ratio)));
+        }
+    }
+    if validation_ratio + test_ratio > 1.0 {
+        return Err(FfiError::invalid(format!("validation and test ratios add up to {}, above 1", validation_ratio + test_ratio)));
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut split = Split::default();
+
+    for mut group in groups(n_samples, stratify) {
+        group.shuffle(&mut rng);
+        let test_size = (group.len() as f32 * test_ratio).round() as usize;
+        let validation_size = ((group.len() as f32 * validation_ratio).round() as usize).min(group.len() - test_size);
+        split.test.extend_from_slice(&group[..test_size]);
+        split.validation.extend_from_slice(&group[test_size..test_size + validation_size]);
+        split.train.extend_from_slice(&group[test_size + validation_size..]);
+    }
+
+    // Mix the classes back together
+    split.train.shuffle(&mut rng);
+    split.validation.shuffle(&mut rng);
+    split.test.shuffle(&mut rng);
+    Ok(split)
+}
+
+/// Iterator over the k (train, validation) index pairs of k-fold cross-validation,
+/// with 2 <= k <= n_samples.
+pub struct KFold {
+    folds: Vec<Vec<usize>>,
+    current: usize,
+}
+
+impl KFold {
+    pub fn new(n_samples: usize, k: usize, seed: u64) -> FfiResult<KFold> {
+        KFold::build(n_samples, k, None, seed)
+    }
+
+    /// Every fold gets the same class proportions as the whole set.
+    pub fn stratified(classes: &[usize], k: usize, seed: u64) -> FfiResult<KFold> {
+        KFold::build(classes.len(), k, Some(classes), seed)
+    }
+
+    fn build(n_samples: usize, k: usize, stratify: Option<&[usize]>, seed: u64) -> FfiResult<KFold> {
+        if k < 2 {
+            return Err(FfiError::invalid(format!("k-fold needs at least 2 folds ({})", k)));
+        }
+        if k > n_samples {
+            return Err(FfiError::invalid(format!("{} folds for {} samples", k, n_samples)));
+        }
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut folds = vec![Vec::new(); k];
+
+        // Deal each class round-robin, continuing where the previous class stopped
+        let mut next = 0;
+        for mut group in groups(n_samples, stratify) {
+            group.shuffle(&mut rng);
+            for index in group {
+                folds[next % k].push(index);
+                next += 1;
+            }
+        }
+        Ok(KFold { folds, current: 0 })
+    }
+}
+
+impl Iterator for KFold {
+    type Item = (Vec<usize>, Vec<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let validation = self.folds.get(self.current)?.clone();
+        let train = self.folds.iter().enumerate()
+            .filter(|&(fold, _)| fold != self.current)
+            .flat_map(|(_, indices)| indices.iter().copied())
+            .collect();
+        self.current += 1;
+        Some((train, validation))
+    }
+}
+
+// Indices per class, a single group without stratification
+fn groups(n_samples: usize, stratify: Option<&[usize]>) -> Vec<Vec<usize>> {
+    match stratify {
+        None => vec![(0..n_samples).collect()],
+        Some(classes) => {
+            let mut groups = vec![Vec::new(); classes.iter().max().map_or(0, |&max| max + 1)];
+            for (index, &class) in classes.iter().enumerate() {
+                groups[class].push(index);
+            }
+            groups
+        }
+    }
+}
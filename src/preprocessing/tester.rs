@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use crate::dataset::csv::CsvOptions;
+    use crate::dataset::dataset_struct::Dataset;
+    use crate::ffi::error::Status;
+    use crate::ffi::handle;
+    use crate::ffi::test_support::{created, output, taken};
+    use crate::preprocessing::encoder::{LabelEncoder, OneHotEncoder};
+    use crate::preprocessing::manager::{load_one_hot_encoder, load_scaler, save_one_hot_encoder, save_scaler};
+    use crate::preprocessing::scaler::{Scaler, ScalerKind};
+    use crate::preprocessing::split::{class_ids, train_validation_test_split, KFold};
+    use crate::preprocessing::transform::{fit_one_hot_encoder, fit_scaler, one_hot_encoder_output_size, one_hot_encoder_transform, scaler_inverse_transform, scaler_transform};
+
+    #[test]
+    fn min_max_scaler() {
+        let rows = vec![vec![1.0, 10.0, 5.0], vec![3.0, 10.0, 5.0], vec![2.0, 30.0, 5.0]];
+        let scaler = Scaler::fit(ScalerKind::MinMax, &rows);
+        assert_eq!(scaler.offsets, vec![1.0, 10.0, 5.0]);
+        // Constant feature keeps a scale of 1
+        assert_eq!(scaler.scales, vec![2.0, 20.0, 1.0]);
+        assert_eq!(scaler.transform(&[vec![2.0, 20.0, 5.0]]), vec![vec![0.5, 0.5, 0.0]]);
+    }
+
+    #[test]
+    fn standard_scaler() {
+        let scaler = Scaler::fit(ScalerKind::Standard, &[vec![1.0], vec![3.0]]);
+        assert_eq!((scaler.offsets[0], scaler.scales[0]), (2.0, 1.0));
+        assert_eq!(scaler.transform(&[vec![1.0], vec![4.0]]), vec![vec![-1.0], vec![2.0]]);
+    }
+
+    #[test]
+    fn robust_scaler_ignores_outlier() {
+        let rows: Vec<Vec<f32>> = [1.0, 2.0, 3.0, 4.0, 100.0].iter().map(|&x| vec![x]).collect();
+        let scaler = Scaler::fit(ScalerKind::Robust, &rows);
+        // median 3, quartiles 2 and 4
+        assert_eq!((scaler.offsets[0], scaler.scales[0]), (3.0, 2.0));
+    }
+
+    #[test]
+    fn scaler_ffi_round_trip() {
+        let data = vec![1.0, 10.0, 3.0, 20.0, 5.0, 60.0];
+        let scaler = created(|out| fit_scaler(ScalerKind::Standard as i32, data.as_ptr(), data.len() as i32, 2, out));
+        let mut scaled = data.clone();
+        assert_eq!(scaler_transform(scaler, scaled.as_mut_ptr(), scaled.len() as i32), Status::Ok);
+        assert!((scaled[0] + scaled[2] + scaled[4]).abs() < 1e-5);
+        assert_eq!(scaler_transform(scaler, scaled.as_mut_ptr(), 5), Status::DimensionMismatch);
+
+        assert_eq!(scaler_inverse_transform(scaler, scaled.as_mut_ptr(), scaled.len() as i32), Status::Ok);
+        for (a, b) in scaled.iter().zip(&data) {
+            assert!((a - b).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn label_encoder() {
+        let encoder = LabelEncoder::fit(&["cat", "dog", "cat", "bird"]);
+        assert_eq!(encoder.classes, vec!["bird", "cat", "dog"]);
+        assert_eq!(encoder.transform("dog"), Some(2));
+        assert_eq!(encoder.transform("fish"), None);
+        assert_eq!(encoder.inverse_transform(1), Some("cat"));
+    }
+
+    #[test]
+    fn one_hot_encoder() {
+        let rows = vec![vec![0.5, 2.0, 7.0], vec![1.0, 1.0, 7.0], vec![0.0, 2.0, 8.0]];
+        let encoder = OneHotEncoder::fit(&rows, &[1, 2]);
+        assert_eq!(encoder.categories, vec![vec![1.0, 2.0], vec![7.0, 8.0]]);
+        assert_eq!(encoder.output_size(), 5);
+        assert_eq!(encoder.transform(&rows[..1]), vec![vec![0.5, 0.0, 1.0, 1.0, 0.0]]);
+
+        // Unknown category encodes as zeros
+        assert_eq!(encoder.transform(&[vec![0.0, 3.0, 8.0]]), vec![vec![0.0, 0.0, 0.0, 0.0, 1.0]]);
+    }
+
+    #[test]
+    fn one_hot_encoder_ffi() {
+        let data = vec![0.5, 2.0, 1.0, 1.0];
+        let columns = [1];
+        let encoder = created(|out| fit_one_hot_encoder(data.as_ptr(), data.len() as i32, 2, columns.as_ptr(), 1, out));
+        assert_eq!(output(|size| one_hot_encoder_output_size(encoder, size)), 3);
+
+        let mut encoded = [0.0; 3];
+        let written = output(|written| one_hot_encoder_transform(encoder, [4.0f32, 2.0].as_ptr(), 2, encoded.as_mut_ptr(), 3, written));
+        assert_eq!(written, 3);
+        assert_eq!(encoded, [4.0, 0.0, 1.0]);
+
+        let mut invalid = 0;
+        assert_eq!(fit_one_hot_encoder(data.as_ptr(), data.len() as i32, 2, [2].as_ptr(), 1, &mut invalid), Status::InvalidArgument);
+        assert_eq!(invalid, 0);
+    }
+
+    #[test]
+    fn stratified_split_keeps_proportions() {
+        let classes: Vec<usize> = (0..100).map(|i| (i % 10 < 3) as usize).collect();
+        let split = train_validation_test_split(100, 0.2, 0.1, Some(&classes), 42).unwrap();
+
+        assert_eq!((split.train.len(), split.validation.len(), split.test.len()), (70, 20, 10));
+        let positives = |indices: &[usize]| indices.iter().filter(|&&i| classes[i] == 1).count();
+        assert_eq!(positives(&split.train), 21);
+        assert_eq!(positives(&split.validation), 6);
+        assert_eq!(positives(&split.test), 3);
+
+        // Every sample lands in exactly one part
+        let mut all: Vec<usize> = [split.train.clone(), split.validation.clone(), split.test.clone()].concat();
+        all.sort();
+        assert_eq!(all, (0..100).collect::<Vec<usize>>());
+
+        assert_eq!(split, train_validation_test_split(100, 0.2, 0.1, Some(&classes), 42).unwrap());
+        assert_ne!(split, train_validation_test_split(100, 0.2, 0.1, Some(&classes), 43).unwrap());
+    }
+
+    #[test]
+    fn split_rejects_invalid_ratios() {
+        for (validation, test) in [(0.2, 1.5), (-0.1, 0.2), (0.6, 0.5), (f32::NAN, 0.1)] {
+            assert!(train_validation_test_split(10, validation, test, None, 0).is_err());
+        }
+        let split = train_validation_test_split(10, 0.0, 1.0, None, 0).unwrap();
+        assert_eq!((split.train.len(), split.validation.len(), split.test.len()), (0, 0, 10));
+    }
+
+    #[test]
+    fn k_fold_covers_every_sample_once() {
+        let folds: Vec<(Vec<usize>, Vec<usize>)> = KFold::new(10, 3, 0).unwrap().collect();
+        assert_eq!(folds.len(), 3);
+        assert_eq!(folds.iter().map(|(_, validation)| validation.len()).collect::<Vec<usize>>(), vec![4, 3, 3]);
+
+        let mut validations: Vec<usize> = folds.iter().flat_map(|(_, validation)| validation.clone()).collect();
+        validations.sort();
+        assert_eq!(validations, (0..10).collect::<Vec<usize>>());
+        for (train, validation) in &folds {
+            assert_eq!(train.len() + validation.len(), 10);
+            assert!(train.iter().all(|i| !validation.contains(i)));
+        }
+    }
+
+    #[test]
+    fn k_fold_rejects_invalid_k() {
+        assert_eq!(KFold::new(10, 1, 0).err().unwrap().message, "k-fold needs at least 2 folds (1)");
+        assert_eq!(KFold::new(3, 4, 0).err().unwrap().message, "4 folds for 3 samples");
+        assert!(KFold::stratified(&[0, 1], 3, 0).is_err());
+    }
+
+    #[test]
+    fn stratified_k_fold() {
+        let labels: Vec<Vec<f32>> = (0..20).map(|i| vec![if i < 10 { 1.0 } else { -1.0 }]).collect();
+        let classes = class_ids(&labels);
+        for (_, validation) in KFold::stratified(&classes, 5, 1).unwrap() {
+            assert_eq!(validation.iter().filter(|&&i| classes[i] == 1).count(), 2);
+        }
+    }
+
+    #[test]
+    fn split_dataset() {
+        let text = "x,label\n0,a\n1,b\n2,a\n3,b\n";
+        let dataset = Dataset::parse_csv(text, &CsvOptions::default()).unwrap();
+        let part = dataset.subset(&[3, 0]);
+        assert_eq!(part.n_samples, 2);
+        assert_eq!(part.features, vec![3.0, 0.0]);
+        assert_eq!(part.label_rows(), vec![vec![-1.0, 1.0], vec![1.0, -1.0]]);
+    }
+
+    #[test]
+    fn save_and_load_next_to_model() {
+        let scaler = Scaler::fit(ScalerKind::MinMax, &[vec![0.0, 1.0], vec![4.0, 3.0]]);
+        let encoder = OneHotEncoder::fit(&[vec![1.0], vec![2.0]], &[0]);
+        let dir = std::env::temp_dir();
+        let scaler_path = CString::new(dir.join("rustml_model.scaler.bin").to_str().unwrap()).unwrap();
+        let encoder_path = CString::new(dir.join("rustml_model.encoder.bin").to_str().unwrap()).unwrap();
+
+        let saved_scaler = handle::insert(scaler.clone());
+        let saved_encoder = handle::insert(encoder.clone());
+        assert_eq!(save_scaler(saved_scaler, scaler_path.as_ptr()), Status::Ok);
+        assert_eq!(save_one_hot_encoder(saved_encoder, encoder_path.as_ptr()), Status::Ok);
+        let loaded_scaler = created(|out| load_scaler(scaler_path.as_ptr(), out));
+        let loaded_encoder = created(|out| load_one_hot_encoder(encoder_path.as_ptr(), out));
+        assert_eq!(taken::<Scaler>(loaded_scaler), taken::<Scaler>(saved_scaler));
+        assert_eq!(taken::<OneHotEncoder>(loaded_encoder), taken::<OneHotEncoder>(saved_encoder));
+        std::fs::remove_file(dir.join("rustml_model.scaler.bin")).unwrap();
+        std::fs::remove_file(dir.join("rustml_model.encoder.bin")).unwrap();
+
+        let missing = CString::new("/nonexistent/rustml_model.scaler.bin").unwrap();
+        let mut loaded = 0;
+        assert_eq!(load_scaler(missing.as_ptr(), &mut loaded), Status::Io);
+        assert_eq!(loaded, 0);
+    }
+}
@@ -0,0 +1,87 @@
+use super::encoder::OneHotEncoder;
+use super::scaler::{Scaler, ScalerKind};
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, FfiError, FfiResult, Status};
+use crate::ffi::handle::{self, Handle};
+
+#[no_mangle]
+pub(crate) extern "C" fn fit_scaler(kind: i32, inputs_ptr: *const f32, input_length: i32, inputs_sub: i32, out: *mut Handle) -> Status {
+    ffi_call(|| {
+        let kind = ScalerKind::try_from(kind)?;
+        let inputs = args::rows(inputs_ptr, input_length, inputs_sub, "inputs")?;
+        if inputs.is_empty() {
+            return Err(FfiError::invalid("no samples to fit on"));
+        }
+        handle::write(out, Scaler::fit(kind, &inputs))
+    })
+}
+
+// Scale data_len floats in place, rows of the fitted feature size
+#[no_mangle]
+pub(crate) extern "C" fn scaler_transform(scaler: Handle, data: *mut f32, data_len: i32) -> Status {
+    ffi_call(|| handle::with(scaler, |scaler: &Scaler| {
+        let data_slice = scaler_rows(scaler, data, data_len)?;
+        for row in data_slice.chunks_mut(scaler.offsets.len()) {
+            scaler.transform_in_place(row);
+        }
+        Ok(())
+    }))
+}
+
+// Back to the original units, e.g. for regression predictions of a scaled target
+#[no_mangle]
+pub(crate) extern "C" fn scaler_inverse_transform(scaler: Handle, data: *mut f32, data_len: i32) -> Status {
+    ffi_call(|| handle::with(scaler, |scaler: &Scaler| {
+        let data_slice = scaler_rows(scaler, data, data_len)?;
+        for row in data_slice.chunks_mut(scaler.offsets.len()) {
+            scaler.inverse_transform_in_place(row);
+        }
+        Ok(())
+    }))
+}
+
+// Data made of whole rows of the fitted feature size
+fn scaler_rows<'a>(scaler: &Scaler, data: *mut f32, data_len: i32) -> FfiResult<&'a mut [f32]> {
+    let data_slice = args::slice_mut(data, data_len, "data")?;
+    if data_slice.len() % scaler.offsets.len().max(1) != 0 {
+        return Err(FfiError::dimension(format!("data length {} is not a multiple of {} features", data_slice.len(), scaler.offsets.len())));
+    }
+    Ok(data_slice)
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn fit_one_hot_encoder(inputs_ptr: *const f32, input_length: i32, inputs_sub: i32,
+                                             columns_ptr: *const i32, columns_len: i32, out: *mut Handle) -> Status {
+    ffi_call(|| {
+        let inputs = args::rows(inputs_ptr, input_length, inputs_sub, "inputs")?;
+        if inputs.is_empty() {
+            return Err(FfiError::invalid("no samples to fit on"));
+        }
+        let columns = args::slice(columns_ptr, columns_len, "columns")?.iter()
+            .map(|&c| match usize::try_from(c) {
+                Ok(column) if column < inputs[0].len() => Ok(column),
+                _ => Err(FfiError::invalid(format!("column {} is out of the {} features", c, inputs[0].len()))),
+            })
+            .collect::<FfiResult<Vec<usize>>>()?;
+        handle::write(out, OneHotEncoder::fit(&inputs, &columns))
+    })
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn one_hot_encoder_output_size(encoder: Handle, size: *mut i32) -> Status {
+    ffi_call(|| handle::with(encoder, |encoder: &OneHotEncoder| args::write(size, encoder.output_size() as i32, "size")))
+}
+
+// Encode one row, `written` is the number of floats written
+#[no_mangle]
+pub(crate) extern "C" fn one_hot_encoder_transform(encoder: Handle, inputs: *const f32, inputs_len: i32,
+                                                   output: *mut f32, output_len: i32, written: *mut i32) -> Status {
+    ffi_call(|| handle::with(encoder, |encoder: &OneHotEncoder| {
+        let inputs_slice = args::slice(inputs, inputs_len, "inputs")?;
+        args::expect_len(inputs_slice.len(), encoder.input_size, "inputs")?;
+
+        let mut encoded = Vec::with_capacity(encoder.output_size());
+        encoder.transform_into(inputs_slice, &mut encoded);
+        args::copy_out(&encoded, output, output_len, written)
+    }))
+}
@@ -0,0 +1,85 @@
+use numpy::{AllowTypeChange, PyArrayLike1};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use super::{check, values};
+use crate::deep_reinforce_learning::deep_q_learning::create::init_dql;
+use crate::deep_reinforce_learning::deep_q_learning::dql_struct::DeepQLearning;
+use crate::deep_reinforce_learning::deep_q_learning::manager::{deserialize_model, save_model};
+use crate::deep_reinforce_learning::deep_q_learning::predict::choose_action;
+use crate::deep_reinforce_learning::deep_q_learning::train::{learn_dql, update_epsilon};
+use crate::ffi::buffer::ByteBuffer;
+use crate::ffi::handle::{self, Handle};
+
+/// Deep Q-learning agent, a network maps a state to the Q value of each action.
+#[pyclass(name = "DeepQLearning", module = "rustml")]
+pub struct PyDeepQLearning {
+    handle: Handle,
+}
+
+impl PyDeepQLearning {
+    fn action_size(&self) -> PyResult<i32> {
+        Ok(handle::with(self.handle, |model: &DeepQLearning| Ok(model.action_size))?)
+    }
+}
+
+#[pymethods]
+impl PyDeepQLearning {
+    /// `seed` drives the network weights and the exploration.
+    #[new]
+    #[pyo3(signature = (state_dim, action_dim, learning_rate, gamma, epsilon, epsilon_min, epsilon_decay, seed = 0))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(state_dim: i32, action_dim: i32, learning_rate: f32, gamma: f32,
+           epsilon: f32, epsilon_min: f32, epsilon_decay: f32, seed: u64) -> PyResult<PyDeepQLearning> {
+        let mut handle = 0;
+        check(init_dql(state_dim, action_dim, learning_rate, gamma, epsilon, epsilon_min, epsilon_decay, seed, &mut handle))?;
+        Ok(PyDeepQLearning { handle })
+    }
+
+    /// Model saved by `save`.
+    #[staticmethod]
+    fn load(bytes: &[u8]) -> PyResult<PyDeepQLearning> {
+        let mut handle = 0;
+        check(deserialize_model(bytes.as_ptr(), bytes.len(), &mut handle))?;
+        Ok(PyDeepQLearning { handle })
+    }
+
+    fn save<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let mut bytes = ByteBuffer::default();
+        check(save_model(self.handle, &mut bytes))?;
+        Ok(PyBytes::new(py, &unsafe { bytes.into_vec() }))
+    }
+
+    #[getter]
+    fn epsilon(&self) -> PyResult<f32> {
+        Ok(handle::with(self.handle, |model: &DeepQLearning| Ok(model.epsilon))?)
+    }
+
+    /// Epsilon-greedy action among `available_actions`.
+    fn choose_action(&self, state: PyArrayLike1<'_, f32, AllowTypeChange>, available_actions: Vec<i32>) -> PyResult<i32> {
+        let state = state.as_array();
+        let state = values(&state);
+        let mut action = 0;
+        check(choose_action(self.handle, state.as_ptr(), state.len() as i32, self.action_size()?,
+                            available_actions.as_ptr(), available_actions.len() as i32, &mut action))?;
+        Ok(action)
+    }
+
+    /// One Q-learning update, `next_state` is ignored when `done`.
+    fn learn(&self, state: PyArrayLike1<'_, f32, AllowTypeChange>, action: i32, reward: i32,
+             next_state: PyArrayLike1<'_, f32, AllowTypeChange>, done: bool) -> PyResult<()> {
+        let (state, next_state) = (state.as_array(), next_state.as_array());
+        let (state, next_state) = (values(&state), values(&next_state));
+        check(learn_dql(self.handle, state.as_ptr(), state.len() as i32, action, reward,
+                        next_state.as_ptr(), next_state.len() as i32, self.action_size()?, done))
+    }
+
+    fn update_epsilon(&self) -> PyResult<()> {
+        check(update_epsilon(self.handle))
+    }
+}
+
+impl Drop for PyDeepQLearning {
+    fn drop(&mut self) {
+        handle::remove::<DeepQLearning>(self.handle).ok();
+    }
+}
@@ -0,0 +1,121 @@
+use numpy::PyArray1;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use super::check;
+use crate::environment::create::{env_create, BuiltinEnvironment, EnvironmentKind, EnvironmentParams};
+use crate::environment::play::{env_encode_state, env_render_ascii, env_reset, env_step};
+use crate::ffi::buffer::{ByteBuffer, FloatBuffer};
+use crate::ffi::handle::{self, Handle};
+
+/// Built-in environment, `kind` is line_world, grid_world, rock_paper_scissors, monty_hall
+/// or secret_env0 to secret_env3. A kind ignores the sizes it does not use, `seed` drives the random resets
+/// and a positive `max_episode_steps` truncates the episodes.
+#[pyclass(name = "Environment", module = "rustml")]
+pub struct PyEnvironment {
+    pub(super) handle: Handle,
+}
+
+fn kind(name: &str) -> PyResult<EnvironmentKind> {
+    match name {
+        "line_world" => Ok(EnvironmentKind::LineWorld),
+        "grid_world" => Ok(EnvironmentKind::GridWorld),
+        "rock_paper_scissors" => Ok(EnvironmentKind::RockPaperScissors),
+        "monty_hall" => Ok(EnvironmentKind::MontyHall),
+        "secret_env0" => Ok(EnvironmentKind::SecretEnv0),
+        "secret_env1" => Ok(EnvironmentKind::SecretEnv1),
+        "secret_env2" => Ok(EnvironmentKind::SecretEnv2),
+        "secret_env3" => Ok(EnvironmentKind::SecretEnv3),
+        _ => Err(PyValueError::new_err(format!("unknown environment '{}'", name))),
+    }
+}
+
+impl PyEnvironment {
+    fn with<R>(&self, body: impl FnOnce(&mut BuiltinEnvironment) -> R) -> PyResult<R> {
+        Ok(handle::with_mut(self.handle, |env: &mut BuiltinEnvironment| Ok(body(env)))?)
+    }
+}
+
+#[pymethods]
+impl PyEnvironment {
+    #[new]
+    #[pyo3(signature = (kind, rows = 0, cols = 0, start = 0, doors = 0, seed = 0, max_episode_steps = 0))]
+    fn new(kind: &str, rows: i32, cols: i32, start: i32, doors: i32, seed: u64, max_episode_steps: i32) -> PyResult<PyEnvironment> {
+        let params = EnvironmentParams { rows, cols, start, doors, seed, max_episode_steps };
+        let mut handle = 0;
+        check(env_create(self::kind(kind)? as i32, params, &mut handle))?;
+        Ok(PyEnvironment { handle })
+    }
+
+    #[getter]
+    fn num_states(&self) -> PyResult<usize> {
+        self.with(|env| env.0.all_states().len())
+    }
+
+    #[getter]
+    fn num_actions(&self) -> PyResult<usize> {
+        self.with(|env| env.0.all_action().len())
+    }
+
+    #[getter]
+    fn state(&self) -> PyResult<usize> {
+        self.with(|env| env.0.state_id())
+    }
+
+    #[getter]
+    fn score(&self) -> PyResult<f32> {
+        self.with(|env| env.0.score())
+    }
+
+    #[getter]
+    fn is_game_over(&self) -> PyResult<bool> {
+        self.with(|env| env.0.is_game_over())
+    }
+
+    /// The last done step came from `max_episode_steps`, not from a terminal state.
+    #[getter]
+    fn truncated(&self) -> PyResult<bool> {
+        self.with(|env| env.0.truncated())
+    }
+
+    /// Starts an episode and returns its first state.
+    fn reset(&self) -> PyResult<i32> {
+        let mut state = 0;
+        check(env_reset(self.handle, &mut state))?;
+        Ok(state)
+    }
+
+    /// Plays `action` and returns `(next_state, reward, done)`.
+    fn step(&self, action: i32) -> PyResult<(i32, f32, bool)> {
+        let (mut next_state, mut reward, mut done) = (0, 0.0, false);
+        check(env_step(self.handle, action, &mut next_state, &mut reward, &mut done))?;
+        Ok((next_state, reward, done))
+    }
+
+    fn available_actions<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyArray1<i32>>> {
+        let actions = self.with(|env| env.0.available_actions())?;
+        Ok(PyArray1::from_iter(py, actions.into_iter().map(|action| action as i32)))
+    }
+
+    /// One-hot encoding of the current state.
+    fn encode_state<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyArray1<f32>>> {
+        let mut encoded = FloatBuffer::default();
+        check(env_encode_state(self.handle, &mut encoded))?;
+        Ok(PyArray1::from_vec(py, unsafe { encoded.into_vec() }))
+    }
+
+    fn render(&self) -> PyResult<String> {
+        let mut text = ByteBuffer::default();
+        check(env_render_ascii(self.handle, &mut text))?;
+        Ok(String::from_utf8_lossy(&unsafe { text.into_vec() }).into_owned())
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        self.render()
+    }
+}
+
+impl Drop for PyEnvironment {
+    fn drop(&mut self) {
+        handle::remove::<BuiltinEnvironment>(self.handle).ok();
+    }
+}
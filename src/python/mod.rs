@@ -0,0 +1,49 @@
+// Python module built with maturin and the `python` feature. Each class owns a handle and
+// calls the exports of the C API, a failed call raises with the rustml_last_error message.
+use std::borrow::Cow;
+use std::ffi::CStr;
+use numpy::ndarray::{ArrayView, Dimension};
+use pyo3::exceptions::{PyOSError, PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use crate::ffi::error::{rustml_last_error, FfiError, Status};
+
+mod deep_q_learning;
+mod environment;
+mod neural_network;
+mod tabular;
+mod tester;
+
+impl From<FfiError> for PyErr {
+    fn from(error: FfiError) -> PyErr {
+        match error.status {
+            Status::Io => PyOSError::new_err(error.message),
+            Status::Panic | Status::InvalidHandle => PyRuntimeError::new_err(error.message),
+            _ => PyValueError::new_err(error.message),
+        }
+    }
+}
+
+fn check(status: Status) -> PyResult<()> {
+    if status == Status::Ok {
+        return Ok(());
+    }
+    let message = unsafe { CStr::from_ptr(rustml_last_error()) }.to_string_lossy().into_owned();
+    Err(FfiError::new(status, message).into())
+}
+
+// numpy views may be strided, the exports take row-major values
+fn values<'a, T: Clone, D: Dimension>(array: &'a ArrayView<'_, T, D>) -> Cow<'a, [T]> {
+    match array.as_slice() {
+        Some(slice) => Cow::Borrowed(slice),
+        None => Cow::Owned(array.iter().cloned().collect()),
+    }
+}
+
+#[pymodule]
+fn rustml(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<neural_network::PyNeuralNetwork>()?;
+    module.add_class::<deep_q_learning::PyDeepQLearning>()?;
+    module.add_class::<tabular::PyTabularAgent>()?;
+    module.add_class::<environment::PyEnvironment>()?;
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use std::ffi::CString;
+use numpy::{AllowTypeChange, PyArray1, PyArray2, PyArrayLike1, PyArrayLike2, PyArrayMethods};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use super::{check, values};
+use crate::ffi::buffer::FloatBuffer;
+use crate::ffi::handle::{self, Handle};
+use crate::neural_network::activation::Activation;
+use crate::neural_network::create::{init, set_hidden_activation};
+use crate::neural_network::initializer::WeightInit;
+use crate::neural_network::manager::{load_pmc, save_pmc};
+use crate::neural_network::nn_struct::NeuralNetwork;
+use crate::neural_network::predict::{predict, predict_batch};
+use crate::neural_network::train::train_pmc_model;
+use crate::neural_network::validation::HISTORY_RECORD_SIZE;
+
+/// Multilayer perceptron, `layers` holds the neurons of each layer from the inputs to the outputs.
+#[pyclass(name = "NeuralNetwork", module = "rustml")]
+pub struct PyNeuralNetwork {
+    handle: Handle,
+}
+
+fn weight_init(name: &str) -> PyResult<WeightInit> {
+    match name {
+        "uniform" => Ok(WeightInit::Uniform),
+        "xavier_uniform" => Ok(WeightInit::XavierUniform),
+        "xavier_normal" => Ok(WeightInit::XavierNormal),
+        "he_uniform" => Ok(WeightInit::HeUniform),
+        "he_normal" => Ok(WeightInit::HeNormal),
+        _ => Err(PyValueError::new_err(format!(
+            "unknown weight_init '{}', expected uniform, xavier_uniform, xavier_normal, he_uniform or he_normal", name))),
+    }
+}
+
+fn activation(name: &str) -> PyResult<Activation> {
+    match name {
+        "auto" => Ok(Activation::Auto),
+        "tanh" => Ok(Activation::Tanh),
+        "relu" => Ok(Activation::Relu),
+        "sigmoid" => Ok(Activation::Sigmoid),
+        _ => Err(PyValueError::new_err(format!("unknown activation '{}', expected auto, tanh, relu or sigmoid", name))),
+    }
+}
+
+fn path(path: &str) -> PyResult<CString> {
+    CString::new(path).map_err(|_| PyValueError::new_err("path contains a nul byte"))
+}
+
+#[pymethods]
+impl PyNeuralNetwork {
+    #[new]
+    #[pyo3(signature = (layers, weight_init = "uniform", bias = 0.0, seed = 0, hidden_activation = "auto"))]
+    fn new(layers: Vec<i32>, weight_init: &str, bias: f32, seed: u64, hidden_activation: &str) -> PyResult<PyNeuralNetwork> {
+        let (weight_init, hidden_activation) = (self::weight_init(weight_init)?, activation(hidden_activation)?);
+        let mut handle = 0;
+        check(init(layers.as_ptr(), layers.len() as i32, weight_init as i32, bias, seed, &mut handle))?;
+        let network = PyNeuralNetwork { handle };
+        check(set_hidden_activation(handle, hidden_activation as i32))?;
+        Ok(network)
+    }
+
+    #[staticmethod]
+    fn load(path: &str) -> PyResult<PyNeuralNetwork> {
+        let path = self::path(path)?;
+        let mut handle = 0;
+        check(load_pmc(path.as_ptr(), &mut handle))?;
+        Ok(PyNeuralNetwork { handle })
+    }
+
+    fn save(&self, path: &str) -> PyResult<()> {
+        let path = self::path(path)?;
+        check(save_pmc(self.handle, path.as_ptr()))
+    }
+
+    #[getter]
+    fn layers(&self) -> PyResult<Vec<usize>> {
+        Ok(handle::with(self.handle, |model: &NeuralNetwork| Ok(model.neurons_per_layer.clone()))?)
+    }
+
+    /// Output layer for one sample.
+    #[pyo3(signature = (inputs, is_classification = false))]
+    fn predict<'py>(&self, py: Python<'py>, inputs: PyArrayLike1<'py, f32, AllowTypeChange>,
+                    is_classification: bool) -> PyResult<Bound<'py, PyArray1<f32>>> {
+        let inputs = inputs.as_array();
+        let inputs = values(&inputs);
+        let mut output = FloatBuffer::default();
+        check(predict(self.handle, inputs.as_ptr(), inputs.len() as i32, is_classification, &mut output))?;
+        Ok(PyArray1::from_vec(py, unsafe { output.into_vec() }))
+    }
+
+    /// Output layers for a (samples, inputs) array, one row per sample.
+    #[pyo3(signature = (inputs, is_classification = false))]
+    fn predict_batch<'py>(&self, py: Python<'py>, inputs: PyArrayLike2<'py, f32, AllowTypeChange>,
+                          is_classification: bool) -> PyResult<Bound<'py, PyArray2<f32>>> {
+        let inputs = inputs.as_array();
+        let (samples, input_dim) = inputs.dim();
+        let output_size = handle::with(self.handle, |model: &NeuralNetwork| Ok(model.output_size()))?;
+        let inputs = values(&inputs);
+        let mut output = vec![0.0; samples * output_size];
+        check(predict_batch(self.handle, inputs.as_ptr(), samples as i32, input_dim as i32,
+                            output.as_mut_ptr(), output.len() as i32, is_classification))?;
+        PyArray1::from_vec(py, output).reshape([samples, output_size])
+    }
+
+    /// Trains on (samples, inputs) and (samples, outputs) arrays and returns the history,
+    /// one `[iteration, train_loss, train_accuracy, val_loss, val_accuracy]` row per evaluation.
+    #[pyo3(signature = (inputs, outputs, learning_rate, iterations, is_classification = false,
+                        validation = None, eval_every = 0, patience = 0))]
+    #[allow(clippy::too_many_arguments)]
+    fn fit<'py>(&self, py: Python<'py>,
+                inputs: PyArrayLike2<'py, f32, AllowTypeChange>, outputs: PyArrayLike2<'py, f32, AllowTypeChange>,
+                learning_rate: f32, iterations: i32, is_classification: bool,
+                validation: Option<(PyArrayLike2<'py, f32, AllowTypeChange>, PyArrayLike2<'py, f32, AllowTypeChange>)>,
+                eval_every: i32, patience: i32) -> PyResult<Bound<'py, PyArray2<f32>>> {
+        let (inputs, outputs) = (inputs.as_array(), outputs.as_array());
+        let (validations, labels) = match &validation {
+            Some((validations, labels)) => (Some(validations.as_array()), Some(labels.as_array())),
+            None => (None, None),
+        };
+        let (input_values, output_values) = (values(&inputs), values(&outputs));
+        let validation_values = validations.as_ref().map(values).unwrap_or_default();
+        let label_values = labels.as_ref().map(values).unwrap_or_default();
+        let (validation_sub, label_sub) = (validations.as_ref().map_or(0, |array| array.ncols()), labels.as_ref().map_or(0, |array| array.ncols()));
+
+        // At most one record per evaluation, plus the last iteration
+        let evaluations = if eval_every > 0 { iterations.max(0) / eval_every + 1 } else { 0 };
+        let mut history = vec![0.0; evaluations as usize * HISTORY_RECORD_SIZE];
+        let mut written = 0;
+        let model = self.handle;
+        py.detach(|| check(train_pmc_model(model,
+            input_values.as_ptr(), input_values.len() as i32, inputs.ncols() as i32,
+            output_values.as_ptr(), output_values.len() as i32, outputs.ncols() as i32,
+            validation_values.as_ptr(), validation_values.len() as i32, validation_sub as i32,
+            label_values.as_ptr(), label_values.len() as i32, label_sub as i32,
+            learning_rate, iterations, is_classification, None, eval_every, patience,
+            history.as_mut_ptr(), history.len() as i32, &mut written)))?;
+
+        history.truncate(written as usize * HISTORY_RECORD_SIZE);
+        PyArray1::from_vec(py, history).reshape([written as usize, HISTORY_RECORD_SIZE])
+    }
+}
+
+impl Drop for PyNeuralNetwork {
+    fn drop(&mut self) {
+        handle::remove::<NeuralNetwork>(self.handle).ok();
+    }
+}
@@ -0,0 +1,111 @@
+use numpy::{AllowTypeChange, PyArray1, PyArrayLike3};
+use pyo3::prelude::*;
+use super::environment::PyEnvironment;
+use super::{check, values};
+use crate::environment::create::BuiltinEnvironment;
+use crate::ffi::handle::{self, Handle};
+use crate::reinforce_learning::create::{init_dyna_q, init_dyna_q_plus, init_monte_carlo_es, init_policy_iteration,
+                                        init_q_learning, init_sarsa, init_value_iteration};
+use crate::reinforce_learning::tabular::TabularModel;
+use crate::reinforce_learning::train::train_on;
+
+/// Tabular learner, built by one of the static constructors. Two agents with the same `seed`
+/// trained on environments with the same seed learn the same values.
+#[pyclass(name = "TabularAgent", module = "rustml")]
+pub struct PyTabularAgent {
+    handle: Handle,
+}
+
+// Runs an init_* export
+fn created(init: impl FnOnce(*mut Handle) -> crate::ffi::error::Status) -> PyResult<PyTabularAgent> {
+    let mut handle = 0;
+    check(init(&mut handle))?;
+    Ok(PyTabularAgent { handle })
+}
+
+// `rewards` and `probabilities` are (states, actions, states) arrays, indexed [state][action][next_state]
+type Transitions<'py> = PyArrayLike3<'py, f32, AllowTypeChange>;
+
+#[pymethods]
+impl PyTabularAgent {
+    #[staticmethod]
+    #[pyo3(signature = (iterations, gamma, alpha, epsilon, seed = 0))]
+    fn q_learning(iterations: i32, gamma: f32, alpha: f32, epsilon: f32, seed: u64) -> PyResult<PyTabularAgent> {
+        created(|out| init_q_learning(iterations, gamma, alpha, epsilon, seed, out))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (num_states, num_actions, alpha, gamma, epsilon, nb_episode, seed = 0))]
+    fn sarsa(num_states: i32, num_actions: i32, alpha: f32, gamma: f32, epsilon: f32, nb_episode: i32, seed: u64) -> PyResult<PyTabularAgent> {
+        created(|out| init_sarsa(num_states, num_actions, alpha, gamma, epsilon, nb_episode, seed, out))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (iterations, gamma, alpha, epsilon, planning_steps, seed = 0))]
+    fn dyna_q(iterations: i32, gamma: f32, alpha: f32, epsilon: f32, planning_steps: i32, seed: u64) -> PyResult<PyTabularAgent> {
+        created(|out| init_dyna_q(iterations, gamma, alpha, epsilon, planning_steps, seed, out))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (iterations, gamma, alpha, epsilon, planning_steps, kappa, seed = 0))]
+    fn dyna_q_plus(iterations: i32, gamma: f32, alpha: f32, epsilon: f32, planning_steps: i32, kappa: f32, seed: u64) -> PyResult<PyTabularAgent> {
+        created(|out| init_dyna_q_plus(iterations, gamma, alpha, epsilon, planning_steps, kappa, seed, out))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (num_episodes, gamma, seed = 0))]
+    fn monte_carlo_es(num_episodes: i32, gamma: f32, seed: u64) -> PyResult<PyTabularAgent> {
+        created(|out| init_monte_carlo_es(num_episodes, gamma, seed, out))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (rewards, probabilities, gamma, terminal_states, seed = 0))]
+    fn policy_iteration(rewards: Transitions<'_>, probabilities: Transitions<'_>, gamma: f32, terminal_states: Vec<i32>, seed: u64) -> PyResult<PyTabularAgent> {
+        let (rewards, probabilities) = (rewards.as_array(), probabilities.as_array());
+        let (num_states, num_actions, _) = rewards.dim();
+        let (rewards, probabilities) = (values(&rewards), values(&probabilities));
+        created(|out| init_policy_iteration(num_states as i32, num_actions as i32,
+                                            rewards.as_ptr(), rewards.len() as i32,
+                                            probabilities.as_ptr(), probabilities.len() as i32,
+                                            gamma, terminal_states.as_ptr(), terminal_states.len() as i32, seed, out))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (rewards, probabilities, gamma, terminal_states, theta, seed = 0))]
+    fn value_iteration(rewards: Transitions<'_>, probabilities: Transitions<'_>, gamma: f32, terminal_states: Vec<i32>, theta: f32, seed: u64) -> PyResult<PyTabularAgent> {
+        let (rewards, probabilities) = (rewards.as_array(), probabilities.as_array());
+        let (num_states, num_actions, _) = rewards.dim();
+        let (rewards, probabilities) = (values(&rewards), values(&probabilities));
+        created(|out| init_value_iteration(num_states as i32, num_actions as i32,
+                                           rewards.as_ptr(), rewards.len() as i32,
+                                           probabilities.as_ptr(), probabilities.len() as i32,
+                                           gamma, terminal_states.as_ptr(), terminal_states.len() as i32, theta, seed, out))
+    }
+
+    /// Policy and value iteration train without an environment.
+    #[pyo3(signature = (env = None))]
+    fn train(&self, py: Python<'_>, env: Option<PyRef<'_, PyEnvironment>>) -> PyResult<()> {
+        let (model, env) = (self.handle, env.map(|env| env.handle));
+        py.detach(|| Ok(handle::with_mut(model, |model: &mut TabularModel| match env {
+            Some(env) => handle::with_mut(env, |env: &mut BuiltinEnvironment| train_on(model, Some(env.0.as_mut()))),
+            None => train_on(model, None),
+        })?))
+    }
+
+    /// Greedy action in `state`, None for a state never visited.
+    fn action(&self, state: usize) -> PyResult<Option<usize>> {
+        Ok(handle::with(self.handle, |model: &TabularModel| Ok(model.action(state)))?)
+    }
+
+    /// Policy indexed by state, -1 for the states never visited.
+    fn policy<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyArray1<i32>>> {
+        let policy = handle::with(self.handle, |model: &TabularModel| Ok(model.policy()))?;
+        Ok(PyArray1::from_iter(py, policy.into_iter().map(|action| action.map_or(-1, |action| action as i32))))
+    }
+}
+
+impl Drop for PyTabularAgent {
+    fn drop(&mut self) {
+        handle::remove::<TabularModel>(self.handle).ok();
+    }
+}
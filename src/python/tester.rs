@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+    use pyo3::prelude::*;
+    use pyo3::types::PyDict;
+    use pyo3::wrap_pymodule;
+    use crate::python::rustml;
+
+    // Runs a script with the module imported as `rustml`, without numpy which the arrays need
+    fn run(script: &CStr) {
+        Python::initialize();
+        Python::attach(|py| {
+            let globals = PyDict::new(py);
+            globals.set_item("rustml", wrap_pymodule!(rustml)(py)).unwrap();
+            if let Err(error) = py.run(script, Some(&globals), None) {
+                error.display(py);
+                panic!("script failed: {}", error);
+            }
+        });
+    }
+
+    #[test]
+    fn tabular_agent_learns_on_a_builtin_environment() {
+        run(cr#"
+env = rustml.Environment("line_world", rows=5, start=2)
+assert str(env) == "__X__"
+assert (env.num_states, env.num_actions) == (5, 3)
+assert env.step(2) == (3, 0.0, False)
+
+agent = rustml.TabularAgent.q_learning(500, 0.9, 0.1, 0.2)
+agent.train(env)
+assert [agent.action(state) for state in (1, 2, 3)] == [2, 2, 2]
+assert rustml.TabularAgent.q_learning(10, 0.9, 0.1, 0.2).action(3) is None
+
+def resets(seed):
+    env = rustml.Environment("grid_world", rows=4, cols=4, seed=seed)
+    return [env.reset() for _ in range(10)]
+assert resets(3) == resets(3)
+"#);
+    }
+
+    #[test]
+    fn models_round_trip() {
+        run(cr#"
+import os, tempfile
+network = rustml.NeuralNetwork([2, 3, 1], weight_init="he_normal", seed=7, hidden_activation="relu")
+assert network.layers == [2, 3, 1]
+path = os.path.join(tempfile.mkdtemp(), "pmc.bin")
+network.save(path)
+assert rustml.NeuralNetwork.load(path).layers == [2, 3, 1]
+
+agent = rustml.DeepQLearning(4, 2, 0.01, 0.9, 1.0, 0.1, 0.5)
+agent.update_epsilon()
+assert agent.epsilon == 0.5
+assert rustml.DeepQLearning.load(agent.save()).epsilon == 0.5
+"#);
+    }
+
+    #[test]
+    fn failures_raise_with_the_error_message() {
+        run(cr#"
+def raises(error_type, message, call):
+    try:
+        call()
+    except error_type as error:
+        assert str(error) == message, str(error)
+    else:
+        raise AssertionError("no " + error_type.__name__)
+
+raises(ValueError, "doors should be at least 3 (2)", lambda: rustml.Environment("monty_hall", doors=2))
+raises(ValueError, "unknown environment 'maze'", lambda: rustml.Environment("maze"))
+raises(ValueError, "env is null", lambda: rustml.TabularAgent.q_learning(10, 0.9, 0.1, 0.2).train())
+raises(ValueError, "a network needs at least an input and an output layer", lambda: rustml.NeuralNetwork([3]))
+raises(OSError, "No such file or directory (os error 2)", lambda: rustml.NeuralNetwork.load("/nonexistent/pmc.bin"))
+
+env = rustml.Environment("line_world", rows=5, start=3)
+env.step(2)
+raises(ValueError, "the episode is over, call env_reset", lambda: env.step(2))
+"#);
+    }
+}
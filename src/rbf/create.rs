@@ -0,0 +1,48 @@
+use super::rbf_struct::{RbfCenters, RbfNetwork, RbfTraining};
+use crate::basic_function::random::SeededRng;
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
+use crate::neural_network::initializer::WeightInit;
+
+#[no_mangle]
+pub(crate) extern "C" fn init_rbf(input_size: i32, output_size: i32, num_centers: i32, gamma: f32,
+                                  center_selection: i32, training: i32,
+                                  is_classification: bool, seed: u64, out: *mut Handle) -> Status {
+    ffi_call(|| {
+        let (center_selection, training) = (RbfCenters::try_from(center_selection)?, RbfTraining::try_from(training)?);
+        // FullSet picks its own number of centers
+        let num_centers = match center_selection {
+            RbfCenters::KMeans => args::positive(num_centers, "num_centers")?,
+            RbfCenters::FullSet => args::length(num_centers, "num_centers")?,
+        };
+        let model = RbfNetwork::new(args::positive(input_size, "input_size")?, args::positive(output_size, "output_size")?, num_centers, gamma,
+                                    center_selection, training, is_classification, seed);
+        handle::write(out, model)
+    })
+}
+
+impl RbfNetwork {
+    /// Centers are placed by `fit`, until then they sit at the origin.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(input_size: usize, output_size: usize, num_centers: usize, gamma: f32,
+               center_selection: RbfCenters, training: RbfTraining,
+               is_classification: bool, seed: u64) -> RbfNetwork {
+        let mut rng = SeededRng::new(seed);
+        RbfNetwork {
+            input_size,
+            output_size,
+            num_centers,
+            gamma,
+            center_selection,
+            training,
+            is_classification,
+            centers: vec![0.0; num_centers * input_size],
+            weights: (0..num_centers * output_size)
+                .map(|_| WeightInit::Uniform.sample(&mut rng, num_centers, output_size))
+                .collect(),
+            biases: vec![0.0; output_size],
+            rng,
+        }
+    }
+}
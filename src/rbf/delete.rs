@@ -0,0 +1,12 @@
+use super::rbf_struct::RbfNetwork;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
+
+#[no_mangle]
+extern "C" fn delete_rbf(model: Handle) -> Status {
+    ffi_call(|| {
+        handle::remove::<RbfNetwork>(model)?;
+        println!("Model deleted");
+        Ok(())
+    })
+}
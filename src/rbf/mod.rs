@@ -0,0 +1,7 @@
+pub mod create;
+pub mod rbf_struct;
+pub mod delete;
+pub mod train;
+pub mod predict;
+pub mod manager;
+mod tester;
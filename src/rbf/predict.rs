@@ -0,0 +1,16 @@
+use super::rbf_struct::RbfNetwork;
+use crate::ffi::args;
+use crate::ffi::buffer::FloatBuffer;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
+
+#[no_mangle]
+pub(crate) extern "C" fn predict_rbf(model: Handle, inputs: *const f32, inputs_len: i32, out: *mut FloatBuffer) -> Status {
+    ffi_call(|| handle::with(model, |model: &RbfNetwork| {
+        let inputs_slice = args::slice(inputs, inputs_len, "inputs")?;
+        args::expect_len(inputs_slice.len(), model.input_size, "inputs")?;
+
+        // Released by the caller with free_float_buffer
+        args::write(out, FloatBuffer::from_vec(model.predict(inputs_slice)), "out")
+    }))
+}
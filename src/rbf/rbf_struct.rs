@@ -0,0 +1,76 @@
+use bincode::{Decode, Encode};
+use crate::basic_function::random::SeededRng;
+use crate::ffi::args::enum_code;
+
+#[repr(C)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Debug)]
+pub enum RbfCenters {
+    /// num_centers centers found by k-means++
+    KMeans,
+    /// One center per training sample, num_centers is replaced by the dataset size
+    FullSet,
+}
+
+enum_code!(RbfCenters { KMeans, FullSet });
+
+#[repr(C)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Debug)]
+pub enum RbfTraining {
+    /// Exact least squares output weights (naive RBF)
+    PseudoInverse,
+    /// Stochastic gradient descent on the mean squared error
+    GradientDescent,
+}
+
+enum_code!(RbfTraining { PseudoInverse, GradientDescent });
+
+#[derive(Encode, Decode, PartialEq, Debug, Clone)]
+pub struct RbfNetwork {
+    pub input_size: usize,
+    pub output_size: usize,
+    pub num_centers: usize,
+    pub gamma: f32,
+    pub center_selection: RbfCenters,
+    pub training: RbfTraining,
+    /// Classification predicts the sign of each output, labels in {-1, 1}
+    pub is_classification: bool,
+    // Row-major: num_centers rows of input_size coordinates
+    pub centers: Vec<f32>,
+    // Row-major: output_size rows of num_centers weights
+    pub weights: Vec<f32>,
+    pub biases: Vec<f32>,
+    /// Places the k-means centers and picks the gradient descent samples, seeded by `new`
+    pub rng: SeededRng,
+}
+
+impl RbfNetwork {
+    // phi_k = exp(-gamma * ||x - c_k||^2)
+    pub(crate) fn features_into(&self, inputs: &[f32], features: &mut [f32]) {
+        for (feature, center) in features.iter_mut().zip(self.centers.chunks_exact(self.input_size)) {
+            let distance: f32 = center.iter().zip(inputs).map(|(c, x)| (x - c).powi(2)).sum();
+            *feature = (-self.gamma * distance).exp();
+        }
+    }
+
+    pub(crate) fn features(&self, inputs: &[f32]) -> Vec<f32> {
+        let mut features = vec![0.0; self.num_centers];
+        self.features_into(inputs, &mut features);
+        features
+    }
+
+    // Linear output layer on top of the gaussian features, before the classification sign
+    pub(crate) fn raw_outputs(&self, features: &[f32]) -> Vec<f32> {
+        self.weights.chunks_exact(self.num_centers.max(1)).zip(&self.biases)
+            .map(|(row, &bias)| bias + row.iter().zip(features).map(|(w, phi)| w * phi).sum::<f32>())
+            .collect()
+    }
+
+    pub fn predict(&self, inputs: &[f32]) -> Vec<f32> {
+        let outputs = self.raw_outputs(&self.features(inputs));
+        if self.is_classification {
+            outputs.iter().map(|&total| if total >= 0.0 { 1.0 } else { -1.0 }).collect()
+        } else {
+            outputs
+        }
+    }
+}
@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use crate::ffi::error::Status;
+    use crate::ffi::handle;
+    use crate::ffi::test_support::{created, output, taken};
+    use crate::rbf::create::init_rbf;
+    use crate::rbf::manager::{load_rbf, save_rbf};
+    use crate::rbf::predict::predict_rbf;
+    use crate::rbf::rbf_struct::{RbfCenters, RbfNetwork, RbfTraining};
+    use crate::rbf::train::train_rbf;
+
+    // XOR with -1 / 1 labels, not linearly separable
+    fn xor_dataset() -> (Vec<Vec<f32>>, Vec<Vec<f32>>) {
+        let inputs = vec![vec![0.0, 0.0], vec![0.0, 1.0], vec![1.0, 0.0], vec![1.0, 1.0]];
+        let outputs = vec![vec![-1.0], vec![1.0], vec![1.0], vec![-1.0]];
+        (inputs, outputs)
+    }
+
+    #[test]
+    fn init_rbf_network() {
+        let model = taken::<RbfNetwork>(created(|out| init_rbf(3, 2, 4, 1.0, RbfCenters::KMeans as i32, RbfTraining::PseudoInverse as i32, true, 1, out)));
+        assert_eq!(model.centers.len(), 12);
+        assert_eq!(model.weights.len(), 8);
+        assert_eq!(model.biases, vec![0.0, 0.0]);
+        assert_eq!(model, taken(created(|out| init_rbf(3, 2, 4, 1.0, RbfCenters::KMeans as i32, RbfTraining::PseudoInverse as i32, true, 1, out))));
+
+        let mut invalid = 0;
+        assert_eq!(init_rbf(3, 2, 0, 1.0, RbfCenters::KMeans as i32, RbfTraining::PseudoInverse as i32, true, 1, &mut invalid), Status::InvalidArgument);
+        assert_eq!(invalid, 0);
+    }
+
+    #[test]
+    fn naive_rbf_solves_xor() {
+        let (inputs, outputs) = xor_dataset();
+        let flat_inputs = inputs.concat();
+        let flat_outputs = outputs.concat();
+        let model = created(|out| init_rbf(2, 1, 0, 2.0, RbfCenters::FullSet as i32, RbfTraining::PseudoInverse as i32, true, 2, out));
+        let status = train_rbf(model, flat_inputs.as_ptr(), flat_inputs.len() as i32, 2, flat_outputs.as_ptr(), flat_outputs.len() as i32, 1, 0.0, 0);
+        assert_eq!(status, Status::Ok);
+
+        assert_eq!(handle::with(model, |model: &RbfNetwork| Ok(model.num_centers)), Ok(4));
+        for (input, expected) in inputs.iter().zip(&outputs) {
+            let res = output(|out| predict_rbf(model, input.as_ptr(), 2, out));
+            let prediction = unsafe { res.into_vec() };
+            assert_eq!(prediction[0], expected[0]);
+        }
+    }
+
+    #[test]
+    fn full_set_recovers_known_weights() {
+        let inputs: Vec<Vec<f32>> = (0..5).map(|i| vec![i as f32 * 0.5]).collect();
+        let mut model = RbfNetwork::new(1, 1, inputs.len(), 1.0, RbfCenters::FullSet, RbfTraining::PseudoInverse, false, 5);
+        model.centers = inputs.concat();
+
+        // Weights in the row space of [1, Phi] are the minimum norm solution, so the solve must return them
+        let design: Vec<Vec<f32>> = inputs.iter()
+            .map(|x| std::iter::once(1.0).chain(model.features(x)).collect())
+            .collect();
+        let z = [0.5f32, -1.0, 2.0, 0.25, -0.75];
+        let known: Vec<f32> = (0..=inputs.len())
+            .map(|j| design.iter().zip(&z).map(|(row, zi)| row[j] * zi).sum())
+            .collect();
+        let outputs: Vec<Vec<f32>> = design.iter()
+            .map(|row| vec![row.iter().zip(&known).map(|(a, w)| a * w).sum()])
+            .collect();
+
+        model.fit(&inputs, &outputs, 0.0, 0, &mut StdRng::seed_from_u64(0));
+        assert_eq!(model.num_centers, 5);
+        assert!((model.biases[0] - known[0]).abs() < 1e-3);
+        for (weight, expected) in model.weights.iter().zip(&known[1..]) {
+            assert!((weight - expected).abs() < 1e-3, "{weight} != {expected}");
+        }
+        for (input, output) in inputs.iter().zip(&outputs) {
+            assert!((model.predict(input)[0] - output[0]).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn kmeans_gradient_descent_regression() {
+        // y = sin(x) on [0, 3]
+        let inputs: Vec<Vec<f32>> = (0..60).map(|i| vec![i as f32 * 0.05]).collect();
+        let outputs: Vec<Vec<f32>> = inputs.iter().map(|x| vec![x[0].sin()]).collect();
+        let mut model = RbfNetwork::new(1, 1, 8, 4.0, RbfCenters::KMeans, RbfTraining::GradientDescent, false, 3);
+        model.fit(&inputs, &outputs, 0.1, 50000, &mut StdRng::seed_from_u64(0));
+
+        for (input, output) in inputs.iter().zip(&outputs) {
+            assert!((model.predict(input)[0] - output[0]).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn pseudo_inverse_matches_gradient_descent() {
+        let inputs: Vec<Vec<f32>> = (0..30).map(|i| vec![i as f32 * 0.1]).collect();
+        let outputs: Vec<Vec<f32>> = inputs.iter().map(|x| vec![x[0] * x[0]]).collect();
+        let mut exact = RbfNetwork::new(1, 1, 5, 1.0, RbfCenters::KMeans, RbfTraining::PseudoInverse, false, 4);
+        exact.fit(&inputs, &outputs, 0.0, 0, &mut StdRng::seed_from_u64(1));
+
+        // Least squares is at least as good as any other output layer on the same centers
+        let error = |model: &RbfNetwork| -> f32 {
+            inputs.iter().zip(&outputs).map(|(x, y)| (model.predict(x)[0] - y[0]).powi(2)).sum()
+        };
+        let mut descent = RbfNetwork::new(1, 1, 5, 1.0, RbfCenters::KMeans, RbfTraining::GradientDescent, false, 4);
+        descent.fit(&inputs, &outputs, 0.05, 20000, &mut StdRng::seed_from_u64(1));
+        assert_eq!(exact.centers, descent.centers);
+        assert!(error(&exact) <= error(&descent) + 1e-4);
+        assert!(error(&exact) < 1.0);
+    }
+
+    #[test]
+    fn same_seed_trains_the_same_network() {
+        let (inputs, outputs) = xor_dataset();
+        let (inputs, outputs) = (inputs.concat(), outputs.concat());
+        let trained = |seed| {
+            let model = created(|out| init_rbf(2, 1, 3, 0.5, RbfCenters::KMeans as i32, RbfTraining::GradientDescent as i32, true, seed, out));
+            assert_eq!(train_rbf(model, inputs.as_ptr(), 8, 2, outputs.as_ptr(), 4, 1, 0.1, 200), Status::Ok);
+            taken::<RbfNetwork>(model)
+        };
+        assert_eq!(trained(3), trained(3));
+        assert_ne!(trained(3).weights, trained(4).weights);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let model = created(|out| init_rbf(2, 1, 3, 0.5, RbfCenters::KMeans as i32, RbfTraining::GradientDescent as i32, false, 5, out));
+        let path = std::env::temp_dir().join("rustml_rbf_test.bin");
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+
+        assert_eq!(save_rbf(model, c_path.as_ptr()), Status::Ok);
+        let loaded = created(|out| load_rbf(c_path.as_ptr(), out));
+        // The generator is not saved, see SeededRng
+        let (mut loaded, model) = (taken::<RbfNetwork>(loaded), taken::<RbfNetwork>(model));
+        assert_ne!(loaded.rng, model.rng);
+        loaded.rng = model.rng.clone();
+        assert_eq!(loaded, model);
+        std::fs::remove_file(path).unwrap();
+
+        let missing = CString::new("/nonexistent/rustml_model.bin").unwrap();
+        let mut loaded = 0;
+        assert_eq!(load_rbf(missing.as_ptr(), &mut loaded), Status::Io);
+        assert_eq!(loaded, 0);
+    }
+}
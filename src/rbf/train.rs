@@ -0,0 +1,97 @@
+use rand::Rng;
+use super::rbf_struct::{RbfCenters, RbfNetwork, RbfTraining};
+use crate::clustering::kmeans::KMeans;
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, FfiError, Status};
+use crate::ffi::handle::{self, Handle};
+use crate::linalg::matrix::Matrix;
+
+const KMEANS_ITERATIONS: usize = 100;
+
+#[no_mangle]
+pub(crate) extern "C" fn train_rbf(model: Handle,
+                                   inputs_ptr: *const f32, input_length: i32, inputs_sub: i32,
+                                   output_ptr: *const f32, output_length: i32, output_sub: i32,
+                                   learning_rate: f32, iteration: i32) -> Status {
+    ffi_call(|| handle::with_mut(model, |model: &mut RbfNetwork| {
+        // Recompose Vec<Vec<f32>>
+        let inputs = args::rows(inputs_ptr, input_length, inputs_sub, "inputs")?;
+        let outputs = args::rows(output_ptr, output_length, output_sub, "outputs")?;
+        args::check_samples(&inputs, &outputs, model.input_size, model.output_size)?;
+        if inputs.is_empty() {
+            return Err(FfiError::invalid("no training samples"));
+        }
+        if model.center_selection == RbfCenters::KMeans && model.num_centers > inputs.len() {
+            return Err(FfiError::invalid(format!("{} centers for {} samples", model.num_centers, inputs.len())));
+        }
+
+        // Drawn from the model generator, so the same seed trains the same network
+        let mut rng = model.rng.clone();
+        model.fit(&inputs, &outputs, learning_rate, args::length(iteration, "iteration")?, &mut rng);
+        model.rng = rng;
+        Ok(())
+    }))
+}
+
+impl RbfNetwork {
+    /// Places the centers then trains the output layer.
+    /// The pseudo-inverse ignores `learning_rate` and `iterations`, the solution is exact.
+    pub fn fit<R: Rng>(&mut self, inputs: &[Vec<f32>], outputs: &[Vec<f32>], learning_rate: f32, iterations: usize, rng: &mut R) {
+        let centers = match self.center_selection {
+            RbfCenters::KMeans => KMeans::fit(inputs, self.num_centers, KMEANS_ITERATIONS, rng).center_rows(),
+            RbfCenters::FullSet => inputs.to_vec(),
+        };
+        self.set_centers(&centers);
+
+        let features: Vec<Vec<f32>> = inputs.iter().map(|input| self.features(input)).collect();
+        match self.training {
+            RbfTraining::PseudoInverse => self.solve_output_weights(&features, outputs),
+            RbfTraining::GradientDescent => {
+                for _ in 0..iterations {
+                    // pick random data in the dataset
+                    let rand = rng.random_range(0..inputs.len());
+                    self.gradient_step(&features[rand], &outputs[rand], learning_rate);
+                }
+            }
+        }
+    }
+
+    // Resize the output layer when the number of centers changed
+    fn set_centers(&mut self, centers: &[Vec<f32>]) {
+        if centers.len() != self.num_centers {
+            self.num_centers = centers.len();
+            self.weights = vec![0.0; self.num_centers * self.output_size];
+        }
+        self.centers = centers.concat();
+    }
+
+    // w -= lr * (w.phi + b - y) * phi
+    fn gradient_step(&mut self, features: &[f32], output: &[f32], learning_rate: f32) {
+        let totals = self.raw_outputs(features);
+        for (((row, bias), total), &expected) in self.weights.chunks_exact_mut(self.num_centers)
+            .zip(self.biases.iter_mut()).zip(totals).zip(output) {
+            let error = total - expected;
+            for (weight, &phi) in row.iter_mut().zip(features) {
+                *weight -= learning_rate * error * phi;
+            }
+            *bias -= learning_rate * error;
+        }
+    }
+
+    // Phi with a leading column of ones for the bias. FullSet gives one more unknown than samples,
+    // so Phi^T Phi is singular and the minimum norm solution comes from the pseudo-inverse.
+    fn solve_output_weights(&mut self, features: &[Vec<f32>], outputs: &[Vec<f32>]) {
+        let rows: Vec<Vec<f64>> = features.iter()
+            .map(|phi| std::iter::once(1.0).chain(phi.iter().map(|&x| x as f64)).collect())
+            .collect();
+        let targets: Vec<Vec<f64>> = outputs.iter().map(|output| output.iter().map(|&y| y as f64).collect()).collect();
+        let solution = Matrix::from_rows(&rows).pinv().matmul(&Matrix::from_rows(&targets));
+
+        for (o, (bias, row)) in self.biases.iter_mut().zip(self.weights.chunks_exact_mut(self.num_centers)).enumerate() {
+            *bias = solution[(0, o)] as f32;
+            for (k, weight) in row.iter_mut().enumerate() {
+                *weight = solution[(k + 1, o)] as f32;
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::BTreeMap;
+use super::dynamic_programming::policy_iteration::PolicyIterationModel;
+use super::dynamic_programming::value_iteration::ValueIterationModel;
+use super::monte_carlo::monte_carlo_es::MonteCarloESModel;
+use super::planning::dyna_q::DynaQModel;
+use super::planning::dyna_q_plus::DynaQPlusModel;
+use super::tabular::TabularModel;
+use super::td_learning::q_learning::QLearning;
+use super::td_learning::sarsa::SarsaModel;
+use crate::basic_function::random::SeededRng;
+use crate::environment::environment::{Reward, State};
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, FfiError, FfiResult, Status};
+use crate::ffi::handle::{self, Handle};
+
+#[no_mangle]
+pub(crate) extern "C" fn init_q_learning(iterations: i32, gamma: f32, alpha: f32, epsilon: f32, seed: u64, out: *mut Handle) -> Status {
+    ffi_call(|| {
+        let model = QLearning::new(args::length(iterations, "iterations")?, gamma, alpha, epsilon, seed);
+        handle::write(out, TabularModel::QLearning(*model))
+    })
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn init_sarsa(num_states: i32, num_actions: i32, alpha: f32, gamma: f32, epsilon: f32, nb_episode: i32, seed: u64, out: *mut Handle) -> Status {
+    ffi_call(|| {
+        // SarsaModel::new sizes itself from an environment, the host gives the sizes instead
+        let num_states = args::positive(num_states, "num_states")?;
+        let model = SarsaModel {
+            alpha,
+            gamma,
+            epsilon: epsilon as f64,
+            nb_episode: args::length(nb_episode, "nb_episode")?,
+            num_states,
+            num_actions: args::positive(num_actions, "num_actions")?,
+            policy: vec![0; num_states],
+            q_values: BTreeMap::new(),
+            rng: SeededRng::new(seed),
+        };
+        handle::write(out, TabularModel::Sarsa(model))
+    })
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn init_dyna_q(iterations: i32, gamma: f32, alpha: f32, epsilon: f32, planning_steps: i32, seed: u64, out: *mut Handle) -> Status {
+    ffi_call(|| {
+        let model = DynaQModel::new(args::length(iterations, "iterations")?, gamma, alpha, epsilon, args::length(planning_steps, "planning_steps")?, seed);
+        handle::write(out, TabularModel::DynaQ(*model))
+    })
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn init_dyna_q_plus(iterations: i32, gamma: f32, alpha: f32, epsilon: f32, planning_steps: i32, kappa: f32, seed: u64, out: *mut Handle) -> Status {
+    ffi_call(|| {
+        let model = DynaQPlusModel::new(args::length(iterations, "iterations")?, gamma, alpha, epsilon, args::length(planning_steps, "planning_steps")?, kappa, seed);
+        handle::write(out, TabularModel::DynaQPlus(*model))
+    })
+}
+
+// Episodes end when the environment reports done, a built-in environment caps them with max_episode_steps
+#[no_mangle]
+pub(crate) extern "C" fn init_monte_carlo_es(num_episodes: i32, gamma: f32, seed: u64, out: *mut Handle) -> Status {
+    ffi_call(|| {
+        let model = MonteCarloESModel::new(args::length(num_episodes, "num_episodes")?, gamma, seed);
+        handle::write(out, TabularModel::MonteCarloES(*model))
+    })
+}
+
+// `rewards` and `probabilities` are num_states x num_actions x num_states, indexed [state][action][next_state]
+#[no_mangle]
+pub(crate) extern "C" fn init_policy_iteration(num_states: i32, num_actions: i32,
+                                               rewards: *const f32, rewards_len: i32,
+                                               probabilities: *const f32, probabilities_len: i32,
+                                               gamma: f32, terminal_states: *const i32, terminal_len: i32,
+                                               seed: u64, out: *mut Handle) -> Status {
+    ffi_call(|| {
+        let mdp = Mdp::new(num_states, num_actions, rewards, rewards_len, probabilities, probabilities_len, terminal_states, terminal_len)?;
+        let model = PolicyIterationModel::new(mdp.states, mdp.actions, mdp.rewards, mdp.probabilities, gamma, mdp.terminal_states, seed);
+        handle::write(out, TabularModel::PolicyIteration(*model))
+    })
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn init_value_iteration(num_states: i32, num_actions: i32,
+                                              rewards: *const f32, rewards_len: i32,
+                                              probabilities: *const f32, probabilities_len: i32,
+                                              gamma: f32, terminal_states: *const i32, terminal_len: i32,
+                                              theta: f32, seed: u64, out: *mut Handle) -> Status {
+    ffi_call(|| {
+        if theta <= 0.0 {
+            return Err(FfiError::invalid(format!("theta should be positive ({})", theta)));
+        }
+        let mdp = Mdp::new(num_states, num_actions, rewards, rewards_len, probabilities, probabilities_len, terminal_states, terminal_len)?;
+        let model = ValueIterationModel::new(mdp.states, mdp.actions, mdp.rewards, mdp.probabilities, gamma, mdp.terminal_states, seed);
+        handle::write(out, TabularModel::ValueIteration(*model, theta))
+    })
+}
+
+// Transition model of the dynamic programming exports, in the layout the environments build
+struct Mdp {
+    states: Vec<State>,
+    actions: Vec<usize>,
+    rewards: Vec<Vec<Vec<Reward>>>,
+    probabilities: Vec<Vec<Vec<f32>>>,
+    terminal_states: Vec<State>,
+}
+
+impl Mdp {
+    #[allow(clippy::too_many_arguments)]
+    fn new(num_states: i32, num_actions: i32, rewards: *const f32, rewards_len: i32,
+           probabilities: *const f32, probabilities_len: i32, terminal_states: *const i32, terminal_len: i32) -> FfiResult<Mdp> {
+        let num_states = args::positive(num_states, "num_states")?;
+        let num_actions = args::positive(num_actions, "num_actions")?;
+        let terminal_states = args::slice(terminal_states, terminal_len, "terminal_states")?.iter()
+            .map(|&state| if state >= 0 && (state as usize) < num_states {
+                Ok(state as State)
+            } else {
+                Err(FfiError::invalid(format!("terminal state {} is out of [0, {})", state, num_states)))
+            })
+            .collect::<FfiResult<Vec<State>>>()?;
+        Ok(Mdp {
+            states: (0..num_states).collect(),
+            actions: (0..num_actions).collect(),
+            rewards: Self::tensor(rewards, rewards_len, num_states, num_actions, "rewards")?,
+            probabilities: Self::tensor(probabilities, probabilities_len, num_states, num_actions, "probabilities")?,
+            terminal_states,
+        })
+    }
+
+    fn tensor(values: *const f32, len: i32, num_states: usize, num_actions: usize, name: &str) -> FfiResult<Vec<Vec<Vec<f32>>>> {
+        let values = args::slice(values, len, name)?;
+        args::expect_len(values.len(), num_states * num_actions * num_states, name)?;
+        Ok(values.chunks(num_actions * num_states)
+            .map(|state| state.chunks(num_states).map(|action| action.to_vec()).collect())
+            .collect())
+    }
+}
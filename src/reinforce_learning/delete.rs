@@ -0,0 +1,12 @@
+use super::tabular::TabularModel;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
+
+#[no_mangle]
+extern "C" fn delete_tabular(model: Handle) -> Status {
+    ffi_call(|| {
+        handle::remove::<TabularModel>(model)?;
+        println!("Model deleted");
+        Ok(())
+    })
+}
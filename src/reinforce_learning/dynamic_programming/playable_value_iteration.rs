@@ -1,4 +1,5 @@
 use rand::Rng;
+use crate::basic_function::random::SeededRng;
 use crate::environment::environment::{State, Action, Reward, Environment};
 
 pub struct ValueIterationModel {
@@ -10,14 +11,14 @@ pub struct ValueIterationModel {
 }
 
 impl ValueIterationModel {
-    pub fn new(s: Vec<State>, a: Vec<Action>, g: f32, terminal_state: Vec<State>) -> Box<ValueIterationModel> {
-        let mut rng = rand::thread_rng();
+    pub fn new(s: Vec<State>, a: Vec<Action>, g: f32, terminal_state: Vec<State>, seed: u64) -> Box<ValueIterationModel> {
+        let mut rng = SeededRng::new(seed);
         let mut vi_model = Box::new(ValueIterationModel {
             states: s.clone(),
             actions: a,
             gamma: g,
             policy: vec![0; s.len()],
-            value_function: (0..s.len()).map(|_| rng.gen::<f32>()).collect(),
+            value_function: (0..s.len()).map(|_| rng.random::<f32>()).collect(),
         });
         for &s in terminal_state.iter() {
             vi_model.value_function[s as usize] = 0.0;
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::fs::File;
 use std::{f32, io};
 use rand::Rng;
+use crate::basic_function::random::SeededRng;
 use crate::environment::environment::{Action, Reward, State};
 
 pub struct PolicyIterationModel {
@@ -17,8 +18,8 @@ pub struct PolicyIterationModel {
 
 impl PolicyIterationModel {
     pub fn new(states: Vec<State>, actions: Vec<Action>, rewards: Vec<Vec<Vec<Reward>>>,
-               probabilities: Vec<Vec<Vec<f32>>>, gamma: f32, terminal_state: Vec<State>) -> Box<PolicyIterationModel> {
-        let mut rng = rand::thread_rng();
+               probabilities: Vec<Vec<Vec<f32>>>, gamma: f32, terminal_state: Vec<State>, seed: u64) -> Box<PolicyIterationModel> {
+        let mut rng = SeededRng::new(seed);
         let mut pi_model = Box::new(PolicyIterationModel {
             states: states.clone(),
             actions,
@@ -27,7 +28,7 @@ impl PolicyIterationModel {
             is_policy_stable: false,
             gamma,
             policy: vec![0; states.len()],
-            value_function: (0..states.len()).map(|_| rng.gen::<f32>()).collect()
+            value_function: (0..states.len()).map(|_| rng.random::<f32>()).collect()
         });
         for &s in terminal_state.iter() {
             pi_model.value_function[s as usize] = 0.0;
@@ -119,9 +120,4 @@ impl PolicyIterationModel {
         self.policy = vec.clone();
         Ok(vec)
     }
-
-    pub fn print_policy(&self) {
-        println!("Policy: {:?}", self.policy);
-    }
-
 }
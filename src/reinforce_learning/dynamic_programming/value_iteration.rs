@@ -3,6 +3,7 @@ use std::fs::File;
 use std::{io};
 use rand::Rng;
 use serde_json;
+use crate::basic_function::random::SeededRng;
 use crate::environment::environment::{Reward};
 
 pub struct ValueIterationModel {
@@ -16,8 +17,8 @@ pub struct ValueIterationModel {
 }
 
 impl ValueIterationModel {
-    pub fn new(s: Vec<usize>, a: Vec<usize>, r: Vec<Vec<Vec<Reward>>>, p: Vec<Vec<Vec<f32>>>, g: f32, terminal_state: Vec<usize>) -> Box<ValueIterationModel> {
-        let mut rng = rand::thread_rng();
+    pub fn new(s: Vec<usize>, a: Vec<usize>, r: Vec<Vec<Vec<Reward>>>, p: Vec<Vec<Vec<f32>>>, g: f32, terminal_state: Vec<usize>, seed: u64) -> Box<ValueIterationModel> {
+        let mut rng = SeededRng::new(seed);
         let mut vi_model = Box::new(ValueIterationModel {
             states: s.clone(),
             actions: a,
@@ -25,7 +26,7 @@ impl ValueIterationModel {
             probabilities: p,
             gamma: g,
             policy: vec![0; s.len()],
-            value_function: (0..s.len()).map(|_| rng.gen::<f32>()).collect(),
+            value_function: (0..s.len()).map(|_| rng.random::<f32>()).collect(),
         });
         for &s in terminal_state.iter() {
             vi_model.value_function[s] = 0.0;
@@ -93,8 +94,4 @@ impl ValueIterationModel {
         self.policy = vec.clone();
         Ok(vec)
     }
-
-    pub fn print_policy(&self) {
-        println!("Policy: {:?}", self.policy);
-    }
 }
@@ -1,4 +1,10 @@
 pub mod dynamic_programming;
 pub mod monte_carlo;
 pub mod planning;
-pub mod td_learning;
\ No newline at end of file
+pub mod td_learning;
+pub mod tabular;
+pub mod create;
+pub mod train;
+pub mod predict;
+pub mod delete;
+mod tester;
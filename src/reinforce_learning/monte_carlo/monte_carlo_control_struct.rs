@@ -3,16 +3,15 @@ extern crate serde;
 extern crate serde_json;
 extern crate bincode;
 
-use rand::seq::SliceRandom;
-use rand::{thread_rng};
-use std::collections::HashMap;
+use rand::seq::IndexedRandom;
+use std::collections::BTreeMap;
+use crate::basic_function::random::SeededRng;
 use crate::environment::environment::{State, Action, Reward, Environment};
 use serde::{Serialize, Deserialize};
 use std::fs::File;
 use std::io::{self};
 use std::error::Error;
 
-/*
 #[derive(Clone, Debug)]
 pub struct EpisodeStep {
     pub state: State,
@@ -24,28 +23,31 @@ pub struct EpisodeStep {
 pub struct MonteCarloControl {
     pub epsilon: f64,
     pub gamma: f32,
-    pub derived_policy: HashMap<State, HashMap<Action, f64>>,
-    pub q_values: HashMap<(State, Action), Reward>,
-    pub returns: HashMap<(State, Action), Vec<Reward>>,
-    pub policy: HashMap<State, Action>,
+    pub derived_policy: BTreeMap<State, BTreeMap<Action, f64>>,
+    pub q_values: BTreeMap<(State, Action), Reward>,
+    pub returns: BTreeMap<(State, Action), Vec<Reward>>,
+    pub policy: BTreeMap<State, Action>,
+    #[serde(skip, default = "SeededRng::from_entropy")]
+    pub rng: SeededRng,
 }
 
 impl MonteCarloControl {
-    pub fn new(epsilon: f64, gamma: f32) -> Box<MonteCarloControl> {
+    pub fn new(epsilon: f64, gamma: f32, seed: u64) -> Box<MonteCarloControl> {
         Box::new(MonteCarloControl {
             epsilon,
             gamma,
-            derived_policy: HashMap::new(),
-            q_values: HashMap::new(),
-            returns: HashMap::new(),
-            policy: HashMap::new(),
+            derived_policy: BTreeMap::new(),
+            q_values: BTreeMap::new(),
+            returns: BTreeMap::new(),
+            policy: BTreeMap::new(),
+            rng: SeededRng::new(seed),
         })
     }
 
     pub fn ensure_policy_initialized(&mut self, state: State, env: &mut dyn Environment) {
         if !self.derived_policy.contains_key(&state)
         {
-            let mut actions = HashMap::new();
+            let mut actions = BTreeMap::new();
             let available_actions = env.all_action();
             for &action in &available_actions {
                 if !env.is_forbidden(action) {
@@ -58,7 +60,7 @@ impl MonteCarloControl {
         }
     }
 
-    pub fn choose_action_soft(&mut self, state: State, env: &mut dyn Environment, rng: &mut rand::rngs::ThreadRng) -> Action {
+    pub fn choose_action_soft(&mut self, state: State, env: &mut dyn Environment, rng: &mut SeededRng) -> Action {
         self.ensure_policy_initialized(state, env);
 
         let available_actions = env.available_actions();
@@ -90,11 +92,8 @@ impl MonteCarloControl {
     }
 
     pub fn on_policy_mc_control(&mut self, env: &mut dyn Environment, num_episodes: usize, max_steps: usize) {
-        let mut rng = thread_rng();
-        let mut i = 0;
+        let mut rng = self.rng.clone();
         for _ in 0..num_episodes {
-            println!("{:?}", i);
-            i += 1;
             let mut episode: Vec<EpisodeStep> = vec![];
             let mut state = env.reset();
             let mut done = false;
@@ -110,6 +109,7 @@ impl MonteCarloControl {
             self.process_episode(episode);
             self.derive_and_assign_policy();
         }
+        self.rng = rng;
     }
 
     fn process_episode(&mut self, episode: Vec<EpisodeStep>) {
@@ -162,7 +162,7 @@ impl MonteCarloControl {
     }
 
     pub fn derive_and_assign_policy(&mut self) {
-        let mut derived_policy = HashMap::new();
+        let mut derived_policy = BTreeMap::new();
 
         for (&state, action_probs) in &self.derived_policy {
             let best_action = action_probs.iter().max_by(|a, b| a.1.partial_cmp(b.1).unwrap()).map(|(action, _)| *action).unwrap();
@@ -197,8 +197,4 @@ pub fn load_q_values(&mut self, filename: &str) -> Result<(), Box<dyn Error>> {
     self.derive_and_assign_policy();
     Ok(())
 }*/
-
-pub fn print_policy(&self) {
-    println!("Derived Policy: {:?}", self.policy);
 }
-}*/
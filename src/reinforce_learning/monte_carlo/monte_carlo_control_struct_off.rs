@@ -2,15 +2,14 @@ extern crate rand;
 extern crate serde;
 extern crate serde_json;
 
-use rand::seq::SliceRandom;
-use rand::thread_rng;
-use std::collections::HashMap;
+use rand::seq::IndexedRandom;
+use std::collections::BTreeMap;
 use std::error::Error;
 use serde::{Serialize, Deserialize};
 use std::fs::File;
 use std::io::{self};
+use crate::basic_function::random::SeededRng;
 use crate::environment::environment::{State, Action, Reward, Environment};
-/*
 #[derive(Clone, Debug)]
 pub struct EpisodeStep {
     pub state: State,
@@ -22,29 +21,31 @@ pub struct EpisodeStep {
 pub struct MonteCarloControlOff {
     pub epsilon: f32,
     pub gamma: f32,
-    pub derived_policy: HashMap<State, HashMap<Action, f32>>,
-    pub q_values: HashMap<(State, Action), Reward>,
-    pub c_values: HashMap<(State, Action), f32>,
-    pub policy: HashMap<State, Action>,
+    pub derived_policy: BTreeMap<State, BTreeMap<Action, f32>>,
+    pub q_values: BTreeMap<(State, Action), Reward>,
+    pub c_values: BTreeMap<(State, Action), f32>,
+    pub policy: BTreeMap<State, Action>,
+    #[serde(skip, default = "SeededRng::from_entropy")]
+    pub rng: SeededRng,
 }
 
 impl MonteCarloControlOff {
-    pub fn new(epsilon: f32, gamma: f32) -> Box<MonteCarloControlOff> {
+    pub fn new(epsilon: f32, gamma: f32, seed: u64) -> Box<MonteCarloControlOff> {
         Box::new(MonteCarloControlOff {
             epsilon,
             gamma,
-            derived_policy: HashMap::new(),
-            q_values: HashMap::new(),
-            c_values: HashMap::new(),
-            policy: HashMap::new(),
+            derived_policy: BTreeMap::new(),
+            q_values: BTreeMap::new(),
+            c_values: BTreeMap::new(),
+            policy: BTreeMap::new(),
+            rng: SeededRng::new(seed),
         })
     }
 
     pub fn off_policy_mc_control(&mut self, env: &mut dyn Environment, num_episodes: usize, max_steps: usize) {
-        let mut rng = thread_rng();
+        let mut rng = self.rng.clone();
 
-        for i in 0..num_episodes {
-            println!("{}",i);
+        for _ in 0..num_episodes {
             let mut episode: Vec<EpisodeStep> = vec![];
             let mut state = env.reset();
             let mut done = false;
@@ -63,11 +64,12 @@ impl MonteCarloControlOff {
             self.process_episode_off_policy(episode);
             self.derive_and_assign_policy();
         }
+        self.rng = rng;
     }
 
-    pub fn choose_action_soft(&mut self, env: &dyn Environment, state: State, rng: &mut rand::rngs::ThreadRng) -> Action {
+    pub fn choose_action_soft(&mut self, env: &dyn Environment, state: State, rng: &mut SeededRng) -> Action {
         if !self.derived_policy.contains_key(&state) {
-            let mut actions = HashMap::new();
+            let mut actions = BTreeMap::new();
             let available_actions = env.available_actions();
             for &action in &available_actions {
                 actions.insert(action, 1.0 / available_actions.len() as f32);
@@ -145,7 +147,7 @@ impl MonteCarloControlOff {
     }
 
     pub fn derive_and_assign_policy(&mut self) {
-        let mut derived_policy = HashMap::new();
+        let mut derived_policy = BTreeMap::new();
 
         for (&state, action_probs) in &self.derived_policy {
             let best_action = action_probs.iter().max_by(|a, b| a.1.partial_cmp(b.1).unwrap()).map(|(action, _)| *action).unwrap();
@@ -180,9 +182,4 @@ impl MonteCarloControlOff {
         self.derive_and_assign_policy();
         Ok(())
     }*/
-
-    pub fn print_policy(&self) {
-        println!("Derived Policy: {:?}", self.policy);
-    }
 }
-*/
\ No newline at end of file
@@ -3,12 +3,12 @@ extern crate serde;
 extern crate serde_json;
 
 use rand::seq::{IteratorRandom, SliceRandom};
-use rand::thread_rng;
 use serde::{Serialize, Deserialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::error::Error;
 use std::fs::File;
 use std::io::{self};
+use crate::basic_function::random::SeededRng;
 use crate::environment::environment::{State, Action, Reward, Environment};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -22,55 +22,53 @@ pub struct EpisodeStep {
 pub struct MonteCarloESModel {
     pub num_episodes: usize,
     pub gamma: f32,
-    pub max_steps: usize,
-    pub policy: HashMap<State, Action>,
-    pub q_values: HashMap<(State, Action), Reward>,
-    pub returns: HashMap<(State, Action), Vec<Reward>>,
+    pub policy: BTreeMap<State, Action>,
+    pub q_values: BTreeMap<(State, Action), Reward>,
+    pub returns: BTreeMap<(State, Action), Vec<Reward>>,
+    #[serde(skip, default = "SeededRng::from_entropy")]
+    pub rng: SeededRng,
 }
 impl MonteCarloESModel {
-    pub fn new(num_episodes: usize, gamma: f32, max_steps: usize) -> Box<MonteCarloESModel> {
+    pub fn new(num_episodes: usize, gamma: f32, seed: u64) -> Box<MonteCarloESModel> {
         Box::new(MonteCarloESModel {
             num_episodes,
             gamma,
-            max_steps,
-            policy: HashMap::new(),
-            q_values: HashMap::new(),
-            returns: HashMap::new(),
+            policy: BTreeMap::new(),
+            q_values: BTreeMap::new(),
+            returns: BTreeMap::new(),
+            rng: SeededRng::new(seed),
         })
     }
 
+    /// Episodes end when the environment reports done, a greedy policy can loop forever so wrap
+    /// the environment in a TimeLimit when it has no other end.
     pub fn monte_carlo_es(&mut self, env: &mut dyn Environment) {
-        let mut rng = thread_rng();
-        let mut i = 0;
         for _ in 0..self.num_episodes {
             env.random_state();
             env.state_id();
             //env.reset();
-            println!("{}", i);
-            i = i + 1;
             let mut is_first_action = true;
             let mut trajectory: Vec<EpisodeStep> = Vec::new();
-            let mut steps_count = 0;
 
-            while steps_count < self.max_steps {
+            loop {
                 let state = env.state_id();
 
                 let available_actions = env.available_actions();
 
                 // Assurer que chaque état a une politique initiale
                 if !self.policy.contains_key(&state) {
-                    let action = available_actions.clone().into_iter().choose(&mut rng).unwrap();
+                    let action = available_actions.clone().into_iter().choose(&mut self.rng).unwrap();
                     self.policy.insert(state, action);
                 }
 
                 let action = if is_first_action {
                     is_first_action = false;
-                    available_actions.into_iter().choose(&mut rng).unwrap()
+                    available_actions.into_iter().choose(&mut self.rng).unwrap()
                 } else {
                     *self.policy.get(&state).unwrap()
                 };
 
-                let (new_state, reward, done) = env.step(action);
+                let (_, reward, done) = env.step(action);
 
                 trajectory.push(EpisodeStep {
                     state,
@@ -78,8 +76,6 @@ impl MonteCarloESModel {
                     reward,
                 });
 
-                steps_count += 1;
-
                 if done {
                     break;
                 }
@@ -113,8 +109,8 @@ impl MonteCarloESModel {
         }
     }
 
-    pub fn derive_policy(&self) -> HashMap<State, Action> {
-        let mut policy = HashMap::new();
+    pub fn derive_policy(&self) -> BTreeMap<State, Action> {
+        let mut policy = BTreeMap::new();
 
         for (&(state, action), &q_value) in &self.q_values {
             if let Some(&best_action) = policy.get(&state) {
@@ -126,7 +122,6 @@ impl MonteCarloESModel {
             }
         }
 
-        println!("Derived policy: {:?}", policy);
         policy
     }
 
@@ -3,10 +3,11 @@ extern crate serde;
 extern crate serde_json;
 
 use rand::seq::SliceRandom;
-use rand::{Rng, thread_rng};
-use std::collections::HashMap;
+use rand::Rng;
+use std::collections::BTreeMap;
 use std::error::Error;
 use rand::prelude::IteratorRandom;
+use crate::basic_function::random::SeededRng;
 use crate::environment::environment::{State, Action, Reward, Environment};
 use serde::{Serialize, Deserialize};
 use std::fs::File;
@@ -26,33 +27,33 @@ pub struct DynaQModel {
     pub alpha: f32,
     pub epsilon: f32,
     pub planning_steps: usize, // Number of planning steps
-    pub q_values: HashMap<(State, Action), Reward>,
-    pub model: HashMap<(State, Action), (Reward, State)>,
-    pub policy: HashMap<State, Action>,
+    pub q_values: BTreeMap<(State, Action), Reward>,
+    pub model: BTreeMap<(State, Action), (Reward, State)>,
+    pub policy: BTreeMap<State, Action>,
+    #[serde(skip, default = "SeededRng::from_entropy")]
+    pub rng: SeededRng,
 }
 
 impl DynaQModel {
-    pub fn new(iterations: usize, gamma: f32, alpha: f32, epsilon: f32, planning_steps: usize) -> Box<DynaQModel> {
+    pub fn new(iterations: usize, gamma: f32, alpha: f32, epsilon: f32, planning_steps: usize, seed: u64) -> Box<DynaQModel> {
         Box::new(DynaQModel {
             iterations,
             gamma,
             alpha,
             epsilon,
             planning_steps,
-            q_values: HashMap::new(),
-            model: HashMap::new(),
-            policy: HashMap::new(),
+            q_values: BTreeMap::new(),
+            model: BTreeMap::new(),
+            policy: BTreeMap::new(),
+            rng: SeededRng::new(seed),
         })
     }
 
     pub fn dyna_q(&mut self, env: &mut dyn Environment) {
-        let mut rng = thread_rng();
-        let mut i = 0;
+        let mut rng = self.rng.clone();
         for _ in 0..self.iterations {
             // Get current nonterminal state S
             let mut state = env.reset();
-            println!("{}", i);
-            i += 1;
             loop {
                 // Choose action A using epsilon-greedy policy
                 let available_actions = env.available_actions();
@@ -86,6 +87,7 @@ impl DynaQModel {
                 }
             }
         }
+        self.rng = rng;
     }
 
     fn max_q_value(&self, state: State, actions: &[Action]) -> f32 {
@@ -95,8 +97,8 @@ impl DynaQModel {
             .fold(std::f64::MIN, |a, b| a.max(b as f64)) as f32
     }
 
-    fn epsilon_greedy(&self, state: State, actions: &[Action], rng: &mut rand::prelude::ThreadRng) -> Action {
-        if rng.gen::<f32>() < self.epsilon {
+    fn epsilon_greedy(&self, state: State, actions: &[Action], rng: &mut SeededRng) -> Action {
+        if rng.random::<f32>() < self.epsilon {
             *actions.into_iter().choose(rng).unwrap()
         } else {
             actions
@@ -113,8 +115,8 @@ impl DynaQModel {
         }
     }
 
-    pub fn derive_policy(&self) -> HashMap<State, Action> {
-        let mut policy = HashMap::new();
+    pub fn derive_policy(&self) -> BTreeMap<State, Action> {
+        let mut policy = BTreeMap::new();
 
         for (&(state, action), &q_value) in &self.q_values {
             if let Some(&best_action) = policy.get(&state) {
@@ -129,10 +131,6 @@ impl DynaQModel {
         policy
     }
 
-    pub fn print_policy(&self) {
-        println!("Policy: {:?}", self.policy);
-    }
-
     pub fn save_policy(&self, filename: &str) -> io::Result<()> {
         let file = File::create(filename)?;
         serde_json::to_writer(file, &self.policy)?;
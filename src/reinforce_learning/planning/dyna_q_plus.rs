@@ -3,10 +3,11 @@ extern crate serde;
 extern crate serde_json;
 
 use rand::seq::SliceRandom;
-use rand::{Rng, thread_rng};
-use std::collections::HashMap;
+use rand::Rng;
+use std::collections::BTreeMap;
 use std::error::Error;
 use rand::prelude::IteratorRandom;
+use crate::basic_function::random::SeededRng;
 use crate::environment::environment::{State, Action, Reward, Environment};
 use serde::{Serialize, Deserialize};
 use std::fs::File;
@@ -27,14 +28,16 @@ pub struct DynaQPlusModel {
     pub epsilon: f32,
     pub planning_steps: usize, // Number of planning steps
     pub kappa: f32, // New parameter for Dyna-Q+
-    pub q_values: HashMap<(State, Action), Reward>,
-    pub model: HashMap<(State, Action), (Reward, State)>,
-    pub time_since_last_action: HashMap<(State, Action), usize>, // Track time since last action
-    pub policy: HashMap<State, Action>, // Policy map
+    pub q_values: BTreeMap<(State, Action), Reward>,
+    pub model: BTreeMap<(State, Action), (Reward, State)>,
+    pub time_since_last_action: BTreeMap<(State, Action), usize>, // Track time since last action
+    pub policy: BTreeMap<State, Action>, // Policy map
+    #[serde(skip, default = "SeededRng::from_entropy")]
+    pub rng: SeededRng,
 }
 
 impl DynaQPlusModel {
-    pub fn new(iterations: usize, gamma: f32, alpha: f32, epsilon: f32, planning_steps: usize, kappa: f32) -> Box<DynaQPlusModel> {
+    pub fn new(iterations: usize, gamma: f32, alpha: f32, epsilon: f32, planning_steps: usize, kappa: f32, seed: u64) -> Box<DynaQPlusModel> {
         Box::new(DynaQPlusModel {
             iterations,
             gamma,
@@ -42,15 +45,16 @@ impl DynaQPlusModel {
             epsilon,
             planning_steps,
             kappa,
-            q_values: HashMap::new(),
-            model: HashMap::new(),
-            time_since_last_action: HashMap::new(), // Initialize the tracking map
-            policy: HashMap::new(), // Initialize policy map
+            q_values: BTreeMap::new(),
+            model: BTreeMap::new(),
+            time_since_last_action: BTreeMap::new(), // Initialize the tracking map
+            policy: BTreeMap::new(), // Initialize policy map
+            rng: SeededRng::new(seed),
         })
     }
 
     pub fn dyna_q_plus(&mut self, env: &mut dyn Environment) {
-        let mut rng = thread_rng();
+        let mut rng = self.rng.clone();
 
         for _ in 0..self.iterations {
             // Get current nonterminal state S
@@ -96,6 +100,7 @@ impl DynaQPlusModel {
                 }
             }
         }
+        self.rng = rng;
         self.derive_and_assign_policy(); // Update policy after training
     }
 
@@ -106,8 +111,8 @@ impl DynaQPlusModel {
             .fold(std::f64::MIN, |a, b| a.max(b as f64)) as f32
     }
 
-    fn epsilon_greedy(&self, state: State, actions: &[Action], rng: &mut rand::prelude::ThreadRng) -> Action {
-        if rng.gen::<f32>() < self.epsilon {
+    fn epsilon_greedy(&self, state: State, actions: &[Action], rng: &mut SeededRng) -> Action {
+        if rng.random::<f32>() < self.epsilon {
             *actions.into_iter().choose(rng).unwrap()
         } else {
             actions
@@ -135,8 +140,8 @@ impl DynaQPlusModel {
         }
     }
 
-    pub fn derive_policy(&self) -> HashMap<State, Action> {
-        let mut policy = HashMap::new();
+    pub fn derive_policy(&self) -> BTreeMap<State, Action> {
+        let mut policy = BTreeMap::new();
 
         for (&(state, action), &q_value) in &self.q_values {
             if let Some(&best_action) = policy.get(&state) {
@@ -151,10 +156,6 @@ impl DynaQPlusModel {
         policy
     }
 
-    pub fn print_policy(&self) {
-        println!("Policy: {:?}", self.policy);
-    }
-
     pub fn save_policy(&self, filename: &str) -> io::Result<()> {
         let file = File::create(filename)?;
         serde_json::to_writer(file, &self.policy)?;
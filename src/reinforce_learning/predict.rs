@@ -0,0 +1,28 @@
+use super::tabular::TabularModel;
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, FfiError, Status};
+use crate::ffi::handle::{self, Handle};
+
+#[no_mangle]
+pub(crate) extern "C" fn tabular_action(model: Handle, state: i32, action: *mut i32) -> Status {
+    ffi_call(|| handle::with(model, |model: &TabularModel| {
+        let state = args::length(state, "state")?;
+        let best = model.action(state)
+            .ok_or_else(|| FfiError::invalid(format!("state {} is not in the policy", state)))?;
+        args::write(action, best as i32, "action")
+    }))
+}
+
+// Copy the policy indexed by state, -1 for states the model never visited, `written` is the number of states written
+#[no_mangle]
+pub(crate) extern "C" fn tabular_policy(model: Handle, actions: *mut i32, actions_len: i32, written: *mut i32) -> Status {
+    ffi_call(|| handle::with(model, |model: &TabularModel| {
+        let actions_slice = args::slice_mut(actions, actions_len, "actions")?;
+        let policy = model.policy();
+        let count = policy.len().min(actions_slice.len());
+        for (slot, action) in actions_slice.iter_mut().zip(&policy) {
+            *slot = action.map_or(-1, |action| action as i32);
+        }
+        args::write(written, count as i32, "written")
+    }))
+}
@@ -0,0 +1,84 @@
+// One handle type for the tabular learners exposed to the host, the exports dispatch on the variant.
+use crate::environment::environment::{Action, Environment, State};
+use crate::reinforce_learning::dynamic_programming::policy_iteration::PolicyIterationModel;
+use crate::reinforce_learning::dynamic_programming::value_iteration::ValueIterationModel;
+use crate::reinforce_learning::monte_carlo::monte_carlo_es::MonteCarloESModel;
+use crate::reinforce_learning::planning::dyna_q::DynaQModel;
+use crate::reinforce_learning::planning::dyna_q_plus::DynaQPlusModel;
+use crate::reinforce_learning::td_learning::q_learning::QLearning;
+use crate::reinforce_learning::td_learning::sarsa::SarsaModel;
+
+pub enum TabularModel {
+    QLearning(QLearning),
+    Sarsa(SarsaModel),
+    DynaQ(DynaQModel),
+    DynaQPlus(DynaQPlusModel),
+    MonteCarloES(MonteCarloESModel),
+    PolicyIteration(PolicyIterationModel),
+    // Stops once no state value moves more than theta
+    ValueIteration(ValueIterationModel, f32),
+}
+
+impl TabularModel {
+    /// Dynamic programming learns from its transition arrays and needs no environment.
+    pub fn needs_environment(&self) -> bool {
+        !matches!(self, TabularModel::PolicyIteration(_) | TabularModel::ValueIteration(..))
+    }
+
+    /// Number of states the model was built for, None when it learns them while training.
+    pub fn num_states(&self) -> Option<usize> {
+        match self {
+            TabularModel::Sarsa(model) => Some(model.num_states),
+            TabularModel::PolicyIteration(model) => Some(model.states.len()),
+            TabularModel::ValueIteration(model, _) => Some(model.states.len()),
+            _ => None,
+        }
+    }
+
+    pub fn train(&mut self, env: Option<&mut dyn Environment>) {
+        match (self, env) {
+            (TabularModel::QLearning(model), Some(env)) => model.q_learning(env),
+            (TabularModel::Sarsa(model), Some(env)) => {
+                model.process_episode(env);
+            }
+            (TabularModel::DynaQ(model), Some(env)) => {
+                model.dyna_q(env);
+                model.derive_and_assign_policy();
+            }
+            (TabularModel::DynaQPlus(model), Some(env)) => model.dyna_q_plus(env),
+            (TabularModel::MonteCarloES(model), Some(env)) => model.monte_carlo_es(env),
+            (TabularModel::PolicyIteration(model), _) => {
+                model.policy_iteration();
+            }
+            (TabularModel::ValueIteration(model, theta), _) => model.iteration(*theta),
+            (_, None) => panic!("this model trains on an environment"),
+        }
+    }
+
+    /// Greedy action of the learned policy, None for a state never visited.
+    pub fn action(&self, state: State) -> Option<Action> {
+        match self {
+            TabularModel::QLearning(model) => model.policy.get(&state).copied(),
+            TabularModel::DynaQ(model) => model.policy.get(&state).copied(),
+            TabularModel::DynaQPlus(model) => model.policy.get(&state).copied(),
+            TabularModel::MonteCarloES(model) => model.policy.get(&state).copied(),
+            TabularModel::Sarsa(model) => model.policy.get(state).copied(),
+            TabularModel::PolicyIteration(model) => model.policy.get(state).copied(),
+            TabularModel::ValueIteration(model, _) => model.policy.get(state).copied(),
+        }
+    }
+
+    /// Policy indexed by state, up to the largest state the model knows.
+    pub fn policy(&self) -> Vec<Option<Action>> {
+        let len = match self {
+            TabularModel::QLearning(model) => model.policy.keys().max().map_or(0, |&state| state + 1),
+            TabularModel::DynaQ(model) => model.policy.keys().max().map_or(0, |&state| state + 1),
+            TabularModel::DynaQPlus(model) => model.policy.keys().max().map_or(0, |&state| state + 1),
+            TabularModel::MonteCarloES(model) => model.policy.keys().max().map_or(0, |&state| state + 1),
+            TabularModel::Sarsa(model) => model.policy.len(),
+            TabularModel::PolicyIteration(model) => model.policy.len(),
+            TabularModel::ValueIteration(model, _) => model.policy.len(),
+        };
+        (0..len).map(|state| self.action(state)).collect()
+    }
+}
@@ -2,42 +2,44 @@ extern crate rand;
 extern crate serde;
 extern crate serde_json;
 
-use rand::seq::SliceRandom;
-use rand::{Rng, thread_rng};
-use std::collections::HashMap;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+use std::collections::BTreeMap;
 use std::error::Error;
+use crate::basic_function::random::SeededRng;
 use crate::environment::environment::{State, Action, Reward, Environment};
 use serde::{Serialize, Deserialize};
 use std::fs::File;
 use std::io::{self};
-/*
 #[derive(Serialize, Deserialize)]
 pub struct QLearning {
     pub iterations: usize,
     pub gamma: f32,
     pub alpha: f32,
     pub epsilon: f32,
-    pub q_values: HashMap<(State, Action), Reward>,
-    pub policy: HashMap<State, Action>,
+    pub q_values: BTreeMap<(State, Action), Reward>,
+    pub policy: BTreeMap<State, Action>,
+    #[serde(skip, default = "SeededRng::from_entropy")]
+    pub rng: SeededRng,
 }
 
 impl QLearning {
-    pub fn new(iterations: usize, gamma: f32, alpha: f32, epsilon: f32) -> Box<QLearning> {
+    pub fn new(iterations: usize, gamma: f32, alpha: f32, epsilon: f32, seed: u64) -> Box<QLearning> {
         Box::new(QLearning {
             iterations,
             gamma,
             alpha,
             epsilon,
-            q_values: HashMap::new(),
-            policy: HashMap::new(),
+            q_values: BTreeMap::new(),
+            policy: BTreeMap::new(),
+            rng: SeededRng::new(seed),
         })
     }
 
     pub fn q_learning(&mut self, env: &mut dyn Environment) {
-        let mut rng = thread_rng();
+        let mut rng = self.rng.clone();
 
-        for i in 0..self.iterations {
-            println!("Iteration: {}", i);
+        for _ in 0..self.iterations {
             // Get current nonterminal state S
             let mut state = env.reset();
 
@@ -62,6 +64,7 @@ impl QLearning {
                 }
             }
         }
+        self.rng = rng;
         self.derive_and_assign_policy(); // Update policy after training
     }
 
@@ -72,8 +75,8 @@ impl QLearning {
             .fold(std::f32::MIN, |a, b| a.max(b))
     }
 
-    fn epsilon_greedy(&self, state: State, actions: &[Action], rng: &mut rand::prelude::ThreadRng) -> Action {
-        if rng.gen::<f32>() < self.epsilon {
+    fn epsilon_greedy(&self, state: State, actions: &[Action], rng: &mut SeededRng) -> Action {
+        if rng.random::<f32>() < self.epsilon {
             *actions.choose(rng).unwrap()
         } else {
             actions
@@ -90,8 +93,8 @@ impl QLearning {
         }
     }
 
-    pub fn derive_policy(&self) -> HashMap<State, Action> {
-        let mut policy = HashMap::new();
+    pub fn derive_policy(&self) -> BTreeMap<State, Action> {
+        let mut policy = BTreeMap::new();
 
         for (&(state, action), &q_value) in &self.q_values {
             if let Some(&best_action) = policy.get(&state) {
@@ -106,10 +109,6 @@ impl QLearning {
         policy
     }
 
-    pub fn print_policy(&self) {
-        println!("Policy: {:?}", self.policy);
-    }
-
     pub fn save_policy(&self, filename: &str) -> io::Result<()> {
         let file = File::create(filename)?;
         serde_json::to_writer(file, &self.policy)?;
@@ -140,4 +139,3 @@ pub fn derive_and_assign_policy(&mut self) {
     self.policy = self.derive_policy();
 }
 }
-*/
\ No newline at end of file
@@ -2,16 +2,16 @@ extern crate rand;
 extern crate serde;
 extern crate serde_json;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
 use std::error::Error;
 use rand::Rng;
-use rand::seq::SliceRandom;
+use rand::seq::IndexedRandom;
 use serde::{Deserialize, Serialize};
+use crate::basic_function::random::SeededRng;
 use crate::environment::environment::{Environment, State, Action, Reward};
 use std::io::{self};
 
-/*
 #[derive(Serialize, Deserialize)]
 pub struct SarsaModel {
     pub alpha: f32,
@@ -21,11 +21,13 @@ pub struct SarsaModel {
     pub num_states: usize,
     pub num_actions: usize,
     pub policy: Vec<Action>,
-    pub q_values: HashMap<(State, Action), Reward>
+    pub q_values: BTreeMap<(State, Action), Reward>,
+    #[serde(skip, default = "SeededRng::from_entropy")]
+    pub rng: SeededRng,
 }
 
 impl SarsaModel {
-    pub fn new(env: &mut dyn Environment, alpha: f32, gamma:f32, epsilon:f64, nb_episode: usize) -> Box<SarsaModel>{
+    pub fn new(env: &mut dyn Environment, alpha: f32, gamma:f32, epsilon:f64, nb_episode: usize, seed: u64) -> Box<SarsaModel>{
         let ns = env.all_states().len();
         let na = env.available_actions().len();
         let model = Box::new(SarsaModel {
@@ -36,46 +38,42 @@ impl SarsaModel {
             epsilon,
             nb_episode,
             policy: vec![0; ns],
-            q_values: HashMap::new()
+            q_values: BTreeMap::new(),
+            rng: SeededRng::new(seed),
         });
         model
     }
 
+    /// Episodes end when the environment reports done, wrap it in a TimeLimit to cap their length.
     pub fn process_episode(&mut self, env: &mut dyn Environment) -> Vec<Action>{
-        for num_episode in 0..self.nb_episode {
-            println!("Episode n°{} start", num_episode);
+        for _ in 0..self.nb_episode {
             env.reset();
-            /*let mut state: State = if rand == false { 0usize } else {
-                let mut rng = rand::thread_rng();
-                rng.gen_range(0..self.num_states-1usize)
-            } as State;*/
             let mut action = self.chose_action(env, env.state_id());
 
-            let mut count = 0;
             loop {
                 let state = env.state_id();
-
-                let (new_state, reward) = self.do_action(env, action);
-                let new_action = self.chose_action(env, env.state_id());
+                let (new_state, reward, done) = env.step(action);
 
                 let q_value_key = (state, action);
-                let next_q_value_key = (new_state, new_action);
-
                 let q_value = *self.q_values.entry(q_value_key).or_insert(0.0);
-                let next_q_value = *self.q_values.entry(next_q_value_key).or_insert(0.0);
 
-                let updated_q_value = q_value + self.alpha * (reward + self.gamma * next_q_value - q_value);
+                // No action is taken from a terminal state, its value is zero. A truncated
+                // episode stops in a state that still has a value, so it bootstraps as usual.
+                if done && !env.truncated() {
+                    self.q_values.insert(q_value_key, q_value + self.alpha * (reward - q_value));
+                    break;
+                }
 
+                let new_action = self.chose_action(env, env.state_id());
+                let next_q_value = *self.q_values.entry((new_state, new_action)).or_insert(0.0);
+                let updated_q_value = q_value + self.alpha * (reward + self.gamma * next_q_value - q_value);
                 self.q_values.insert(q_value_key, updated_q_value);
 
+                if done {
+                    break;
+                }
                 action = new_action;
-
-                if new_state == state { count += 1; }
-                else { count = 0; }
-
-                if new_state == (self.num_states - 1) as State || count > 20 { break; }
             }
-            println!("Episode n°{} end", num_episode);
         }
         self.derive_policy()
     }
@@ -102,11 +100,10 @@ impl SarsaModel {
     fn chose_action(&mut self, env: &dyn Environment, state: State) -> Action {
         let available_actions = env.available_actions();
 
-        let mut rng = rand::thread_rng();
-        let rand = rng.gen_range(0.0..=1.0);
+        let rand = self.rng.random_range(0.0..=1.0);
         let best_action = if rand < self.epsilon {
             // random
-            *available_actions.into_iter().choose(&mut rng).unwrap()
+            *available_actions.choose(&mut self.rng).unwrap()
             //rng.gen_range(0..self.num_actions)
         } else {
             // best action
@@ -120,19 +117,12 @@ impl SarsaModel {
                 })
                 .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or_else(|| a.0.total_cmp(&b.0))) // Trouver la valeur maximale
                 .map(|(_, action)| action) // Extraire l'action
-                .unwrap_or( *available_actions.choose(&mut rng).unwrap() ) as Action;
+                .unwrap_or( *available_actions.choose(&mut self.rng).unwrap() ) as Action;
             action
         };
         best_action
     }
 
-    fn do_action(&mut self, env: &mut dyn Environment, action: Action) -> (State, Reward) {
-        println!("\nstate :{}, action : {}", env.state_id(), action);
-        let tuple: (State, Reward, bool) = env.step(action as Action);
-        env.display();
-        return (tuple.0, tuple.1)
-    }
-
     fn policy_to_hashmap(&self) -> HashMap<usize, usize> { // Changed to usize
         let mut policy_map = HashMap::new();
         for (state, &action) in self.policy.iter().enumerate() {
@@ -172,4 +162,4 @@ pub fn load_q_values(&mut self, filename: &str) -> Result<(), Box<dyn Error>> {
     self.derive_policy();
     Ok(())
 }*/
-}*/
\ No newline at end of file
+}
\ No newline at end of file
@@ -0,0 +1,301 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::ffi::{c_void, CStr};
+    use crate::environment::environment::{Action, Environment, Reward, State};
+    use crate::environment::host_environment::EnvironmentCallbacks;
+    use crate::environment::line_world::LineWorld;
+    use crate::environment::wrappers::TimeLimit;
+    use crate::ffi::error::{rustml_last_error, Status};
+    use crate::ffi::handle::Handle;
+    use crate::ffi::test_support::{created, output, taken};
+    use crate::reinforce_learning::create::{init_dyna_q, init_dyna_q_plus, init_monte_carlo_es, init_policy_iteration,
+                                            init_q_learning, init_sarsa, init_value_iteration};
+    use crate::reinforce_learning::monte_carlo::monte_carlo_control_struct::MonteCarloControl;
+    use crate::reinforce_learning::monte_carlo::monte_carlo_control_struct_off::MonteCarloControlOff;
+    use crate::reinforce_learning::predict::{tabular_action, tabular_policy};
+    use crate::reinforce_learning::tabular::TabularModel;
+    use crate::reinforce_learning::td_learning::sarsa::SarsaModel;
+    use crate::reinforce_learning::train::train_tabular;
+
+    // Host side of the bridge, user_data is the boxed environment
+    fn host_env<'a>(user_data: *mut c_void) -> &'a mut Box<dyn Environment> {
+        unsafe { &mut *(user_data as *mut Box<dyn Environment>) }
+    }
+
+    extern "C" fn reset(user_data: *mut c_void) -> i32 {
+        host_env(user_data).reset() as i32
+    }
+
+    extern "C" fn step(user_data: *mut c_void, action: i32, next_state: *mut i32, reward: *mut f32, done: *mut bool) {
+        let (state, step_reward, step_done) = host_env(user_data).step(action as usize);
+        unsafe {
+            *next_state = state as i32;
+            *reward = step_reward;
+            *done = step_done;
+        }
+    }
+
+    extern "C" fn available_actions(user_data: *mut c_void, actions: *mut i32, capacity: i32) -> i32 {
+        let available = host_env(user_data).available_actions();
+        let actions = unsafe { std::slice::from_raw_parts_mut(actions, capacity as usize) };
+        for (slot, &action) in actions.iter_mut().zip(&available) {
+            *slot = action as i32;
+        }
+        available.len() as i32
+    }
+
+    extern "C" fn state_id(user_data: *mut c_void) -> i32 {
+        host_env(user_data).state_id() as i32
+    }
+
+    extern "C" fn out_of_range(_user_data: *mut c_void) -> i32 {
+        7
+    }
+
+    fn callbacks(env: &mut Box<dyn Environment>) -> EnvironmentCallbacks {
+        EnvironmentCallbacks {
+            user_data: env as *mut Box<dyn Environment> as *mut c_void,
+            num_states: env.all_states().len() as i32,
+            num_actions: env.all_action().len() as i32,
+            reset: Some(reset),
+            step: Some(step),
+            available_actions: Some(available_actions),
+            state_id: Some(state_id),
+            random_state: None,
+        }
+    }
+
+    fn last_error() -> String {
+        unsafe { CStr::from_ptr(rustml_last_error()) }.to_string_lossy().into_owned()
+    }
+
+    // Moving right (action 2) from every inner cell reaches the +1 end
+    fn assert_moves_right(model: Handle) {
+        for state in 1..4 {
+            assert_eq!(output(|action| tabular_action(model, state, action)), 2, "state {}", state);
+        }
+    }
+
+    // LineWorld of 5 cells, episodes cut after 20 steps since a greedy policy may keep staying
+    fn line_world(seed: u64) -> Box<dyn Environment> {
+        Box::new(TimeLimit::new(LineWorld::new(5, false, 2, seed), 20))
+    }
+
+    #[test]
+    fn learners_solve_host_line_world() {
+        let mut env = line_world(0);
+        let env_callbacks = callbacks(&mut env);
+        let models = [
+            created(|out| init_q_learning(500, 0.9, 0.1, 0.2, 0, out)),
+            created(|out| init_sarsa(5, 3, 0.1, 0.9, 0.2, 500, 0, out)),
+            created(|out| init_dyna_q(200, 0.9, 0.1, 0.2, 5, 0, out)),
+            created(|out| init_dyna_q_plus(200, 0.9, 0.1, 0.2, 5, 0.001, 0, out)),
+            created(|out| init_monte_carlo_es(2000, 0.9, 0, out)),
+        ];
+
+        for model in models {
+            assert_eq!(train_tabular(model, Some(&env_callbacks)), Status::Ok);
+            assert_moves_right(model);
+            taken::<TabularModel>(model);
+        }
+    }
+
+    // Stays in its last state for 30 steps before reporting done
+    #[derive(Default)]
+    struct Corridor {
+        steps: usize,
+        episode_lengths: Vec<usize>,
+    }
+
+    fn corridor<'a>(user_data: *mut c_void) -> &'a mut Corridor {
+        unsafe { &mut *(user_data as *mut Corridor) }
+    }
+
+    extern "C" fn corridor_reset(user_data: *mut c_void) -> i32 {
+        corridor(user_data).steps = 0;
+        0
+    }
+
+    extern "C" fn corridor_step(user_data: *mut c_void, _action: i32, next_state: *mut i32, reward: *mut f32, done: *mut bool) {
+        let env = corridor(user_data);
+        env.steps += 1;
+        if env.steps == 30 {
+            env.episode_lengths.push(env.steps);
+        }
+        unsafe {
+            *next_state = 1;
+            *reward = 0.0;
+            *done = env.steps == 30;
+        }
+    }
+
+    extern "C" fn corridor_actions(_user_data: *mut c_void, actions: *mut i32, capacity: i32) -> i32 {
+        if capacity > 0 {
+            unsafe { *actions = 0 };
+        }
+        1
+    }
+
+    extern "C" fn corridor_state(user_data: *mut c_void) -> i32 {
+        (corridor(user_data).steps > 0) as i32
+    }
+
+    #[test]
+    fn episodes_end_on_done() {
+        let mut env = Corridor::default();
+        let env_callbacks = EnvironmentCallbacks {
+            user_data: &mut env as *mut Corridor as *mut c_void,
+            num_states: 2,
+            num_actions: 1,
+            reset: Some(corridor_reset),
+            step: Some(corridor_step),
+            available_actions: Some(corridor_actions),
+            state_id: Some(corridor_state),
+            random_state: None,
+        };
+        let models = [
+            created(|out| init_sarsa(2, 1, 0.1, 0.9, 0.2, 3, 0, out)),
+            created(|out| init_monte_carlo_es(3, 0.9, 0, out)),
+        ];
+
+        for model in models {
+            assert_eq!(train_tabular(model, Some(&env_callbacks)), Status::Ok);
+            taken::<TabularModel>(model);
+            assert_eq!(std::mem::take(&mut env.episode_lengths), vec![30, 30, 30]);
+        }
+    }
+
+    #[test]
+    fn sarsa_bootstraps_truncated_episodes() {
+        // One step per episode, the first reset of the seed lands on an inner cell
+        let start = LineWorld::new(5, false, 2, 3).reset();
+        assert!((1..4).contains(&start));
+        let mut env = TimeLimit::new(LineWorld::new(5, false, 2, 3), 1);
+
+        // Greedy on staying, whose value is 1 everywhere
+        let mut model = SarsaModel::new(&mut env, 1.0, 0.5, 0.0, 1, 0);
+        for state in 0..5 {
+            model.q_values.extend([((state, 0), 1.0), ((state, 1), 0.0), ((state, 2), 0.0)]);
+        }
+        model.process_episode(&mut env);
+        assert!(env.truncated());
+        // Cut after staying, so the reward of 0 plus half the value of the state kept
+        assert_eq!(model.q_values[&(start, 0)], 0.5);
+    }
+
+    fn q_values(model: &TabularModel) -> BTreeMap<(State, Action), Reward> {
+        match model {
+            TabularModel::QLearning(model) => model.q_values.clone(),
+            TabularModel::Sarsa(model) => model.q_values.clone(),
+            TabularModel::DynaQ(model) => model.q_values.clone(),
+            TabularModel::DynaQPlus(model) => model.q_values.clone(),
+            TabularModel::MonteCarloES(model) => model.q_values.clone(),
+            _ => BTreeMap::new(),
+        }
+    }
+
+    // Trains the model of `init` on a LineWorld whose resets come from `seed`
+    fn trained_q_values(init: impl Fn(*mut Handle) -> Status, seed: u64) -> BTreeMap<(State, Action), Reward> {
+        let mut env = line_world(seed);
+        let model = created(init);
+        assert_eq!(train_tabular(model, Some(&callbacks(&mut env))), Status::Ok);
+        q_values(&taken::<TabularModel>(model))
+    }
+
+    #[test]
+    fn same_seed_learns_the_same_q_values() {
+        let learners: [(&str, fn(u64, *mut Handle) -> Status); 5] = [
+            ("q_learning", |seed, out| init_q_learning(100, 0.9, 0.1, 0.5, seed, out)),
+            ("sarsa", |seed, out| init_sarsa(5, 3, 0.1, 0.9, 0.5, 100, seed, out)),
+            ("dyna_q", |seed, out| init_dyna_q(50, 0.9, 0.1, 0.5, 5, seed, out)),
+            ("dyna_q_plus", |seed, out| init_dyna_q_plus(50, 0.9, 0.1, 0.5, 5, 0.001, seed, out)),
+            ("monte_carlo_es", |seed, out| init_monte_carlo_es(200, 0.9, seed, out)),
+        ];
+
+        for (name, init) in learners {
+            let first = trained_q_values(|out| init(7, out), 7);
+            assert!(!first.is_empty(), "{}", name);
+            assert_eq!(first, trained_q_values(|out| init(7, out), 7), "{}", name);
+        }
+        let init = |seed, out| init_q_learning(100, 0.9, 0.1, 0.5, seed, out);
+        assert_ne!(trained_q_values(|out| init(7, out), 7), trained_q_values(|out| init(8, out), 8));
+
+        // The Monte Carlo control learners have no C export, they train on the environment directly
+        let on_policy = |seed| {
+            let mut model = MonteCarloControl::new(0.2, 0.9, seed);
+            model.on_policy_mc_control(&mut *line_world(seed), 100, 20);
+            model.q_values
+        };
+        let off_policy = |seed| {
+            let mut model = MonteCarloControlOff::new(0.2, 0.9, seed);
+            model.off_policy_mc_control(&mut *line_world(seed), 100, 20);
+            model.q_values
+        };
+        assert!(!on_policy(7).is_empty() && !off_policy(7).is_empty());
+        assert_eq!(on_policy(7), on_policy(7));
+        assert_eq!(off_policy(7), off_policy(7));
+    }
+
+    #[test]
+    fn value_iteration_from_transition_arrays() {
+        let env = LineWorld::new(5, false, 2, 0);
+        let rewards: Vec<f32> = env.rewards.concat().concat();
+        let probabilities: Vec<f32> = env.probabilities.concat().concat();
+        let terminal = [0, 4];
+
+        let model = created(|out| init_value_iteration(5, 3, rewards.as_ptr(), rewards.len() as i32,
+                                                       probabilities.as_ptr(), probabilities.len() as i32,
+                                                       0.9, terminal.as_ptr(), 2, 0.001, 0, out));
+        // Dynamic programming does not need the host environment
+        assert_eq!(train_tabular(model, None), Status::Ok);
+        assert_moves_right(model);
+
+        let mut policy = [0; 8];
+        assert_eq!(output(|written| tabular_policy(model, policy.as_mut_ptr(), 8, written)), 5);
+        assert_eq!(policy[1..4], [2, 2, 2]);
+        taken::<TabularModel>(model);
+
+        let policy_iteration = created(|out| init_policy_iteration(5, 3, rewards.as_ptr(), rewards.len() as i32,
+                                                                   probabilities.as_ptr(), probabilities.len() as i32,
+                                                                   0.9, terminal.as_ptr(), 2, 0, out));
+        assert_eq!(train_tabular(policy_iteration, None), Status::Ok);
+        assert_eq!(output(|written| tabular_policy(policy_iteration, policy.as_mut_ptr(), 8, written)), 5);
+
+        let mut invalid = 0;
+        assert_eq!(init_value_iteration(5, 3, rewards.as_ptr(), 10, probabilities.as_ptr(), probabilities.len() as i32,
+                                        0.9, terminal.as_ptr(), 2, 0.001, 0, &mut invalid), Status::DimensionMismatch);
+        assert_eq!(init_policy_iteration(5, 3, rewards.as_ptr(), rewards.len() as i32, probabilities.as_ptr(), probabilities.len() as i32,
+                                         0.9, [5].as_ptr(), 1, 0, &mut invalid), Status::InvalidArgument);
+        assert_eq!(invalid, 0);
+    }
+
+    #[test]
+    fn unvisited_states_are_not_in_the_policy() {
+        let model = created(|out| init_q_learning(10, 0.9, 0.1, 0.2, 0, out));
+        assert_eq!(tabular_action(model, 3, &mut 0), Status::InvalidArgument);
+        assert_eq!(last_error(), "state 3 is not in the policy");
+        assert_eq!(output(|written| tabular_policy(model, std::ptr::null_mut(), 0, written)), 0);
+    }
+
+    #[test]
+    fn broken_environments_are_reported() {
+        let mut env = line_world(0);
+        let model = created(|out| init_q_learning(10, 0.9, 0.1, 0.2, 0, out));
+
+        assert_eq!(train_tabular(model, None), Status::NullPointer);
+        assert_eq!(last_error(), "env is null");
+        let missing = EnvironmentCallbacks { step: None, ..callbacks(&mut env) };
+        assert_eq!(train_tabular(model, Some(&missing)), Status::NullPointer);
+        assert_eq!(last_error(), "step is null");
+
+        // A state outside [0, num_states) stops training instead of growing the tables
+        let wrong_state = EnvironmentCallbacks { reset: Some(out_of_range), ..callbacks(&mut env) };
+        assert_eq!(train_tabular(model, Some(&wrong_state)), Status::Panic);
+        assert_eq!(last_error(), "panic: host environment returned state 7, expected [0, 5)");
+
+        let sarsa = created(|out| init_sarsa(4, 3, 0.1, 0.9, 0.2, 10, 0, out));
+        assert_eq!(train_tabular(sarsa, Some(&callbacks(&mut env))), Status::DimensionMismatch);
+        assert_eq!(last_error(), "env num_states has 5 values, the model expects 4");
+    }
+}
@@ -0,0 +1,33 @@
+use super::tabular::TabularModel;
+use crate::environment::environment::Environment;
+use crate::environment::host_environment::{EnvironmentCallbacks, HostEnvironment};
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, FfiError, FfiResult, Status};
+use crate::ffi::handle::{self, Handle};
+
+// `env` may be null for policy and value iteration, they learn from their transition arrays
+#[no_mangle]
+pub(crate) extern "C" fn train_tabular(model: Handle, env: Option<&EnvironmentCallbacks>) -> Status {
+    ffi_call(|| handle::with_mut(model, |model: &mut TabularModel| {
+        if !model.needs_environment() {
+            return train_on(model, None);
+        }
+        let mut env = HostEnvironment::new(env)?;
+        train_on(model, Some(&mut env))
+    }))
+}
+
+/// Trains on any environment, the host one or a built-in one.
+pub(crate) fn train_on(model: &mut TabularModel, env: Option<&mut dyn Environment>) -> FfiResult<()> {
+    match env {
+        _ if !model.needs_environment() => model.train(None),
+        None => return Err(FfiError::null("env")),
+        Some(env) => {
+            if let Some(num_states) = model.num_states() {
+                args::expect_len(env.all_states().len(), num_states, "env num_states")?;
+            }
+            model.train(Some(env));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use super::kernel::{Kernel, KernelParams};
+use super::svm_struct::{BinaryClassifier, Svm};
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, FfiError, Status};
+use crate::ffi::handle::{self, Handle};
+
+#[no_mangle]
+pub(crate) extern "C" fn init_svm(input_size: i32, output_size: i32, kernel: KernelParams, c: f32, out: *mut Handle) -> Status {
+    ffi_call(|| {
+        let kernel = Kernel::try_from(kernel)?;
+        if c <= 0.0 {
+            return Err(FfiError::invalid(format!("c should be positive ({})", c)));
+        }
+        handle::write(out, Svm::new(args::positive(input_size, "input_size")?, args::positive(output_size, "output_size")?, kernel, c))
+    })
+}
+
+impl Svm {
+    pub fn new(input_size: usize, output_size: usize, kernel: Kernel, c: f32) -> Svm {
+        Svm {
+            input_size,
+            output_size,
+            c,
+            kernel,
+            classifiers: vec![BinaryClassifier::default(); output_size],
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use super::svm_struct::Svm;
+use crate::ffi::error::{ffi_call, Status};
+use crate::ffi::handle::{self, Handle};
+
+#[no_mangle]
+extern "C" fn delete_svm(model: Handle) -> Status {
+    ffi_call(|| {
+        handle::remove::<Svm>(model)?;
+        println!("Model deleted");
+        Ok(())
+    })
+}
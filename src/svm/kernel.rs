@@ -0,0 +1,93 @@
+use bincode::{Decode, Encode};
+use crate::ffi::args::enum_code;
+use crate::ffi::error::FfiError;
+
+#[repr(C)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Debug)]
+pub enum KernelKind {
+    /// x . y
+    Linear,
+    /// (gamma * x . y + coef0) ^ degree
+    Polynomial,
+    /// exp(-gamma * ||x - y||^2)
+    Rbf,
+}
+
+enum_code!(KernelKind { Linear, Polynomial, Rbf });
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Debug)]
+pub struct Kernel {
+    pub kind: KernelKind,
+    pub degree: i32,
+    pub gamma: f32,
+    pub coef0: f32,
+}
+
+/// Kernel as passed to `init_svm`, `kind` is a `KernelKind` code.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct KernelParams {
+    pub kind: i32,
+    pub degree: i32,
+    pub gamma: f32,
+    pub coef0: f32,
+}
+
+impl TryFrom<KernelParams> for Kernel {
+    type Error = FfiError;
+
+    fn try_from(params: KernelParams) -> Result<Kernel, FfiError> {
+        Ok(Kernel { kind: KernelKind::try_from(params.kind)?, degree: params.degree, gamma: params.gamma, coef0: params.coef0 })
+    }
+}
+
+impl From<Kernel> for KernelParams {
+    fn from(kernel: Kernel) -> KernelParams {
+        KernelParams { kind: kernel.kind as i32, degree: kernel.degree, gamma: kernel.gamma, coef0: kernel.coef0 }
+    }
+}
+
+impl Kernel {
+    pub fn linear() -> Kernel {
+        Kernel { kind: KernelKind::Linear, degree: 1, gamma: 1.0, coef0: 0.0 }
+    }
+
+    pub fn polynomial(degree: i32, gamma: f32, coef0: f32) -> Kernel {
+        Kernel { kind: KernelKind::Polynomial, degree, gamma, coef0 }
+    }
+
+    pub fn rbf(gamma: f32) -> Kernel {
+        Kernel { kind: KernelKind::Rbf, degree: 1, gamma, coef0: 0.0 }
+    }
+
+    pub fn compute(&self, x: &[f32], y: &[f32]) -> f32 {
+        match self.kind {
+            KernelKind::Linear => dot(x, y),
+            KernelKind::Polynomial => (self.gamma * dot(x, y) + self.coef0).powi(self.degree),
+            KernelKind::Rbf => {
+                let distance: f32 = x.iter().zip(y).map(|(a, b)| (a - b).powi(2)).sum();
+                (-self.gamma * distance).exp()
+            }
+        }
+    }
+}
+
+fn dot(x: &[f32], y: &[f32]) -> f32 {
+    x.iter().zip(y).map(|(a, b)| a * b).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kernels_by_hand() {
+        let (x, y) = ([1.0, 2.0], [3.0, -1.0]);
+        assert_eq!(Kernel::linear().compute(&x, &y), 1.0);
+        // (0.5 * 1 + 1) ^ 2
+        assert_eq!(Kernel::polynomial(2, 0.5, 1.0).compute(&x, &y), 2.25);
+        // exp(-0.1 * (4 + 9))
+        assert!((Kernel::rbf(0.1).compute(&x, &y) - (-1.3f32).exp()).abs() < 1e-7);
+        assert_eq!(Kernel::rbf(0.1).compute(&x, &x), 1.0);
+    }
+}
@@ -0,0 +1,9 @@
+pub mod create;
+pub mod kernel;
+pub mod svm_struct;
+pub mod delete;
+pub mod smo;
+pub mod train;
+pub mod predict;
+pub mod manager;
+mod tester;
@@ -0,0 +1,61 @@
+use super::svm_struct::{BinaryClassifier, Svm};
+use crate::ffi::args;
+use crate::ffi::buffer::FloatBuffer;
+use crate::ffi::error::{ffi_call, FfiError, FfiResult, Status};
+use crate::ffi::handle::{self, Handle};
+
+#[no_mangle]
+pub(crate) extern "C" fn predict_svm(model: Handle, inputs: *const f32, inputs_len: i32, out: *mut FloatBuffer) -> Status {
+    ffi_call(|| handle::with(model, |model: &Svm| {
+        let inputs_slice = args::slice(inputs, inputs_len, "inputs")?;
+        args::expect_len(inputs_slice.len(), model.input_size, "inputs")?;
+
+        // Released by the caller with free_float_buffer
+        args::write(out, FloatBuffer::from_vec(model.predict(inputs_slice)), "out")
+    }))
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn predict_svm_into(model: Handle, inputs: *const f32, inputs_len: i32, output: *mut f32, output_len: i32) -> Status {
+    ffi_call(|| handle::with(model, |model: &Svm| {
+        let inputs_slice = args::slice(inputs, inputs_len, "inputs")?;
+        let output_slice = args::slice_mut(output, output_len, "output")?;
+        args::expect_len(inputs_slice.len(), model.input_size, "inputs")?;
+        args::expect_len(output_slice.len(), model.output_size, "output")?;
+
+        output_slice.copy_from_slice(&model.predict(inputs_slice));
+        Ok(())
+    }))
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn svm_support_vector_count(model: Handle, class: i32, count: *mut i32) -> Status {
+    ffi_call(|| with_classifier(model, class, |classifier| args::write(count, classifier.support_vector_count() as i32, "count")))
+}
+
+// Copy the support vectors of one machine (count x input_size), `written` is the number of floats written
+#[no_mangle]
+pub(crate) extern "C" fn svm_support_vectors(model: Handle, class: i32, output: *mut f32, output_len: i32, written: *mut i32) -> Status {
+    ffi_call(|| with_classifier(model, class, |classifier| args::copy_out(&classifier.support_vectors, output, output_len, written)))
+}
+
+// Copy alpha_i * y_i of each support vector, `written` is the number of floats written
+#[no_mangle]
+pub(crate) extern "C" fn svm_dual_coefficients(model: Handle, class: i32, output: *mut f32, output_len: i32, written: *mut i32) -> Status {
+    ffi_call(|| with_classifier(model, class, |classifier| args::copy_out(&classifier.coefficients, output, output_len, written)))
+}
+
+#[no_mangle]
+pub(crate) extern "C" fn svm_bias(model: Handle, class: i32, bias: *mut f32) -> Status {
+    ffi_call(|| with_classifier(model, class, |classifier| args::write(bias, classifier.bias, "bias")))
+}
+
+// Machine of one class, only exists once the model is trained
+fn with_classifier<R>(model: Handle, class: i32, body: impl FnOnce(&BinaryClassifier) -> FfiResult<R>) -> FfiResult<R> {
+    handle::with(model, |model: &Svm| {
+        let classifier = usize::try_from(class).ok()
+            .and_then(|class| model.classifiers.get(class))
+            .ok_or_else(|| FfiError::invalid(format!("class {} is out of the {} trained machines", class, model.classifiers.len())))?;
+        body(classifier)
+    })
+}
@@ -0,0 +1,144 @@
+use super::kernel::Kernel;
+use super::svm_struct::BinaryClassifier;
+
+// Stop when the maximal KKT violation falls under this gap
+const TOLERANCE: f32 = 1e-3;
+// Alphas under this are not support vectors
+const ALPHA_EPSILON: f32 = 1e-6;
+
+/// Sequential minimal optimization of the soft-margin dual, labels in {-1, 1}.
+/// The working pair is the maximal violating pair (Keerthi et al.), the kernel matrix is cached.
+pub fn smo(inputs: &[Vec<f32>], labels: &[f32], kernel: &Kernel, c: f32, max_iterations: usize) -> BinaryClassifier {
+    let n = inputs.len();
+    let gram: Vec<Vec<f32>> = inputs.iter()
+        .map(|x| inputs.iter().map(|y| kernel.compute(x, y)).collect())
+        .collect();
+
+    let mut alphas = vec![0.0f32; n];
+    // f(x_t) without the bias: sum alpha_s y_s K(s, t)
+    let mut outputs = vec![0.0f32; n];
+
+    for _ in 0..max_iterations {
+        let Some((i, j)) = violating_pair(&alphas, &outputs, labels, c) else {
+            break;
+        };
+
+        let (y_i, y_j) = (labels[i], labels[j]);
+        let (error_i, error_j) = (outputs[i] - y_i, outputs[j] - y_j);
+        let (lower, upper) = if y_i != y_j {
+            ((alphas[j] - alphas[i]).max(0.0), (c + alphas[j] - alphas[i]).min(c))
+        } else {
+            ((alphas[i] + alphas[j] - c).max(0.0), (alphas[i] + alphas[j]).min(c))
+        };
+        // Curvature along the constraint line, kept positive for non PSD kernels
+        let eta = (gram[i][i] + gram[j][j] - 2.0 * gram[i][j]).max(1e-12);
+
+        let alpha_j = (alphas[j] + y_j * (error_i - error_j) / eta).clamp(lower, upper);
+        let alpha_i = alphas[i] + y_i * y_j * (alphas[j] - alpha_j);
+        let (delta_i, delta_j) = (alpha_i - alphas[i], alpha_j - alphas[j]);
+        alphas[i] = alpha_i;
+        alphas[j] = alpha_j;
+
+        for (t, output) in outputs.iter_mut().enumerate() {
+            *output += delta_i * y_i * gram[i][t] + delta_j * y_j * gram[j][t];
+        }
+    }
+
+    let bias = bias(&alphas, &outputs, labels, c);
+    let support: Vec<usize> = (0..n).filter(|&t| alphas[t] > ALPHA_EPSILON).collect();
+    BinaryClassifier {
+        support_vectors: support.iter().flat_map(|&t| inputs[t].iter().copied()).collect(),
+        coefficients: support.iter().map(|&t| alphas[t] * labels[t]).collect(),
+        bias,
+    }
+}
+
+// Alpha can still grow along y (I_up) or shrink along y (I_low)
+fn is_up(alpha: f32, label: f32, c: f32) -> bool {
+    (label > 0.0 && alpha < c) || (label < 0.0 && alpha > 0.0)
+}
+
+fn is_low(alpha: f32, label: f32, c: f32) -> bool {
+    (label > 0.0 && alpha > 0.0) || (label < 0.0 && alpha < c)
+}
+
+// i maximizes y - f over I_up, j minimizes it over I_low, None once the KKT gap is closed
+fn violating_pair(alphas: &[f32], outputs: &[f32], labels: &[f32], c: f32) -> Option<(usize, usize)> {
+    let mut up: Option<(usize, f32)> = None;
+    let mut low: Option<(usize, f32)> = None;
+
+    for (t, ((&alpha, &output), &label)) in alphas.iter().zip(outputs).zip(labels).enumerate() {
+        let value = label - output;
+        if is_up(alpha, label, c) && up.is_none_or(|(_, best)| value > best) {
+            up = Some((t, value));
+        }
+        if is_low(alpha, label, c) && low.is_none_or(|(_, best)| value < best) {
+            low = Some((t, value));
+        }
+    }
+
+    match (up, low) {
+        (Some((i, m)), Some((j, big_m))) if m - big_m > TOLERANCE => Some((i, j)),
+        _ => None,
+    }
+}
+
+// Mean of y - f over the free support vectors, middle of the feasible interval when there are none
+fn bias(alphas: &[f32], outputs: &[f32], labels: &[f32], c: f32) -> f32 {
+    let mut free_sum = 0.0;
+    let mut free_count = 0;
+    let mut upper = f32::INFINITY;
+    let mut lower = f32::NEG_INFINITY;
+
+    for ((&alpha, &output), &label) in alphas.iter().zip(outputs).zip(labels) {
+        let value = label - output;
+        if alpha > ALPHA_EPSILON && alpha < c - ALPHA_EPSILON {
+            free_sum += value;
+            free_count += 1;
+        }
+        if is_up(alpha, label, c) {
+            lower = lower.max(value);
+        }
+        if is_low(alpha, label, c) {
+            upper = upper.min(value);
+        }
+    }
+
+    if free_count > 0 {
+        free_sum / free_count as f32
+    } else if lower.is_finite() && upper.is_finite() {
+        (lower + upper) / 2.0
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_points_by_hand() {
+        // Margin of 1 on each side: w = 1, b = 0, alpha = 0.5 for both points
+        let inputs = vec![vec![-1.0], vec![1.0]];
+        let classifier = smo(&inputs, &[-1.0, 1.0], &Kernel::linear(), 10.0, 1000);
+
+        assert_eq!(classifier.support_vector_count(), 2);
+        assert!((classifier.coefficients[0] + 0.5).abs() < 1e-5);
+        assert!((classifier.coefficients[1] - 0.5).abs() < 1e-5);
+        assert!(classifier.bias.abs() < 1e-5);
+        assert!((classifier.decision(&Kernel::linear(), &[3.0]) - 3.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn soft_margin_bounds_alphas() {
+        // The last point sits on the wrong side, with a small c its alpha saturates
+        let inputs = vec![vec![-2.0], vec![-1.0], vec![1.0], vec![2.0], vec![-1.5]];
+        let labels = [-1.0, -1.0, 1.0, 1.0, 1.0];
+        let classifier = smo(&inputs, &labels, &Kernel::linear(), 0.1, 1000);
+
+        assert!(classifier.coefficients.iter().all(|coefficient| coefficient.abs() <= 0.1 + 1e-6));
+        let balance: f32 = classifier.coefficients.iter().sum();
+        assert!(balance.abs() < 1e-5);
+    }
+}
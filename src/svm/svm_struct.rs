@@ -0,0 +1,44 @@
+use bincode::{Decode, Encode};
+use super::kernel::Kernel;
+
+/// One binary soft-margin machine, only the support vectors are kept.
+#[derive(Encode, Decode, PartialEq, Debug, Clone, Default)]
+pub struct BinaryClassifier {
+    // Row-major: one row of input_size coordinates per support vector
+    pub support_vectors: Vec<f32>,
+    // alpha_i * y_i for each support vector
+    pub coefficients: Vec<f32>,
+    pub bias: f32,
+}
+
+#[derive(Encode, Decode, PartialEq, Debug, Clone)]
+pub struct Svm {
+    pub input_size: usize,
+    /// 1 for a binary machine with -1 / 1 labels, otherwise one machine per class (one-vs-rest on one-hot labels)
+    pub output_size: usize,
+    /// Soft margin penalty, alphas are bounded by c
+    pub c: f32,
+    pub kernel: Kernel,
+    pub classifiers: Vec<BinaryClassifier>,
+}
+
+impl BinaryClassifier {
+    pub fn support_vector_count(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    // f(x) = sum alpha_i y_i K(sv_i, x) + b
+    pub fn decision(&self, kernel: &Kernel, inputs: &[f32]) -> f32 {
+        let input_size = inputs.len().max(1);
+        self.bias + self.support_vectors.chunks_exact(input_size).zip(&self.coefficients)
+            .map(|(vector, coefficient)| coefficient * kernel.compute(vector, inputs))
+            .sum::<f32>()
+    }
+}
+
+impl Svm {
+    /// Decision value of every machine: the sign is the binary class, the argmax the one-vs-rest class.
+    pub fn predict(&self, inputs: &[f32]) -> Vec<f32> {
+        self.classifiers.iter().map(|classifier| classifier.decision(&self.kernel, inputs)).collect()
+    }
+}
@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use crate::ffi::error::Status;
+    use crate::ffi::handle;
+    use crate::ffi::test_support::{created, output, taken};
+    use crate::neural_network::initializer::WeightInit;
+    use crate::neural_network::nn_struct::NeuralNetwork;
+    use crate::neural_network::train::{fit, TrainConfig};
+    use crate::neural_network::validation::{evaluate, is_correct};
+    use crate::svm::create::init_svm;
+    use crate::svm::kernel::Kernel;
+    use crate::svm::manager::{load_svm, save_svm};
+    use crate::svm::predict::{predict_svm, svm_bias, svm_dual_coefficients, svm_support_vector_count, svm_support_vectors};
+    use crate::svm::svm_struct::Svm;
+    use crate::svm::train::train_svm;
+
+    // XOR with -1 / 1 labels, not linearly separable
+    fn xor_dataset() -> (Vec<Vec<f32>>, Vec<Vec<f32>>) {
+        let inputs = vec![vec![0.0, 0.0], vec![0.0, 1.0], vec![1.0, 0.0], vec![1.0, 1.0]];
+        let outputs = vec![vec![-1.0], vec![1.0], vec![1.0], vec![-1.0]];
+        (inputs, outputs)
+    }
+
+    // Three blobs with one-hot labels
+    fn blobs_dataset() -> (Vec<Vec<f32>>, Vec<Vec<f32>>) {
+        let centers = [[0.0, 0.0], [3.0, 0.0], [0.0, 3.0]];
+        let mut inputs = Vec::new();
+        let mut outputs = Vec::new();
+        for (class, center) in centers.iter().enumerate() {
+            for k in 0..10 {
+                let angle = k as f32;
+                inputs.push(vec![center[0] + 0.5 * angle.cos(), center[1] + 0.5 * angle.sin()]);
+                outputs.push((0..3).map(|c| if c == class { 1.0 } else { -1.0 }).collect());
+            }
+        }
+        (inputs, outputs)
+    }
+
+    fn accuracy(model: &Svm, inputs: &[Vec<f32>], outputs: &[Vec<f32>]) -> f32 {
+        let correct = inputs.iter().zip(outputs).filter(|(x, y)| is_correct(&model.predict(x), y)).count();
+        correct as f32 / inputs.len() as f32
+    }
+
+    #[test]
+    fn init_svm_machines() {
+        let model = taken::<Svm>(created(|out| init_svm(4, 3, Kernel::rbf(0.5).into(), 1.0, out)));
+        assert_eq!(model.classifiers.len(), 3);
+        assert!(model.classifiers.iter().all(|classifier| classifier.support_vector_count() == 0));
+
+        let mut invalid = 0;
+        assert_eq!(init_svm(4, 3, Kernel::rbf(0.5).into(), 0.0, &mut invalid), Status::InvalidArgument);
+        assert_eq!(invalid, 0);
+    }
+
+    #[test]
+    fn rbf_kernel_solves_xor() {
+        let (inputs, outputs) = xor_dataset();
+        let flat_inputs = inputs.concat();
+        let flat_outputs = outputs.concat();
+        let model = created(|out| init_svm(2, 1, Kernel::rbf(1.0).into(), 10.0, out));
+        let status = train_svm(model, flat_inputs.as_ptr(), flat_inputs.len() as i32, 2, flat_outputs.as_ptr(), flat_outputs.len() as i32, 1, 10000);
+        assert_eq!(status, Status::Ok);
+
+        for (input, expected) in inputs.iter().zip(&outputs) {
+            let res = output(|out| predict_svm(model, input.as_ptr(), 2, out));
+            let prediction = unsafe { res.into_vec() };
+            assert_eq!(prediction[0] > 0.0, expected[0] > 0.0);
+        }
+    }
+
+    #[test]
+    fn polynomial_kernel_solves_xor() {
+        let (inputs, outputs) = xor_dataset();
+        let mut model = Svm::new(2, 1, Kernel::polynomial(2, 1.0, 1.0), 100.0);
+        model.fit(&inputs, &outputs, 10000);
+        assert_eq!(accuracy(&model, &inputs, &outputs), 1.0);
+    }
+
+    #[test]
+    fn linear_kernel_cannot_solve_xor() {
+        let (inputs, outputs) = xor_dataset();
+        let mut model = Svm::new(2, 1, Kernel::linear(), 1.0);
+        model.fit(&inputs, &outputs, 10000);
+        assert!(accuracy(&model, &inputs, &outputs) < 1.0);
+    }
+
+    #[test]
+    fn one_vs_rest_blobs() {
+        let (inputs, outputs) = blobs_dataset();
+        let mut model = Svm::new(2, 3, Kernel::linear(), 1.0);
+        model.fit(&inputs, &outputs, 10000);
+        assert_eq!(accuracy(&model, &inputs, &outputs), 1.0);
+    }
+
+    #[test]
+    fn support_vectors_reproduce_decision() {
+        let (inputs, outputs) = blobs_dataset();
+        let mut model = Svm::new(2, 3, Kernel::rbf(0.5), 1.0);
+        model.fit(&inputs, &outputs, 10000);
+        let svm = handle::insert(model.clone());
+
+        let count = output(|count| svm_support_vector_count(svm, 1, count)) as usize;
+        assert!(count > 0 && count < inputs.len());
+        let mut vectors = vec![0.0; count * 2];
+        let mut coefficients = vec![0.0; count];
+        let written = output(|written| svm_support_vectors(svm, 1, vectors.as_mut_ptr(), vectors.len() as i32, written));
+        assert_eq!(written, (count * 2) as i32);
+        let written = output(|written| svm_dual_coefficients(svm, 1, coefficients.as_mut_ptr(), count as i32, written));
+        assert_eq!(written, count as i32);
+        assert_eq!(svm_support_vector_count(svm, 3, &mut 0), Status::InvalidArgument);
+
+        // Rebuild the decision of machine 1 from what the host can read
+        let sample = [2.5f32, 0.5];
+        let mut decision = output(|bias| svm_bias(svm, 1, bias));
+        for (vector, coefficient) in vectors.chunks(2).zip(&coefficients) {
+            decision += coefficient * Kernel::rbf(0.5).compute(vector, &sample);
+        }
+        assert!((decision - model.predict(&sample)[1]).abs() < 1e-5);
+        taken::<Svm>(svm);
+    }
+
+    #[test]
+    fn matches_pmc_on_blobs() {
+        let (inputs, outputs) = blobs_dataset();
+        let mut svm = Svm::new(2, 3, Kernel::rbf(0.5), 1.0);
+        svm.fit(&inputs, &outputs, 10000);
+
+        let mut pmc = NeuralNetwork::new(&[2, 8, 3], WeightInit::XavierUniform, 0.0, 6);
+        let config = TrainConfig {
+            learning_rate: 0.05,
+            iterations: 20000,
+            is_classification: true,
+            regularization: Default::default(),
+            eval_every: 0,
+            patience: 0,
+        };
+        fit(&mut pmc, &inputs, &outputs, &[], &[], &config, &mut StdRng::seed_from_u64(0));
+
+        let (_, pmc_accuracy) = evaluate(&pmc, &inputs, &outputs, true);
+        assert_eq!(pmc_accuracy, 1.0);
+        assert_eq!(accuracy(&svm, &inputs, &outputs), pmc_accuracy);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let (inputs, outputs) = xor_dataset();
+        let mut model = Svm::new(2, 1, Kernel::rbf(1.0), 10.0);
+        model.fit(&inputs, &outputs, 1000);
+        let path = std::env::temp_dir().join("rustml_svm_test.bin");
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+
+        let saved = handle::insert(model.clone());
+        assert_eq!(save_svm(saved, c_path.as_ptr()), Status::Ok);
+        taken::<Svm>(saved);
+        let loaded = created(|out| load_svm(c_path.as_ptr(), out));
+        assert_eq!(taken::<Svm>(loaded), model);
+        std::fs::remove_file(path).unwrap();
+
+        let missing = CString::new("/nonexistent/rustml_model.bin").unwrap();
+        let mut loaded = 0;
+        assert_eq!(load_svm(missing.as_ptr(), &mut loaded), Status::Io);
+        assert_eq!(loaded, 0);
+    }
+}
@@ -0,0 +1,43 @@
+use super::smo::smo;
+use super::svm_struct::Svm;
+use crate::basic_function::tools::argmax;
+use crate::ffi::args;
+use crate::ffi::error::{ffi_call, FfiError, Status};
+use crate::ffi::handle::{self, Handle};
+
+#[no_mangle]
+pub(crate) extern "C" fn train_svm(model: Handle,
+                                   inputs_ptr: *const f32, input_length: i32, inputs_sub: i32,
+                                   output_ptr: *const f32, output_length: i32, output_sub: i32,
+                                   iteration: i32) -> Status {
+    ffi_call(|| handle::with_mut(model, |model: &mut Svm| {
+        // Recompose Vec<Vec<f32>>
+        let inputs = args::rows(inputs_ptr, input_length, inputs_sub, "inputs")?;
+        let outputs = args::rows(output_ptr, output_length, output_sub, "outputs")?;
+        args::check_samples(&inputs, &outputs, model.input_size, model.output_size)?;
+        if inputs.is_empty() {
+            return Err(FfiError::invalid("no training samples"));
+        }
+
+        model.fit(&inputs, &outputs, args::length(iteration, "iteration")?);
+        Ok(())
+    }))
+}
+
+impl Svm {
+    /// Labels are -1 / 1 for a single output, one-hot (argmax) otherwise.
+    /// `iterations` bounds the number of SMO steps of each machine.
+    pub fn fit(&mut self, inputs: &[Vec<f32>], outputs: &[Vec<f32>], iterations: usize) {
+        let classes: Vec<i32> = outputs.iter().map(|output| argmax(output)).collect();
+
+        for (class, classifier) in self.classifiers.iter_mut().enumerate() {
+            let labels: Vec<f32> = if self.output_size == 1 {
+                outputs.iter().map(|output| if output[0] > 0.0 { 1.0 } else { -1.0 }).collect()
+            } else {
+                // One-vs-rest
+                classes.iter().map(|&c| if c as usize == class { 1.0 } else { -1.0 }).collect()
+            };
+            *classifier = smo(inputs, &labels, &self.kernel, self.c, iterations);
+        }
+    }
+}
@@ -0,0 +1,21 @@
+// The committed bindings match the ones build.rs generates from the current exports.
+// Refresh them with RUSTML_UPDATE_BINDINGS=1 cargo build
+
+use std::fs;
+use std::path::Path;
+
+fn assert_fresh(file: &str) {
+    let generated = fs::read_to_string(Path::new(env!("OUT_DIR")).join(file)).unwrap();
+    let committed = fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("bindings").join(file)).unwrap();
+    assert!(generated == committed, "bindings/{} is stale, run RUSTML_UPDATE_BINDINGS=1 cargo build", file);
+}
+
+#[test]
+fn c_header_is_fresh() {
+    assert_fresh("rustml.h");
+}
+
+#[test]
+fn csharp_bindings_are_fresh() {
+    assert_fresh("NativeMethods.g.cs");
+}
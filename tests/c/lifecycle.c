@@ -0,0 +1,131 @@
+/* PMC and DQL lifecycle through the generated header, built and run by tests/c_api.rs */
+#include <stdio.h>
+#include <string.h>
+
+#include "rustml.h"
+
+#define CHECK(call)                                                                   \
+    do {                                                                              \
+        Status status_ = (call);                                                      \
+        if (status_ != STATUS_OK) {                                                   \
+            fprintf(stderr, "%s:%d %s returned %d: %s\n", __FILE__, __LINE__, #call,  \
+                    (int)status_, rustml_last_error());                               \
+            return 1;                                                                 \
+        }                                                                             \
+    } while (0)
+
+#define EXPECT(condition)                                                             \
+    do {                                                                              \
+        if (!(condition)) {                                                           \
+            fprintf(stderr, "%s:%d expected %s\n", __FILE__, __LINE__, #condition);   \
+            return 1;                                                                 \
+        }                                                                             \
+    } while (0)
+
+static int pmc_lifecycle(const char *path) {
+    int32_t topology[] = {2, 4, 1};
+    float inputs[] = {0.0f, 0.0f, 0.0f, 1.0f, 1.0f, 0.0f, 1.0f, 1.0f};
+    float outputs[] = {-1.0f, 1.0f, 1.0f, -1.0f};
+    float history[HISTORY_RECORD_SIZE * 4];
+    int32_t written = 0;
+    Handle model = 0;
+    Handle loaded = 0;
+    FloatBuffer buffer = {0};
+    int epoch, sample;
+
+    CHECK(init(topology, 3, WEIGHT_INIT_XAVIER_UNIFORM, 0.0f, 3, &model));
+    CHECK(train_pmc_model(model, inputs, 8, 2, outputs, 4, 1, inputs, 8, 2, outputs, 4, 1,
+                          0.05f, 400, true, NULL, 100, 0, history, HISTORY_RECORD_SIZE * 4, &written));
+    EXPECT(written == 4);
+    EXPECT(history[0] == 100.0f);
+
+    // XOR, one sample at a time
+    for (epoch = 0; epoch < 5000; epoch++) {
+        for (sample = 0; sample < 4; sample++) {
+            CHECK(one_step_train_pmc(model, inputs + 2 * sample, 2, outputs + sample, 1, 0.05f, true, NULL));
+        }
+    }
+    for (sample = 0; sample < 4; sample++) {
+        CHECK(predict(model, inputs + 2 * sample, 2, true, &buffer));
+        EXPECT(buffer.len == 1);
+        EXPECT((buffer.ptr[0] > 0.0f) == (outputs[sample] > 0.0f));
+        CHECK(free_float_buffer(buffer));
+    }
+
+    // Saved and loaded models predict the same
+    CHECK(save_pmc(model, path));
+    CHECK(load_pmc(path, &loaded));
+    {
+        float expected[4], actual[4];
+        CHECK(predict_batch(model, inputs, 4, 2, expected, 4, true));
+        CHECK(predict_batch(loaded, inputs, 4, 2, actual, 4, true));
+        EXPECT(memcmp(expected, actual, sizeof(expected)) == 0);
+    }
+    remove(path);
+
+    // Failures leave a message and the out parameter untouched
+    buffer.ptr = NULL;
+    EXPECT(predict(model, inputs, 3, true, &buffer) == STATUS_DIMENSION_MISMATCH);
+    EXPECT(rustml_last_error() != NULL);
+    EXPECT(buffer.ptr == NULL);
+    EXPECT(predict(0, inputs, 2, true, &buffer) == STATUS_INVALID_HANDLE);
+    EXPECT(load_pmc("/nonexistent/rustml_model.bin", &loaded) == STATUS_IO);
+    EXPECT(init(topology, -1, WEIGHT_INIT_UNIFORM, 0.0f, 0, &loaded) == STATUS_INVALID_LENGTH);
+    // Enum arguments are int32_t codes, an unknown one is rejected
+    EXPECT(init(topology, 3, WEIGHT_INIT_HE_NORMAL + 1, 0.0f, 0, &loaded) == STATUS_INVALID_ARGUMENT);
+
+    CHECK(delete_pmc(loaded));
+    CHECK(delete_pmc(model));
+    EXPECT(rustml_last_error() == NULL);
+
+    // Deleted handles are rejected instead of freed twice
+    EXPECT(delete_pmc(model) == STATUS_INVALID_HANDLE);
+    EXPECT(predict(loaded, inputs, 2, true, &buffer) == STATUS_INVALID_HANDLE);
+    return 0;
+}
+
+static int dql_lifecycle(void) {
+    float state[] = {0.0f, 1.0f, 0.0f, 0.0f};
+    float next_state[] = {0.0f, 0.0f, 1.0f, 0.0f};
+    int32_t actions[] = {0, 1};
+    int32_t action = -1;
+    int32_t size = 0;
+    uint8_t garbage[] = {1, 2, 3};
+    Handle model = 0;
+    Handle copy = 0;
+    ByteBuffer bytes = {0};
+    int step;
+
+    CHECK(init_dql(4, 2, 0.01f, 0.9f, 1.0f, 0.1f, 0.99f, 0, &model));
+    for (step = 0; step < 20; step++) {
+        CHECK(choose_action(model, state, 4, 2, actions, 2, &action));
+        EXPECT(action == 0 || action == 1);
+        CHECK(learn_dql(model, state, 4, action, step % 2, next_state, 4, 2, step == 19));
+        CHECK(update_epsilon(model));
+    }
+    CHECK(check_size(model, &size));
+    EXPECT(size > 0);
+
+    CHECK(save_model(model, &bytes));
+    EXPECT(bytes.len > 0);
+    CHECK(deserialize_model(bytes.ptr, bytes.len, &copy));
+    CHECK(free_byte_buffer(bytes));
+    EXPECT(deserialize_model(garbage, sizeof(garbage), &copy) == STATUS_IO);
+
+    CHECK(delete_dql(copy));
+    CHECK(delete_dql(model));
+    EXPECT(delete_dql(model) == STATUS_INVALID_HANDLE);
+    return 0;
+}
+
+int main(int argc, char **argv) {
+    if (argc != 2) {
+        fprintf(stderr, "usage: %s <model path>\n", argv[0]);
+        return 2;
+    }
+    if (pmc_lifecycle(argv[1]) != 0 || dql_lifecycle() != 0) {
+        return 1;
+    }
+    printf("ok\n");
+    return 0;
+}
@@ -0,0 +1,33 @@
+// Builds tests/c/lifecycle.c against bindings/rustml.h and runs it on the cdylib
+#![cfg(unix)]
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+#[test]
+fn c_lifecycle() {
+    let manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // The cdylib is built next to this test in target/<profile>/deps
+    let deps = env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    let executable = env::temp_dir().join(format!("rustml_c_lifecycle_{}", std::process::id()));
+    let model_path = env::temp_dir().join(format!("rustml_c_lifecycle_{}.bin", std::process::id()));
+
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let compiled = Command::new(compiler)
+        .args(["-std=c99", "-Wall", "-Wextra", "-Werror"])
+        .arg(manifest.join("tests/c/lifecycle.c"))
+        .arg("-I").arg(manifest.join("bindings"))
+        .arg("-L").arg(&deps)
+        .arg("-lrustml")
+        .arg(format!("-Wl,-rpath,{}", deps.display()))
+        .arg("-o").arg(&executable)
+        .status()
+        .expect("a C compiler is needed, set CC");
+    assert!(compiled.success());
+
+    let output = Command::new(&executable).arg(&model_path).output().unwrap();
+    std::fs::remove_file(&executable).unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout).lines().last(), Some("ok"));
+}